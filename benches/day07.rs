@@ -0,0 +1,57 @@
+//! Times the day 7 feedback loop search, reading each amplifier's output with a copy of the
+//! output stream against borrowing it.
+//!
+//! Run it with `cargo bench --bench day07`.
+use aoc2019::bench;
+use aoc2019::int_code::IntCodeComputer;
+use aoc2019::util;
+
+/// How many times each search is timed.
+const RUNS: usize = 20;
+
+/// Finds the best phase settings for the amplifiers in a feedback loop, using `read` to get the
+/// value an amplifier just output.
+fn search(program: &[i64], read: fn(&mut IntCodeComputer) -> i64) -> i64 {
+    util::Permutations::new(5)
+        .map(|phases| {
+            let mut amps: Vec<IntCodeComputer> = phases
+                .iter()
+                .map(|phase| {
+                    let mut amp = IntCodeComputer::new(program.to_vec());
+                    amp.input.write(5 + *phase as i64);
+                    amp
+                })
+                .collect();
+            let mut signal = 0;
+            while !amps.iter().all(|amp| amp.is_halted()) {
+                for amp in amps.iter_mut() {
+                    amp.input.write(signal);
+                    amp.run();
+                    signal = read(amp);
+                }
+            }
+            signal
+        })
+        .max()
+        .unwrap()
+}
+
+fn copied(amp: &mut IntCodeComputer) -> i64 {
+    let signal = *amp.dump_output().read_all().first().unwrap();
+    amp.clear_output();
+    signal
+}
+
+fn borrowed(amp: &mut IntCodeComputer) -> i64 {
+    amp.drain_output()[0]
+}
+
+fn main() {
+    let program = util::read_int_code_memory("./input/day07.txt");
+    assert_eq!(search(&program, copied), 17519904);
+    assert_eq!(search(&program, borrowed), 17519904);
+    let stats = bench::measure(RUNS, || search(&program, copied));
+    println!("{:<12} {}", "copied", stats);
+    let stats = bench::measure(RUNS, || search(&program, borrowed));
+    println!("{:<12} {}", "borrowed", stats);
+}
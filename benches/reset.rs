@@ -0,0 +1,95 @@
+//! Times the day 2 and day 7 searches, making a new computer for every attempt against
+//! resetting the same ones.
+//!
+//! Run it with `cargo bench --bench reset`.
+use aoc2019::bench;
+use aoc2019::int_code::IntCodeComputer;
+use aoc2019::util;
+
+/// How many times each search is timed.
+const RUNS: usize = 20;
+
+/// Finds the noun and verb that make the day 2 program leave 19690720 in cell 0.
+fn day02_new(program: &[i64]) -> i64 {
+    let mut mem = program.to_vec();
+    for noun in 0..100 {
+        for verb in 0..100 {
+            mem[1] = noun;
+            mem[2] = verb;
+            let mut computer = IntCodeComputer::new(mem.clone());
+            computer.run();
+            if computer.memory().read(0) == 19690720 {
+                return 100 * noun + verb;
+            }
+        }
+    }
+    unreachable!()
+}
+
+fn day02_reset(program: &[i64]) -> i64 {
+    let mut mem = program.to_vec();
+    let mut computer = IntCodeComputer::new(mem.clone());
+    for noun in 0..100 {
+        for verb in 0..100 {
+            mem[1] = noun;
+            mem[2] = verb;
+            computer.reset_with(&mem);
+            computer.run();
+            if computer.memory().read(0) == 19690720 {
+                return 100 * noun + verb;
+            }
+        }
+    }
+    unreachable!()
+}
+
+fn amplifiers(program: &[i64]) -> Vec<IntCodeComputer> {
+    (0..5)
+        .map(|_| IntCodeComputer::new(program.to_vec()))
+        .collect()
+}
+
+/// Runs the day 7 amplifiers in a feedback loop for each order of phase settings, either making
+/// new amplifiers each time or resetting the first ones.
+fn day07(program: &[i64], reuse: bool) -> i64 {
+    let mut amps = amplifiers(program);
+    util::Permutations::new(5)
+        .map(|phases| {
+            if reuse {
+                amps.iter_mut().for_each(|amp| amp.reset());
+            } else {
+                amps = amplifiers(program);
+            }
+            for (amp, phase) in amps.iter_mut().zip(phases) {
+                amp.input.write(5 + phase as i64);
+            }
+            let mut signal = 0;
+            while !amps.iter().all(|amp| amp.is_halted()) {
+                for amp in amps.iter_mut() {
+                    amp.input.write(signal);
+                    amp.run();
+                    signal = amp.drain_output()[0];
+                }
+            }
+            signal
+        })
+        .max()
+        .unwrap()
+}
+
+fn main() {
+    let program = util::read_int_code_memory("./input/day02.txt");
+    assert_eq!(day02_new(&program), day02_reset(&program));
+    let stats = bench::measure(RUNS, || day02_new(&program));
+    println!("{:<12} {}", "day02 new", stats);
+    let stats = bench::measure(RUNS, || day02_reset(&program));
+    println!("{:<12} {}", "day02 reset", stats);
+
+    let program = util::read_int_code_memory("./input/day07.txt");
+    assert_eq!(day07(&program, false), 17519904);
+    assert_eq!(day07(&program, true), 17519904);
+    let stats = bench::measure(RUNS, || day07(&program, false));
+    println!("{:<12} {}", "day07 new", stats);
+    let stats = bench::measure(RUNS, || day07(&program, true));
+    println!("{:<12} {}", "day07 reset", stats);
+}
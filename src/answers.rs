@@ -0,0 +1,396 @@
+//! The answers that have been accepted for each puzzle, kept in a file so that every solution can
+//! be checked against them.
+//!
+//! Each line of the file holds the day, the part, a hash of the input the answer is for and the
+//! answer, separated by spaces, and `#` starts a comment line:
+//!
+//! ```text
+//! 1 1 6fd72d3aa02583d2 3405637
+//! 8 2 82f558b5bd4e86e0 *   ***** ...\n*   **    ...\n
+//! ```
+//!
+//! The answer is the rest of the line, with a newline in it written as `\n` and a backslash as
+//! `\\`. Inputs are told apart by their text rather than where they were read from, so an input
+//! has the same answers whether it comes from `./input`, `AOC_INPUT_DIR` or stdin. Trailing
+//! whitespace isn't part of the hash.
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{input, Answer, AocError};
+
+/// Where the answers for the checked in inputs are kept, relative to the root of the repository.
+pub const PATH: &str = "./input/answers.txt";
+
+/// The name of the answers file in the `AOC_INPUT_DIR` directory.
+const FILE_NAME: &str = "answers.txt";
+
+/// Returns where the answers are kept unless told otherwise: `answers.txt` in the directory the
+/// `AOC_INPUT_DIR` environment variable names, next to the inputs, if it's set, and PATH if not.
+pub fn default_path() -> PathBuf {
+    default_path_with(env::var_os(input::DIR_VAR))
+}
+
+/// Returns the path default_path does, with `dir` in place of the AOC_INPUT_DIR variable.
+fn default_path_with(dir: Option<OsString>) -> PathBuf {
+    match dir {
+        Some(dir) => Path::new(&dir).join(FILE_NAME),
+        None => PathBuf::from(PATH),
+    }
+}
+
+/// Status is how an answer compares with the one that was recorded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// There is no answer recorded for the part and input.
+    Missing,
+}
+
+type Key = (u32, u32, String);
+
+/// A line of the answers file.
+enum Line {
+    /// A comment or blank line, kept as it was.
+    Text(String),
+    Answer(Key),
+}
+
+/// Answers holds the recorded answer for each day, part and input.
+#[derive(Default)]
+pub struct Answers {
+    answers: BTreeMap<Key, String>,
+    /// The lines of the file the answers were parsed from, so that saving them keeps the comments
+    /// and the order. Answers that weren't in the file go after them.
+    lines: Vec<Line>,
+}
+
+fn key(day: u32, part: u32, input: &str) -> Key {
+    (day, part, hash(input))
+}
+
+/// Returns the 64 bit FNV-1a hash of `input`, in hex. Unlike `DefaultHasher`, it stays the same
+/// from one build to the next.
+fn hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash: u64, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
+fn is_hash(field: &str) -> bool {
+    field.len() == 16 && field.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> Option<String> {
+    let mut answer = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        answer.push(match c {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                '\\' => '\\',
+                _ => return None,
+            },
+            c => c,
+        });
+    }
+    Some(answer)
+}
+
+impl Answers {
+    /// Parses answers in the format described in the module docs.
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let mut answers = Self::default();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                answers.lines.push(Line::Text(line.to_string()));
+                continue;
+            }
+            let error = |token: &str, message: &str| AocError::parse(i + 1, token, message);
+            let mut fields = line.splitn(4, ' ');
+            let (day, part) = match (fields.next(), fields.next()) {
+                (Some(day), Some(part)) => match (day.parse::<u32>(), part.parse::<u32>()) {
+                    (Ok(day), Ok(part)) => (day, part),
+                    (Err(_), _) => return Err(error(day, "expected a day and a part")),
+                    (_, Err(_)) => return Err(error(part, "expected a day and a part")),
+                },
+                _ => return Err(error("", "expected a day and a part")),
+            };
+            let (input, escaped) = match (fields.next(), fields.next()) {
+                (Some(input), Some(escaped)) => (input, escaped),
+                _ => return Err(error("", "expected an input hash and an answer")),
+            };
+            if !is_hash(input) {
+                return Err(error(input, "expected an input hash and an answer"));
+            }
+            let answer =
+                unescape(escaped).ok_or_else(|| error(escaped, "invalid escape in the answer"))?;
+            let key = (day, part, input.to_ascii_lowercase());
+            if answers.answers.insert(key.clone(), answer).is_some() {
+                return Err(error(input, "the answer is recorded twice"));
+            }
+            answers.lines.push(Line::Answer(key));
+        }
+        Ok(answers)
+    }
+
+    /// Reads the answers file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|error| AocError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        Self::parse(&text).map_err(|e| e.in_file(path))
+    }
+
+    /// Writes the answers to `path`. The comments and answers of the file they were parsed from
+    /// stay where they were, and answers recorded since go after them, in order.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).map_err(|error| AocError::Io {
+            path: path.to_path_buf(),
+            error,
+        })
+    }
+
+    /// Returns the answer recorded for the part and the input with the text `input`.
+    pub fn get(&self, day: u32, part: u32, input: &str) -> Option<&str> {
+        self.answers.get(&key(day, part, input)).map(|a| a.as_str())
+    }
+
+    /// Records `answer` as the right one for the part and the input with the text `input`,
+    /// replacing any answer already recorded.
+    pub fn record(&mut self, day: u32, part: u32, input: &str, answer: &Answer) {
+        self.answers
+            .insert(key(day, part, input), answer.to_string());
+    }
+
+    /// Compares `answer` with the one recorded for the part and input.
+    pub fn check(&self, day: u32, part: u32, input: &str, answer: &Answer) -> Status {
+        match self.get(day, part, input) {
+            None => Status::Missing,
+            Some(expected) if *expected == answer.to_string() => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answer = |f: &mut fmt::Formatter<'_>, key: &Key| {
+            let (day, part, input) = key;
+            writeln!(
+                f,
+                "{} {} {} {}",
+                day,
+                part,
+                input,
+                escape(&self.answers[key])
+            )
+        };
+        if self.lines.is_empty() {
+            writeln!(
+                f,
+                "# day part input-hash answer, with newlines in answers written \\n"
+            )?;
+        }
+        let mut written = BTreeSet::new();
+        for line in self.lines.iter() {
+            match line {
+                Line::Text(text) => writeln!(f, "{}", text)?,
+                Line::Answer(key) => {
+                    answer(f, key)?;
+                    written.insert(key);
+                }
+            }
+        }
+        for key in self.answers.keys().filter(|key| !written.contains(key)) {
+            answer(f, key)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::process;
+
+    use crate::answers::{default_path_with, hash, Answers, Status, PATH};
+    use crate::days::{self, ALL};
+    use crate::input::resolve_with;
+    use crate::{Answer, AocError};
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(8, 2, "012\n", &Answer::Text("* *\n\\ \n".to_string()));
+        answers.record(1, 1, "12\n14\n", &Answer::Number(3405637));
+        let text = answers.to_string();
+        assert!(text.ends_with(&format!(
+            "1 1 {} 3405637\n8 2 {} * *\\n\\\\ \\n\n",
+            hash("12\n14"),
+            hash("012")
+        )));
+        let parsed = Answers::parse(&text).unwrap();
+        assert_eq!(parsed.get(8, 2, "012"), Some("* *\n\\ \n"));
+        assert_eq!(parsed.get(1, 1, "12\n14\n\n"), Some("3405637"));
+        assert_eq!(parsed.get(1, 1, "12\n15\n"), None);
+        assert_eq!(parsed.get(1, 2, "12\n14\n"), None);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(hash("a\n"), hash("a"));
+    }
+
+    #[test]
+    fn test_comments_kept() {
+        let text = format!(
+            "# day 2\n2 2 {} 1\n\n# day 1\n1 1 {} 2\n",
+            hash("2"),
+            hash("1")
+        );
+        let mut answers = Answers::parse(&text).unwrap();
+        answers.record(2, 2, "2", &Answer::Number(5696));
+        answers.record(2, 1, "2", &Answer::Number(4484226));
+        assert_eq!(
+            answers.to_string(),
+            format!(
+                "# day 2\n2 2 {0} 5696\n\n# day 1\n1 1 {1} 2\n2 1 {0} 4484226\n",
+                hash("2"),
+                hash("1")
+            )
+        );
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let error = Answers::load("input/no_such_answers.txt").err().unwrap();
+        assert!(matches!(error, AocError::Io { .. }), "{:?}", error);
+        let path = env::temp_dir().join(format!("aoc-answers-{}.txt", process::id()));
+        fs::write(&path, format!("1 1 {} 2\n1 x\n", hash("1"))).unwrap();
+        let error = Answers::load(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            error.to_string(),
+            format!("{}:2: expected a day and a part", path.display())
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let text = format!("# a comment\n\n2 1 {} 4484226\n", hash("1,0,0,0,99"));
+        let answers = Answers::parse(&text).unwrap();
+        let check = |part, answer: i64| answers.check(2, part, "1,0,0,0,99", &answer.into());
+        assert_eq!(check(1, 4484226), Status::Pass);
+        assert_eq!(
+            check(1, 5696),
+            Status::Fail {
+                expected: "4484226".to_string()
+            }
+        );
+        assert_eq!(check(2, 5696), Status::Missing);
+    }
+
+    #[test]
+    fn test_errors() {
+        let cases = vec![
+            (
+                "1 x 0123456789abcdef 2",
+                "line 1: expected a day and a part",
+                "x",
+            ),
+            (
+                "1 1 0123456789abcdef",
+                "line 1: expected an input hash and an answer",
+                "",
+            ),
+            (
+                "1 1 input/day01.txt 2",
+                "line 1: expected an input hash and an answer",
+                "input/day01.txt",
+            ),
+            (
+                "\n1 1 0123456789abcdef a\\tb",
+                "line 2: invalid escape in the answer",
+                "a\\tb",
+            ),
+            (
+                "1 1 0123456789abcdef 2\n1 1 0123456789ABCDEF 3",
+                "line 2: the answer is recorded twice",
+                "0123456789ABCDEF",
+            ),
+        ];
+        for (text, msg, expected) in cases {
+            let error = Answers::parse(text).err().expect(text);
+            assert_eq!(error.to_string(), msg, "{:?}", text);
+            match error {
+                AocError::Parse { token, .. } => assert_eq!(token, expected, "{:?}", text),
+                e => panic!("unexpected error {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_checked_in_answers() {
+        let answers = Answers::load(PATH).unwrap();
+        for puzzle in ALL.iter() {
+            let path = days::input_path(puzzle.day());
+            let text = fs::read_to_string(&path).unwrap();
+            let input = puzzle.parse(&text).unwrap();
+            for part in 1..=2 {
+                let answer = puzzle.solve(&*input, part).unwrap();
+                let status = answers.check(puzzle.day(), part, &text, &answer);
+                assert_eq!(status, Status::Pass, "day {} part {}", puzzle.day(), part);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert_eq!(default_path_with(None), Path::new(PATH));
+        let dir = env::temp_dir().join("inputs");
+        assert_eq!(
+            default_path_with(Some(dir.clone().into())),
+            dir.join("answers.txt")
+        );
+    }
+
+    #[test]
+    fn test_input_dir() {
+        // An input read from somewhere else has the answers recorded for the checked in one.
+        let answers = Answers::load(PATH).unwrap();
+        let dir = env::temp_dir().join(format!("aoc-answers-dir-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::copy(days::input_path(1), dir.join("day01.txt")).unwrap();
+        let input = resolve_with(1, None, Some(dir.clone().into()));
+        fs::remove_dir_all(&dir).unwrap();
+        let input = input.unwrap();
+        assert_ne!(input.name, days::input_path(1));
+        let parsed = days::get(1).unwrap().parse(&input.text).unwrap();
+        let answer = days::get(1).unwrap().solve(&*parsed, 1).unwrap();
+        assert_eq!(answers.check(1, 1, &input.text, &answer), Status::Pass);
+    }
+}
@@ -0,0 +1,503 @@
+//! Timings of the solutions, which can be saved so that a later run can be compared with them.
+//!
+//! A report is saved as a JSON array with an object for each step of a day, where the step is
+//! parsing the input or solving one of the parts. Each object holds the day, the step, the number
+//! of runs and the fastest, median and slowest times in nanoseconds:
+//!
+//! ```text
+//! [
+//!   {"day": 7, "step": "parse", "runs": 20, "min_ns": 41200, "median_ns": 43100, "max_ns": 60213},
+//!   {"day": 7, "step": "part1", "runs": 20, "min_ns": 80139, "median_ns": 84261, "max_ns": 101502}
+//! ]
+//! ```
+//!
+//! This is also what `aoc bench --json` prints. Other fields are ignored when a report is read.
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::{CharIndices, FromStr};
+use std::time::{Duration, Instant};
+
+use crate::util::json_string;
+use crate::AocError;
+
+/// Stats are the fastest, median and slowest times of a number of runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+/// Runs `f` `runs` times, at least once, and returns how long it took.
+pub fn measure<R>(runs: usize, mut f: impl FnMut() -> R) -> Stats {
+    let mut times: Vec<Duration> = (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    Stats {
+        runs: times.len(),
+        min: times[0],
+        median: times[times.len() / 2],
+        max: times[times.len() - 1],
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.3?}  median {:>10.3?}  max {:>10.3?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+/// Step is what was timed for a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(u32),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u32,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+/// Comparison is how the median time of a step changed from one report to another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u32,
+    pub step: Step,
+    pub old: Duration,
+    pub new: Duration,
+    /// The change in percent, which is positive if the step got slower.
+    pub change: f64,
+    /// Whether the step got slower by more than the threshold.
+    pub regressed: bool,
+}
+
+/// Report holds the timings of a run, in the order they were taken.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub timings: Vec<Timing>,
+}
+
+impl Report {
+    pub fn push(&mut self, day: u32, step: Step, stats: Stats) {
+        self.timings.push(Timing { day, step, stats });
+    }
+
+    pub fn get(&self, day: u32, step: Step) -> Option<&Stats> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.step == step)
+            .map(|t| &t.stats)
+    }
+
+    /// Compares the median times with those in `old`, flagging those that are more than
+    /// `threshold` percent slower. Steps that `old` has no time for are left out.
+    pub fn compare(&self, old: &Report, threshold: f64) -> Vec<Comparison> {
+        self.timings
+            .iter()
+            .filter_map(|t| {
+                let old = old.get(t.day, t.step)?.median;
+                let new = t.stats.median;
+                let change = if old.is_zero() {
+                    0.0
+                } else {
+                    (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0
+                };
+                Some(Comparison {
+                    day: t.day,
+                    step: t.step,
+                    old,
+                    new,
+                    change,
+                    regressed: change > threshold,
+                })
+            })
+            .collect()
+    }
+
+    /// Parses a report in the format described in the module docs.
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        let mut report = Report::default();
+        for (line, fields) in Json::new(text).objects()? {
+            let field = |name: &str| {
+                let message = format!("expected a field '{}'", name);
+                fields
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| AocError::parse(line, "", message))
+            };
+            let step = match field("step")? {
+                Value::String(step) if step == "parse" => Step::Parse,
+                Value::String(step) if step == "part1" => Step::Part(1),
+                Value::String(step) if step == "part2" => Step::Part(2),
+                Value::String(step) | Value::Number(step) => {
+                    let message = format!("unknown step '{}'", step);
+                    return Err(AocError::parse(line, step, message));
+                }
+            };
+            let stats = Stats {
+                runs: field("runs")?.number(line)?,
+                min: Duration::from_nanos(field("min_ns")?.number(line)?),
+                median: Duration::from_nanos(field("median_ns")?.number(line)?),
+                max: Duration::from_nanos(field("max_ns")?.number(line)?),
+            };
+            report.push(field("day")?.number(line)?, step, stats);
+        }
+        Ok(report)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|error| AocError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        Self::parse(&text).map_err(|e| e.in_file(path))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).map_err(|error| AocError::Io {
+            path: path.to_path_buf(),
+            error,
+        })
+    }
+}
+
+impl fmt::Display for Report {
+    /// Writes the report in the format described in the module docs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[")?;
+        for (i, t) in self.timings.iter().enumerate() {
+            writeln!(
+                f,
+                "  {{\"day\": {}, \"step\": {}, \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \
+                 \"max_ns\": {}}}{}",
+                t.day,
+                json_string(&t.step.to_string()),
+                t.stats.runs,
+                t.stats.min.as_nanos(),
+                t.stats.median.as_nanos(),
+                t.stats.max.as_nanos(),
+                if i + 1 < self.timings.len() { "," } else { "" }
+            )?;
+        }
+        writeln!(f, "]")
+    }
+}
+
+/// The fields of an object in a saved report, in order.
+type Object = Vec<(String, Value)>;
+
+/// Value is a field of a saved report, which holds a string or a whole number.
+enum Value {
+    String(String),
+    /// The digits of the number, which are parsed into the type of the field.
+    Number(String),
+}
+
+impl Value {
+    fn number<T: FromStr>(&self, line: usize) -> Result<T, AocError> {
+        match self {
+            Value::Number(digits) => digits.parse().map_err(|_| {
+                AocError::parse(line, digits, format!("number out of range '{}'", digits))
+            }),
+            Value::String(s) => Err(AocError::parse(
+                line,
+                s,
+                format!("expected a number but found '{}'", s),
+            )),
+        }
+    }
+}
+
+/// Json reads the JSON a report is saved as: an array of objects whose fields are strings or
+/// whole numbers.
+struct Json<'a> {
+    text: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    line: usize,
+}
+
+impl<'a> Json<'a> {
+    fn new(text: &'a str) -> Self {
+        Json {
+            text,
+            chars: text.char_indices().peekable(),
+            line: 1,
+        }
+    }
+
+    fn error(&mut self, message: &str) -> AocError {
+        let token = match self.chars.peek() {
+            Some(&(i, _)) => self.text[i..].split_whitespace().next().unwrap_or(""),
+            None => "",
+        };
+        AocError::parse(self.line, token, message)
+    }
+
+    /// Skips whitespace and returns the next character, without taking it.
+    fn peek(&mut self) -> Option<char> {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                return Some(c);
+            }
+            if c == '\n' {
+                self.line += 1;
+            }
+            self.chars.next();
+        }
+        None
+    }
+
+    /// Takes the next character if it is `c`.
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.chars.next();
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), AocError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c)))
+        }
+    }
+
+    /// Reads the objects of the array, with the line each starts on.
+    fn objects(mut self) -> Result<Vec<(usize, Object)>, AocError> {
+        let mut objects = vec![];
+        self.expect('[')?;
+        if !self.eat(']') {
+            loop {
+                self.peek();
+                let line = self.line;
+                objects.push((line, self.object()?));
+                if self.eat(']') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        if self.peek().is_some() {
+            return Err(self.error("expected the end of the report"));
+        }
+        Ok(objects)
+    }
+
+    fn object(&mut self) -> Result<Object, AocError> {
+        let mut fields = vec![];
+        self.expect('{')?;
+        if self.eat('}') {
+            return Ok(fields);
+        }
+        loop {
+            let name = self.string()?;
+            self.expect(':')?;
+            let value = match self.peek() {
+                Some('"') => Value::String(self.string()?),
+                Some(c) if c.is_ascii_digit() => {
+                    let mut digits = String::new();
+                    while let Some(&(_, c)) = self.chars.peek().filter(|(_, c)| c.is_ascii_digit())
+                    {
+                        digits.push(c);
+                        self.chars.next();
+                    }
+                    Value::Number(digits)
+                }
+                _ => return Err(self.error("expected a string or a whole number")),
+            };
+            fields.push((name, value));
+            if self.eat('}') {
+                return Ok(fields);
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn string(&mut self) -> Result<String, AocError> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            let c = match self.chars.next() {
+                Some((_, '"')) => return Ok(s),
+                Some((_, '\\')) => match self.chars.next().map(|(_, c)| c) {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('u') => {
+                        let hex: String = (0..4)
+                            .filter_map(|_| self.chars.next())
+                            .map(|(_, c)| c)
+                            .collect();
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(c) => c,
+                            None => return Err(self.error("invalid escape in a string")),
+                        }
+                    }
+                    _ => return Err(self.error("invalid escape in a string")),
+                },
+                Some((_, '\n')) | None => return Err(self.error("unterminated string")),
+                Some((_, c)) => c,
+            };
+            s.push(c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{measure, Report, Stats, Step};
+    use crate::AocError;
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            runs: 3,
+            min: Duration::from_nanos(median - 1),
+            median: Duration::from_nanos(median),
+            max: Duration::from_nanos(median + 1),
+        }
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut calls = 0;
+        let stats = measure(5, || calls += 1);
+        assert_eq!((calls, stats.runs), (5, 5));
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+        assert_eq!(measure(0, || ()).runs, 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut report = Report::default();
+        report.push(7, Step::Parse, stats(100));
+        report.push(7, Step::Part(2), stats(2000));
+        let text = report.to_string();
+        assert_eq!(
+            text,
+            "[\n  {\"day\": 7, \"step\": \"parse\", \"runs\": 3, \"min_ns\": 99, \
+             \"median_ns\": 100, \"max_ns\": 101},\n  {\"day\": 7, \"step\": \"part2\", \
+             \"runs\": 3, \"min_ns\": 1999, \"median_ns\": 2000, \"max_ns\": 2001}\n]\n"
+        );
+        assert_eq!(Report::parse(&text).unwrap(), report);
+        assert_eq!(Report::parse(" [ ]\n").unwrap(), Report::default());
+        assert_eq!(Report::default().to_string(), "[\n]\n");
+        let text = "[{\"max_ns\":101,\"median_ns\":100,\"min_ns\":99,\"runs\":3,\
+                    \"note\":\"a \\\"b\\\"\\u0021\",\"step\":\"parse\",\"day\":7}]";
+        let mut expected = Report::default();
+        expected.push(7, Step::Parse, stats(100));
+        assert_eq!(Report::parse(text).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut old = Report::default();
+        old.push(1, Step::Part(1), stats(1000));
+        old.push(1, Step::Part(2), stats(1000));
+        let mut new = Report::default();
+        new.push(1, Step::Part(1), stats(1050));
+        new.push(1, Step::Part(2), stats(1200));
+        new.push(2, Step::Part(1), stats(1000));
+        let compared = new.compare(&old, 10.0);
+        assert_eq!(compared.len(), 2);
+        assert!(!compared[0].regressed);
+        assert!((compared[0].change - 5.0).abs() < 1e-9);
+        assert!(compared[1].regressed);
+        assert_eq!(compared[1].step, Step::Part(2));
+    }
+
+    #[test]
+    fn test_errors() {
+        let fields =
+            "\"step\": \"parse\", \"runs\": 1, \"min_ns\": 2, \"median_ns\": 3, \"max_ns\": 4";
+        let cases = vec![
+            ("7 parse 20 1 2 3".to_string(), "line 1: expected '['", "7"),
+            (
+                format!("[\n{{\"day\": 7, {}}},\n{{\"day\": 7}}\n]", fields),
+                "line 3: expected a field 'step'",
+                "",
+            ),
+            (
+                format!("[{{\"day\": -1, {}}}]", fields),
+                "line 1: expected a string or a whole number",
+                "-1,",
+            ),
+            (
+                format!("[{{\"day\": 4294967296, {}}}]", fields),
+                "line 1: number out of range '4294967296'",
+                "4294967296",
+            ),
+            (
+                format!("[{{\"day\": \"7\", {}}}]", fields),
+                "line 1: expected a number but found '7'",
+                "7",
+            ),
+            (
+                format!("[{{\"day\": 1, {}}}]", fields.replace("parse", "part3")),
+                "line 1: unknown step 'part3'",
+                "part3",
+            ),
+            (
+                format!("[{{\"day\": 1, {}}}] x", fields),
+                "line 1: expected the end of the report",
+                "x",
+            ),
+            (
+                "[{\"day\": \"7}]".to_string(),
+                "line 1: unterminated string",
+                "",
+            ),
+            ("[{\"day\" 7}]".to_string(), "line 1: expected ':'", "7}]"),
+        ];
+        for (text, msg, expected) in cases {
+            let error = Report::parse(&text).expect_err(&text);
+            assert_eq!(error.to_string(), msg, "{:?}", text);
+            match error {
+                AocError::Parse { token, .. } => assert_eq!(token, expected, "{:?}", text),
+                e => panic!("unexpected error {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stats_display() {
+        assert_eq!(
+            stats(2000).to_string(),
+            "min    1.999µs  median    2.000µs  max    2.001µs"
+        );
+    }
+}
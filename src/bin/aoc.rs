@@ -0,0 +1,438 @@
+use std::any::Any;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc2019::answers::{self, Answers, Status};
+use aoc2019::bench::{self, Report, Step};
+use aoc2019::input::{self, Input};
+use aoc2019::util::json_string;
+use aoc2019::{days, Answer, AocError, Puzzle};
+
+const USAGE: &str = "usage: aoc run DAY|all [--part N] [--input PATH] [--json]
+       aoc verify [DAY|all] [--part N] [--input PATH] [--answers FILE] [--json]
+       aoc record DAY|all [--part N] [--input PATH] [--answers FILE]
+       aoc bench [DAY|all] [--part N] [--input PATH] [--runs N] [--save FILE] [--compare FILE]
+                 [--threshold PERCENT] [--json]
+       aoc list [--json]
+
+run solves both parts of DAY, or only part N, reading the puzzle input from PATH, or from stdin if
+PATH is -. Otherwise the input is dayNN.txt in $AOC_INPUT_DIR if that is set, then the input built
+into the binary with the embedded-inputs feature, then ./input/dayNN.txt. all solves every day
+from its default input. list prints the days there are solutions for.
+
+Each answer is printed as `day DAY part N: ANSWER`. An answer that takes more than one line, like
+a picture, starts on the next line instead, with every line of it indented by two spaces. With
+--json, the results are printed as a JSON array of objects instead.
+
+verify solves every day, or DAY, and prints a table comparing each answer with the one recorded
+for its input in the answers file, and how long it took. The answers file is FILE if --answers is
+given, answers.txt in $AOC_INPUT_DIR if that is set, and ./input/answers.txt otherwise. verify
+fails if any answer is wrong or has none recorded. record solves the same way and records the
+answers as the right ones, replacing any that were recorded before, once the puzzle has accepted
+them.
+
+bench times parsing the input and solving each part over N runs, 20 by default, and prints the
+fastest, median and slowest times. --save writes the timings to FILE as a JSON array with an
+object for each step, and --compare reads timings saved before and shows how each median changed,
+failing if any step got slower by more than PERCENT, which is 10 by default. With --json, the
+timings are printed as that JSON array instead.";
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Verify,
+    Record,
+    Bench,
+    List,
+}
+
+struct Args {
+    command: Command,
+    /// The days to solve. All of them if it's empty.
+    days: Vec<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    answers: PathBuf,
+    json: bool,
+    runs: usize,
+    save: Option<String>,
+    compare: Option<String>,
+    threshold: f64,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = env::args().skip(1);
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("verify") => Command::Verify,
+        Some("record") => Command::Record,
+        Some("bench") => Command::Bench,
+        Some("list") => Command::List,
+        Some("-h") | Some("--help") => return Err(String::new()),
+        Some(command) => return Err(format!("unknown command {}", command)),
+        None => return Err("missing command".to_string()),
+    };
+    let mut target = None;
+    let mut parsed = Args {
+        command,
+        days: vec![],
+        part: None,
+        input: None,
+        answers: answers::default_path(),
+        json: false,
+        runs: 20,
+        save: None,
+        compare: None,
+        threshold: 10.0,
+    };
+    let mut answers = None;
+    let mut benching = false;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--part" => match value()?.as_str() {
+                "1" => parsed.part = Some(1),
+                "2" => parsed.part = Some(2),
+                other => return Err(format!("--part: no part {}", other)),
+            },
+            "--input" => parsed.input = Some(value()?),
+            "--answers" => answers = Some(value()?),
+            "--json" => parsed.json = true,
+            "--runs" => {
+                let runs = value()?;
+                parsed.runs = match runs.parse() {
+                    Ok(runs) if runs > 0 => runs,
+                    _ => return Err(format!("--runs: invalid number of runs {}", runs)),
+                };
+                benching = true;
+            }
+            "--save" => {
+                parsed.save = Some(value()?);
+                benching = true;
+            }
+            "--compare" => {
+                parsed.compare = Some(value()?);
+                benching = true;
+            }
+            "--threshold" => {
+                let threshold = value()?;
+                parsed.threshold = match threshold.parse() {
+                    Ok(threshold) if threshold >= 0.0 => threshold,
+                    _ => return Err(format!("--threshold: invalid percentage {}", threshold)),
+                };
+                benching = true;
+            }
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown argument {}", arg)),
+            _ if target.is_none() => target = Some(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+    if parsed.command == Command::List {
+        if target.is_some() || parsed.part.is_some() || parsed.input.is_some() || answers.is_some()
+        {
+            return Err("list only takes --json".to_string());
+        }
+        return Ok(parsed);
+    }
+    if benching && parsed.command != Command::Bench {
+        return Err("only bench takes --runs, --save, --compare and --threshold".to_string());
+    }
+    match target.as_deref() {
+        None if parsed.command == Command::Run || parsed.command == Command::Record => {
+            return Err("missing DAY".to_string())
+        }
+        None | Some("all") => (),
+        Some(day) => match day.parse() {
+            Ok(day) if days::get(day).is_some() => parsed.days.push(day),
+            _ => return Err(format!("no solution for day {}", day)),
+        },
+    }
+    if parsed.input.is_some() && parsed.days.is_empty() {
+        return Err("--input needs a single DAY".to_string());
+    }
+    if let Some(answers) = answers {
+        if parsed.command == Command::Run || parsed.command == Command::Bench {
+            return Err("only verify and record take --answers".to_string());
+        }
+        parsed.answers = PathBuf::from(answers);
+    }
+    if parsed.command == Command::Record && parsed.json {
+        return Err("record doesn't take --json".to_string());
+    }
+    Ok(parsed)
+}
+
+/// Prints JSON objects, each given as its fields, as an array with one object on each line.
+fn print_json(objects: &[Vec<(&str, String)>]) {
+    println!("[");
+    for (i, fields) in objects.iter().enumerate() {
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|(name, value)| format!("{}: {}", json_string(name), value))
+            .collect();
+        let comma = if i + 1 < objects.len() { "," } else { "" };
+        println!("  {{{}}}{}", fields.join(", "), comma);
+    }
+    println!("]");
+}
+
+/// Returns the puzzles that `args` asks for.
+fn puzzles(args: &Args) -> impl Iterator<Item = &'static dyn Puzzle> + '_ {
+    days::ALL
+        .iter()
+        .copied()
+        .filter(move |p| args.days.is_empty() || args.days.contains(&p.day()))
+}
+
+/// Reads the input for `puzzle`.
+fn read_input(args: &Args, puzzle: &dyn Puzzle) -> Input {
+    input::resolve(puzzle.day(), args.input.as_deref()).unwrap_or_else(|e| exit_with(e))
+}
+
+/// Parses the input for `puzzle`, read from `path`.
+fn parse_input(puzzle: &dyn Puzzle, path: &str, text: &str) -> Box<dyn Any> {
+    match puzzle.parse(text) {
+        Ok(input) => input,
+        Err(e) => exit_with(e.in_file(path)),
+    }
+}
+
+fn exit_with(error: AocError) -> ! {
+    eprintln!("{}", error);
+    process::exit(1);
+}
+
+/// Returns the parts that `args` asks for.
+fn parts(args: &Args) -> impl Iterator<Item = u32> + '_ {
+    (1..=2).filter(move |&part| args.part.is_none_or(|p| p == part))
+}
+
+/// Solves the parts that `args` asks for, calling `f` with the puzzle, the input, the part, the
+/// answer and how long it took to find. Parsing the input isn't part of the time.
+fn solve_each(args: &Args, mut f: impl FnMut(&dyn Puzzle, &Input, u32, Answer, Duration)) {
+    for puzzle in puzzles(args) {
+        let text = read_input(args, puzzle);
+        let input = parse_input(puzzle, &text.name, &text.text);
+        for part in parts(args) {
+            let start = Instant::now();
+            let answer = match puzzle.solve(&*input, part) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("day {} part {}: {}", puzzle.day(), part, e);
+                    process::exit(1);
+                }
+            };
+            f(puzzle, &text, part, answer, start.elapsed());
+        }
+    }
+}
+
+fn run(args: &Args) {
+    let mut results = vec![];
+    solve_each(args, |puzzle, _, part, answer, _| {
+        if !args.json {
+            days::print_answer(puzzle.day(), part, &answer);
+        }
+        results.push(vec![
+            ("day", puzzle.day().to_string()),
+            ("part", part.to_string()),
+            ("answer", json_string(&answer.to_string())),
+        ]);
+    });
+    if args.json {
+        print_json(&results);
+    }
+}
+
+fn load_answers(path: &Path) -> Answers {
+    Answers::load(path).unwrap_or_else(|e| exit_with(e))
+}
+
+/// Writes an answer on one line, for the table.
+fn one_line(answer: &str) -> String {
+    answer.replace('\n', "\\n")
+}
+
+fn verify(args: &Args) {
+    let answers = load_answers(&args.answers);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut results = vec![];
+    if !args.json {
+        println!("day  part  status   {:>10}  answer", "time");
+    }
+    solve_each(args, |puzzle, input, part, answer, time| {
+        let status = answers.check(puzzle.day(), part, &input.text, &answer);
+        let answer = answer.to_string();
+        let (name, expected) = match status {
+            Status::Pass => {
+                passed += 1;
+                ("pass", None)
+            }
+            Status::Fail { expected } => {
+                failed += 1;
+                ("FAIL", Some(expected))
+            }
+            Status::Missing => {
+                missing += 1;
+                ("missing", None)
+            }
+        };
+        if !args.json {
+            let mut row = format!(
+                "{:>3}  {:>4}  {:<7}  {:>10.1?}  {}",
+                puzzle.day(),
+                part,
+                name,
+                time,
+                one_line(&answer)
+            );
+            if let Some(expected) = &expected {
+                row += &format!(", expected {}", one_line(expected));
+            }
+            println!("{}", row);
+        }
+        let mut result = vec![
+            ("day", puzzle.day().to_string()),
+            ("part", part.to_string()),
+            ("input", json_string(&input.name)),
+            ("status", json_string(name)),
+            ("answer", json_string(&answer)),
+            ("nanos", time.as_nanos().to_string()),
+        ];
+        if let Some(expected) = &expected {
+            result.push(("expected", json_string(expected)));
+        }
+        results.push(result);
+    });
+    if args.json {
+        print_json(&results);
+    } else {
+        println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    }
+    if failed > 0 || missing > 0 {
+        process::exit(1);
+    }
+}
+
+fn record(args: &Args) {
+    // Recording into a file that doesn't exist yet starts it.
+    let mut answers = if args.answers.exists() {
+        load_answers(&args.answers)
+    } else {
+        Answers::default()
+    };
+    solve_each(args, |puzzle, input, part, answer, _| {
+        answers.record(puzzle.day(), part, &input.text, &answer);
+        days::print_answer(puzzle.day(), part, &answer);
+    });
+    if let Err(e) = answers.save(&args.answers) {
+        exit_with(e);
+    }
+}
+
+fn bench(args: &Args) {
+    let old = args
+        .compare
+        .as_ref()
+        .map(|path| Report::load(path).unwrap_or_else(|e| exit_with(e)));
+    let mut report = Report::default();
+    for puzzle in puzzles(args) {
+        let text = read_input(args, puzzle);
+        let input = parse_input(puzzle, &text.name, &text.text);
+        report.push(
+            puzzle.day(),
+            Step::Parse,
+            bench::measure(args.runs, || puzzle.parse(&text.text)),
+        );
+        for part in parts(args) {
+            report.push(
+                puzzle.day(),
+                Step::Part(part),
+                bench::measure(args.runs, || puzzle.solve(&*input, part)),
+            );
+        }
+    }
+    if let Some(path) = &args.save {
+        if let Err(e) = report.save(path) {
+            exit_with(e);
+        }
+    }
+    let compared = old
+        .as_ref()
+        .map(|old| report.compare(old, args.threshold))
+        .unwrap_or_default();
+    if args.json {
+        print!("{}", report);
+    } else {
+        println!(
+            "day  step   {:>10}  {:>10}  {:>10}{}",
+            "min",
+            "median",
+            "max",
+            if old.is_some() { "  change" } else { "" }
+        );
+        for t in report.timings.iter() {
+            let mut row = format!(
+                "{:>3}  {:<5}  {:>10.1?}  {:>10.1?}  {:>10.1?}",
+                t.day, t.step, t.stats.min, t.stats.median, t.stats.max
+            );
+            if let Some(c) = compared.iter().find(|c| c.day == t.day && c.step == t.step) {
+                row += &format!("  {:>+6.1}%", c.change);
+                if c.regressed {
+                    row += "  REGRESSION";
+                }
+            }
+            println!("{}", row);
+        }
+    }
+    let regressions = compared.iter().filter(|c| c.regressed).count();
+    if regressions > 0 {
+        eprintln!(
+            "{} steps got more than {}% slower",
+            regressions, args.threshold
+        );
+        process::exit(1);
+    }
+}
+
+fn list(args: &Args) {
+    if args.json {
+        let days: Vec<_> = days::ALL
+            .iter()
+            .map(|p| {
+                vec![
+                    ("day", p.day().to_string()),
+                    ("title", json_string(p.title())),
+                ]
+            })
+            .collect();
+        print_json(&days);
+        return;
+    }
+    for puzzle in days::ALL.iter() {
+        println!("day {}: {}", puzzle.day(), puzzle.title());
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    match args.command {
+        Command::Run => run(&args),
+        Command::Verify => verify(&args),
+        Command::Record => record(&args),
+        Command::Bench => bench(&args),
+        Command::List => list(&args),
+    }
+}
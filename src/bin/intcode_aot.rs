@@ -0,0 +1,75 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use aoc2019::int_code::transpile::{transpile, Options};
+use aoc2019::int_code::{loader, patch};
+
+const USAGE: &str = "usage: intcode_aot PROGRAM [--name STRUCT] [--crate PATH] [--output FILE]
+                   [--patch FILE]...
+
+Compiles PROGRAM, in any format the loader understands, to Rust source for a struct named STRUCT,
+Machine by default, that runs it. The source refers to this crate as PATH, aoc2019 by default, and
+goes to stdout unless --output is given. Each --patch file is applied to PROGRAM in turn before it
+is compiled.";
+
+fn parse_args() -> Result<(String, Options, Option<String>, Vec<String>), String> {
+    let mut program = None;
+    let mut options = Options::default();
+    let mut output = None;
+    let mut patches = vec![];
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--name" => options.struct_name = value()?,
+            "--crate" => options.crate_path = value()?,
+            "--output" => output = Some(value()?),
+            "--patch" => patches.push(value()?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown argument {}", arg)),
+            _ if program.is_none() => program = Some(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+    Ok((program.ok_or("missing PROGRAM")?, options, output, patches))
+}
+
+fn main() {
+    let (path, options, output, patches) = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    let mut program = match loader::load(&path) {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    for path in patches.iter() {
+        match patch::load(path) {
+            Ok(patch) => patch.apply(&mut program),
+            Err(e) => {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+        }
+    }
+    let source = transpile(&program, &options);
+    match output {
+        Some(output) => {
+            if let Err(e) = fs::write(&output, source) {
+                eprintln!("{}: {}", output, e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", source),
+    }
+}
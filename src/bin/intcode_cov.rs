@@ -0,0 +1,134 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use aoc2019::int_code::coverage::Coverage;
+use aoc2019::int_code::{loader, patch};
+
+const USAGE: &str = "usage: intcode_cov PROGRAM [--input VALUES]... [--budget N] [--listing FILE]
+                   [--lcov FILE] [--patch FILE]...
+
+Runs PROGRAM once for each --input, a comma separated list of input values, or once with no input
+if there are none. Prints which instructions and branch directions the runs exercised, together,
+and writes an annotated listing of the program. The listing goes to stdout unless --listing is
+given. --lcov writes an lcov tracefile for the listing, and needs --listing. Each --patch file is
+applied to PROGRAM in turn before it runs.";
+
+struct Args {
+    program: String,
+    inputs: Vec<Vec<i64>>,
+    budget: u64,
+    listing: Option<String>,
+    lcov: Option<String>,
+    patches: Vec<String>,
+}
+
+fn parse_values(s: &str) -> Result<Vec<i64>, String> {
+    if s.is_empty() {
+        return Ok(vec![]);
+    }
+    s.split(',')
+        .map(|v| {
+            v.trim()
+                .parse()
+                .map_err(|e| format!("--input {}: {}", s, e))
+        })
+        .collect()
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut program = None;
+    let mut parsed = Args {
+        program: String::new(),
+        inputs: vec![],
+        budget: 10_000_000,
+        listing: None,
+        lcov: None,
+        patches: vec![],
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--input" => parsed.inputs.push(parse_values(&value()?)?),
+            "--budget" => {
+                parsed.budget = value()?.parse().map_err(|e| format!("--budget: {}", e))?
+            }
+            "--listing" => parsed.listing = Some(value()?),
+            "--lcov" => parsed.lcov = Some(value()?),
+            "--patch" => parsed.patches.push(value()?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown argument {}", arg)),
+            _ if program.is_none() => program = Some(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+    parsed.program = program.ok_or("missing PROGRAM")?;
+    if parsed.lcov.is_some() && parsed.listing.is_none() {
+        return Err("--lcov needs --listing".to_string());
+    }
+    if parsed.inputs.is_empty() {
+        parsed.inputs.push(vec![]);
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    let mut program = match loader::load(&args.program) {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("{}: {}", args.program, e);
+            process::exit(1);
+        }
+    };
+    for path in args.patches.iter() {
+        match patch::load(path) {
+            Ok(patch) => patch.apply(&mut program),
+            Err(e) => {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+        }
+    }
+    let mut coverage = Coverage::new(program);
+    for input in args.inputs.iter() {
+        match coverage.run(input, args.budget) {
+            Ok(outcome) => eprintln!("input {:?}: {:?}", input, outcome),
+            Err(e) => eprintln!("input {:?}: {}", input, e),
+        }
+    }
+    let summary = coverage.summary();
+    eprintln!(
+        "instructions: {}/{}, branch directions: {}/{}",
+        summary.instructions_hit, summary.instructions, summary.branches_hit, summary.branches
+    );
+
+    let listing = coverage.listing();
+    let path = match args.listing {
+        Some(path) => path,
+        None => {
+            print!("{}", listing);
+            return;
+        }
+    };
+    let write = |path: &str, contents: String| {
+        if let Err(e) = fs::write(path, contents) {
+            eprintln!("{}: {}", path, e);
+            process::exit(1);
+        }
+    };
+    write(&path, listing);
+    if let Some(lcov) = args.lcov {
+        write(&lcov, coverage.lcov(&path));
+    }
+}
@@ -0,0 +1,79 @@
+use std::env;
+use std::panic;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc2019::int_code::{fuzz, loader};
+
+const USAGE: &str =
+    "usage: intcode_fuzz [--seed N] [--iterations N] [--budget N] [--corpus FILE]...
+
+Runs randomly generated and mutated Intcode programs against IntCodeComputer and reports how
+each one ended. Programs given with --corpus are used as seeds for mutation. Any panic is a bug;
+the smallest case that reproduces each one is printed and the exit code is 1.";
+
+fn parse_args() -> Result<fuzz::Config, String> {
+    let mut config = fuzz::Config {
+        seed: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        ..fuzz::Config::default()
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--seed" => config.seed = value()?.parse().map_err(|e| format!("--seed: {}", e))?,
+            "--iterations" => {
+                config.iterations = value()?
+                    .parse()
+                    .map_err(|e| format!("--iterations: {}", e))?
+            }
+            "--budget" => {
+                config.budget = value()?.parse().map_err(|e| format!("--budget: {}", e))?
+            }
+            "--corpus" => {
+                let path = value()?;
+                let program = loader::load(&path).map_err(|e| format!("{}: {}", path, e))?;
+                config.seeds.push(program)
+            }
+            "-h" | "--help" => return Err(String::new()),
+            _ => return Err(format!("unknown argument {}", arg)),
+        }
+    }
+    Ok(config)
+}
+
+fn main() {
+    let config = match parse_args() {
+        Ok(config) => config,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    // Panics are caught and reported by the harness, so keep the default hook from printing a
+    // backtrace for every one of them.
+    panic::set_hook(Box::new(|_| {}));
+
+    println!(
+        "seed {}, {} iterations, budget {}",
+        config.seed, config.iterations, config.budget
+    );
+    let report = fuzz::fuzz(&config);
+    for (outcome, count) in report.outcomes.iter() {
+        println!("{:>8}  {}", count, outcome);
+    }
+    if report.crashes.is_empty() {
+        return;
+    }
+    println!("\n{} distinct panics:", report.crashes.len());
+    for (case, msg) in report.crashes.iter() {
+        println!("\n{}\n{}", msg, case);
+    }
+    process::exit(1);
+}
@@ -0,0 +1,129 @@
+use std::env;
+use std::process;
+
+use aoc2019::int_code::optimize::{optimize, verify};
+use aoc2019::int_code::{loader, patch};
+
+const USAGE: &str = "usage: intcode_opt PROGRAM [--output FILE] [--verify] [--input VALUES]...
+                   [--budget N] [--patch FILE]...
+
+Optimizes PROGRAM, in any format the loader understands, and prints what changed. The optimized
+program is written to --output as an image, or printed as values if there is no --output.
+--verify runs both programs once for each --input, a comma separated list of input values, or
+once with no input if there are none, for at most --budget instructions, and fails if they behave
+differently. Each --patch file is applied to PROGRAM in turn before it is optimized.";
+
+struct Args {
+    program: String,
+    output: Option<String>,
+    verify: bool,
+    inputs: Vec<Vec<i64>>,
+    budget: u64,
+    patches: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut program = None;
+    let mut parsed = Args {
+        program: String::new(),
+        output: None,
+        verify: false,
+        inputs: vec![],
+        budget: 10_000_000,
+        patches: vec![],
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--output" => parsed.output = Some(value()?),
+            "--verify" => parsed.verify = true,
+            "--input" => {
+                let values = value()?;
+                let input = if values.is_empty() {
+                    vec![]
+                } else {
+                    loader::parse_values(&values).map_err(|e| format!("--input: {}", e))?
+                };
+                parsed.inputs.push(input);
+            }
+            "--budget" => {
+                parsed.budget = value()?.parse().map_err(|e| format!("--budget: {}", e))?
+            }
+            "--patch" => parsed.patches.push(value()?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown argument {}", arg)),
+            _ if program.is_none() => program = Some(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+    parsed.program = program.ok_or("missing PROGRAM")?;
+    if parsed.inputs.is_empty() {
+        parsed.inputs.push(vec![]);
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    let mut program = match loader::load(&args.program) {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("{}: {}", args.program, e);
+            process::exit(1);
+        }
+    };
+    for path in args.patches.iter() {
+        match patch::load(path) {
+            Ok(patch) => patch.apply(&mut program),
+            Err(e) => {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+        }
+    }
+    let optimized = optimize(&program);
+    if let Some(blocker) = &optimized.blocked {
+        eprintln!("not optimized: {}", blocker);
+    }
+    for change in optimized.changes.iter() {
+        eprintln!("{}", change);
+    }
+    eprintln!(
+        "{} cells, optimized {}",
+        program.len(),
+        optimized.program.len()
+    );
+
+    if args.verify {
+        match verify(&program, &optimized.program, &args.inputs, args.budget) {
+            Ok(compared) => eprintln!("verified on {} of {} inputs", compared, args.inputs.len()),
+            Err(mismatch) => {
+                eprintln!("verification failed: {}", mismatch);
+                process::exit(1);
+            }
+        }
+    }
+
+    match args.output {
+        Some(path) => {
+            if let Err(e) = loader::save_image(&path, &optimized.program) {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+        }
+        None => {
+            let values: Vec<String> = optimized.program.iter().map(|v| v.to_string()).collect();
+            println!("{}", values.join(","));
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::env;
+use std::io::{self, Read, Write};
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc2019::int_code::tui::{Key, Player, View};
+use aoc2019::int_code::{loader, patch};
+
+const USAGE: &str =
+    "usage: intcode_tui PROGRAM [--input VALUES] [--speed N] [--columns N] [--rows N]
+                   [--steps N] [--patch FILE]...
+
+Steps through PROGRAM, in any format the loader understands, showing its memory around the
+instruction pointer, the relative base and the queued input and output. --input is a comma
+separated list of input values. It starts paused; space plays and pauses, s runs one instruction,
++ and - change the speed, which starts at --speed instructions per second, r restarts and q quits.
+Each --patch file is applied to PROGRAM in turn before it starts.
+
+With --steps, it doesn't read the keyboard: it prints the state after each of the first N
+instructions, which works without a terminal too.";
+
+/// How often the screen is redrawn.
+const FRAME: Duration = Duration::from_millis(33);
+
+struct Args {
+    program: String,
+    input: Vec<i64>,
+    speed: u64,
+    view: View,
+    steps: Option<u64>,
+    patches: Vec<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut program = None;
+    let mut parsed = Args {
+        program: String::new(),
+        input: vec![],
+        speed: 10,
+        view: View::default(),
+        steps: None,
+        patches: vec![],
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--input" => {
+                let values = value()?;
+                if !values.is_empty() {
+                    parsed.input =
+                        loader::parse_values(&values).map_err(|e| format!("--input: {}", e))?;
+                }
+            }
+            "--speed" => parsed.speed = value()?.parse().map_err(|e| format!("--speed: {}", e))?,
+            "--columns" => {
+                parsed.view.columns = value()?.parse().map_err(|e| format!("--columns: {}", e))?
+            }
+            "--rows" => {
+                parsed.view.rows = value()?.parse().map_err(|e| format!("--rows: {}", e))?
+            }
+            "--steps" => {
+                parsed.steps = Some(value()?.parse().map_err(|e| format!("--steps: {}", e))?)
+            }
+            "--patch" => parsed.patches.push(value()?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with('-') => return Err(format!("unknown argument {}", arg)),
+            _ if program.is_none() => program = Some(arg),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+    parsed.program = program.ok_or("missing PROGRAM")?;
+    Ok(parsed)
+}
+
+/// Runs stty on the terminal, returning what it printed.
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// RawTerminal puts the terminal into a mode where keys can be read one at a time without
+/// waiting, and draws on the alternate screen. Dropping it puts everything back.
+struct RawTerminal {
+    saved: String,
+}
+
+impl RawTerminal {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "0", "time", "0"])?;
+        print!("\x1b[?1049h\x1b[?25l");
+        Ok(Self { saved })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\x1b[?1049l");
+        let _ = io::stdout().flush();
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn play(mut player: Player) -> io::Result<()> {
+    let _terminal = RawTerminal::enable()?;
+    let mut stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut keys = [0; 16];
+    let mut last = Instant::now();
+    loop {
+        let n = stdin.read(&mut keys)?;
+        for key in keys[..n].iter().filter_map(|b| Key::parse(*b)) {
+            if key == Key::Quit {
+                return Ok(());
+            }
+            player.press(key);
+        }
+        let now = Instant::now();
+        player.tick(now - last);
+        last = now;
+
+        // Each line clears whatever the previous frame left to its right.
+        let frame = player.frame().replace('\n', "\x1b[K\n");
+        write!(stdout, "\x1b[H{}\x1b[J", frame)?;
+        stdout.flush()?;
+        thread::sleep(FRAME.saturating_sub(now.elapsed()));
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            if !msg.is_empty() {
+                eprintln!("{}\n", msg);
+            }
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    let mut program = match loader::load(&args.program) {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("{}: {}", args.program, e);
+            process::exit(1);
+        }
+    };
+    for path in args.patches.iter() {
+        match patch::load(path) {
+            Ok(patch) => patch.apply(&mut program),
+            Err(e) => {
+                eprintln!("{}: {}", path, e);
+                process::exit(1);
+            }
+        }
+    }
+    let mut player = Player::new(program, args.input, args.view);
+    player.set_speed(args.speed);
+
+    if let Some(steps) = args.steps {
+        print!("{}", player.frame());
+        for _ in 0..steps {
+            if !player.step() {
+                break;
+            }
+            print!("\n{}", player.frame());
+        }
+        return;
+    }
+    if let Err(e) = play(player) {
+        eprintln!("terminal: {}", e);
+        process::exit(1);
+    }
+}
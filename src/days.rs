@@ -0,0 +1,170 @@
+//! The solutions for each day's puzzle, and a registry of them all.
+use std::env;
+use std::process;
+
+use crate::int_code::{IntCodeComputer, RunOutcome};
+use crate::{input, Answer, AocError, Puzzle};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+
+/// Every day there is a solution for, in order.
+pub const ALL: [&dyn Puzzle; 9] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+];
+
+/// Returns the solution for `day`, if there is one.
+pub fn get(day: u32) -> Option<&'static dyn Puzzle> {
+    ALL.iter().find(|p| p.day() == day).copied()
+}
+
+/// Returns where the input for `day` is kept, relative to the root of the repository.
+pub fn input_path(day: u32) -> String {
+    format!("./input/day{:02}.txt", day)
+}
+
+/// The most instructions a day's Intcode program runs at a time before it is taken to be stuck.
+const BUDGET: u64 = 10_000_000;
+
+/// Runs an Intcode program until it halts or needs input, returning an error if it does something
+/// the computer can't execute or seems to be stuck in a loop.
+fn run_int_code(computer: &mut IntCodeComputer) -> Result<RunOutcome, AocError> {
+    match computer.run_with_budget(BUDGET) {
+        Ok(RunOutcome::BudgetExhausted) => Err(AocError::unsolvable(format!(
+            "the program ran {} instructions without halting or asking for input",
+            BUDGET
+        ))),
+        Ok(outcome) => Ok(outcome),
+        Err(e) => Err(AocError::unsolvable(format!("the program failed: {}", e))),
+    }
+}
+
+/// Prints an answer as `day DAY part N: ANSWER`. An answer of more than one line starts on the
+/// next line instead, with each line of it indented by two spaces.
+pub fn print_answer(day: u32, part: u32, answer: &Answer) {
+    let answer = answer.to_string();
+    let answer = answer.trim_end_matches('\n');
+    if answer.contains('\n') {
+        println!("day {} part {}:", day, part);
+        for line in answer.lines() {
+            println!("  {}", line);
+        }
+    } else {
+        println!("day {} part {}: {}", day, part, answer);
+    }
+}
+
+/// Solves both parts of a puzzle from its input and prints the answers. This is all each day's
+/// own binary does. The input is read from the path given as the first argument, if there is
+/// one, as input::resolve describes. If it can't be read or parsed, or a part has no answer, it
+/// says why and exits.
+pub fn main(puzzle: &dyn Puzzle) {
+    let path = env::args().nth(1);
+    let parsed = input::resolve(puzzle.day(), path.as_deref()).and_then(|input| {
+        puzzle
+            .parse(&input.text)
+            .map_err(|e| e.in_file(&input.name))
+    });
+    let input = match parsed {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    for part in 1..=2 {
+        match puzzle.solve(&*input, part) {
+            Ok(answer) => print_answer(puzzle.day(), part, &answer),
+            Err(e) => {
+                eprintln!("day {} part {}: {}", puzzle.day(), part, e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days::{get, ALL};
+    use crate::{Answer, AocError};
+
+    #[test]
+    fn test_registry() {
+        for (i, puzzle) in ALL.iter().enumerate() {
+            assert_eq!(puzzle.day(), i as u32 + 1);
+        }
+        assert_eq!(get(6).unwrap().title(), "Universal Orbit Map");
+        assert!(get(10).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let day01 = get(1).unwrap();
+        let input = day01.parse("12\n1969\n").unwrap();
+        assert_eq!(day01.solve(&*input, 1).unwrap(), Answer::Number(656));
+        assert_eq!(day01.solve(&*input, 2).unwrap(), Answer::Number(968));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = vec![
+            (1, "", "", "line 1: the input is empty"),
+            (1, "12\n1x\n", "1x", "line 2: invalid mass '1x'"),
+            (2, "1,0,0,3,99,", "", "line 1: expected a value after ','"),
+            (3, "R8,U5", "", "line 2: expected two wires but found 1"),
+            (3, "R8,U5\nU7,Q6", "Q6", "line 2: invalid segment 'Q6'"),
+            (
+                4,
+                "123456",
+                "123456",
+                "line 1: expected a range like 123456-654321 but found '123456'",
+            ),
+            (
+                6,
+                "COM)B\nB-C",
+                "B-C",
+                "line 2: expected an orbit like A)B but found 'B-C'",
+            ),
+            (8, "0123x", "x", "line 1: invalid digit 'x'"),
+            (
+                8,
+                "0123",
+                "0123",
+                "line 1: the image has 4 digits, which isn't a whole number of 25x6 layers",
+            ),
+            (9, "\n", "", "line 1: no values in program"),
+        ];
+        for (day, text, expected, msg) in cases {
+            let error = get(day).unwrap().parse(text).expect_err(text);
+            assert_eq!(error.to_string(), msg, "day {}: {:?}", day, text);
+            match error {
+                AocError::Parse { token, .. } => {
+                    assert_eq!(token, expected, "day {}: {:?}", day, text)
+                }
+                e => panic!("unexpected error {:?}", e),
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input parsed by another puzzle")]
+    fn test_solve_other_input() {
+        let input = get(1).unwrap().parse("12").unwrap();
+        let _ = get(2).unwrap().solve(&*input, 1);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{util, Answer, AocError, Solution};
+
+/// Fuel is calculated using integer arithmetic: max(mass / 3 - 2, 0).
+fn fuel_for_mass(mass: usize) -> usize {
+    const OFFSET: usize = 2;
+    let fuel = mass / 3;
+    // Can't have negative fuel
+    if fuel <= OFFSET {
+        return 0;
+    }
+    fuel - OFFSET
+}
+
+/// Returns the fuel required for both the given mass as well as the fuel needed for the fuel itself.
+fn fuel_for_payload(payload: usize) -> usize {
+    let fuel_mass = fuel_for_mass(payload);
+    // Note that if the given mass requires no fuel we don't need to calculate the mass for the fuel.
+    if fuel_mass == 0 {
+        return fuel_mass;
+    }
+    fuel_mass + fuel_for_payload(fuel_mass)
+}
+
+/// collapse evaluates the mass_fn for each module's mass, and then returns the sum of the
+/// results.
+fn collapse(masses: &[usize], mass_fn: fn(usize) -> usize) -> usize {
+    masses
+        .iter()
+        // Apply the mass function
+        .map(|&mass| mass_fn(mass))
+        // sum. Note that we exclude a ; so this whole statement is an expression
+        .sum()
+}
+
+pub fn part1(masses: &[usize]) -> usize {
+    collapse(masses, fuel_for_mass)
+}
+
+pub fn part2(masses: &[usize]) -> usize {
+    collapse(masses, fuel_for_payload)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The mass of each module.
+    type Input = Vec<usize>;
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "The Tyranny of the Rocket Equation"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<usize>, AocError> {
+        let lines = util::lines(input)?;
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse::<usize>()
+                    .map_err(|_| AocError::parse(i + 1, line, format!("invalid mass '{}'", line)))
+            })
+            .collect()
+    }
+
+    fn part1(&self, masses: &Vec<usize>) -> Result<Answer, AocError> {
+        Ok(part1(masses).into())
+    }
+
+    fn part2(&self, masses: &Vec<usize>) -> Result<Answer, AocError> {
+        Ok(part2(masses).into())
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{fuel_for_mass, fuel_for_payload, part1, part2};
+
+    #[test]
+    fn test_fuel_for_mass() {
+        assert_eq!(fuel_for_mass(12), 2);
+        assert_eq!(fuel_for_mass(14), 2);
+        assert_eq!(fuel_for_mass(1969), 654);
+        assert_eq!(fuel_for_mass(100756), 33583);
+    }
+
+    #[test]
+    fn test_fuel_for_payload() {
+        assert_eq!(fuel_for_payload(12), 2);
+        assert_eq!(fuel_for_payload(1969), 966);
+        assert_eq!(fuel_for_payload(100756), 50346);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&[12, 14]), 4);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&[12, 1969]), 968);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::int_code::patch;
+use crate::{int_code, util, Answer, AocError, Solution};
+
+/// The patch that puts the program into the "1202 program alarm" state.
+const ALARM: &str = include_str!("../../input/day02_alarm.patch");
+
+pub fn part1(memory: &[i64]) -> Result<i64, AocError> {
+    let mut mem = memory.to_owned();
+    // Put the program back into the "1202 program alarm" state it had before the fire
+    let alarm = patch::parse(ALARM)
+        .map_err(|e| AocError::parse(e.line, "", e.message).in_file("input/day02_alarm.patch"))?;
+    alarm.apply(&mut mem);
+    let mut computer1 = int_code::IntCodeComputer::new(mem);
+    super::run_int_code(&mut computer1)?;
+    Ok(computer1.dump_memory().read(0))
+}
+
+pub fn part2(memory: &[i64]) -> Result<i64, AocError> {
+    let target = 19690720;
+    let pairs = (0..100).flat_map(|noun| (0..100).map(move |verb| (noun, verb)));
+    let found = int_code::search::Search::new(memory).find_first(pairs, |bench, &(noun, verb)| {
+        let computer = bench.patched(&[(1, noun), (2, verb)]);
+        // A noun and verb that make the program fail aren't the ones being looked for.
+        let ran = super::run_int_code(computer).is_ok();
+        Some(100 * noun + verb).filter(|_| ran && computer.memory().read(0) == target)
+    });
+    match found {
+        Some((_, answer)) => Ok(answer),
+        None => Err(AocError::unsolvable(format!(
+            "Could not find noun and verb such that the output was {}",
+            target
+        ))),
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<i64>;
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "1202 Program Alarm"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, AocError> {
+        util::parse_int_code(input)
+    }
+
+    fn part1(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part1(memory).map(Answer::from)
+    }
+
+    fn part2(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part2(memory).map(Answer::from)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2};
+
+    #[test]
+    fn test_truncated_program() {
+        // Adds and then runs off the end of the program.
+        let error = part1(&[1, 0, 0]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the program failed: unexpected val for opcode 0 (instruction at 4)"
+        );
+        assert!(part2(&[1, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_no_noun_and_verb() {
+        assert_eq!(
+            part2(&[99]).unwrap_err().to_string(),
+            "Could not find noun and verb such that the output was 19690720"
+        );
+    }
+}
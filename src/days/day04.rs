@@ -0,0 +1,197 @@
+use std::ops::RangeInclusive;
+
+use crate::{util, Answer, AocError, Solution};
+
+/// Returns the lower and upper bounds of a range written like `123456-654321`.
+fn bounds(line: &str) -> Option<(u32, u32)> {
+    let (lower, upper) = line.split_once('-')?;
+    Some((lower.parse().ok()?, upper.parse().ok()?))
+}
+
+pub fn part1(range: &RangeInclusive<u32>) -> u32 {
+    range
+        .clone()
+        // Count all instances where the password is valid
+        .map(|n| is_valid_part1(n) as u32)
+        .sum()
+}
+
+pub fn part2(range: &RangeInclusive<u32>) -> u32 {
+    range
+        .clone()
+        // Count all instances where the password is valid
+        .map(|n| is_valid_part2(n) as u32)
+        .sum()
+}
+
+fn is_valid_part1(password: u32) -> bool {
+    [
+        password_valid_range(password),
+        password_monotonically_incr(password),
+        has_max_consecutive_digits(password, 6),
+    ]
+    .iter()
+    .all(|x| *x)
+}
+
+fn is_valid_part2(password: u32) -> bool {
+    [
+        password_valid_range(password),
+        password_monotonically_incr(password),
+        has_max_consecutive_digits(password, 2),
+    ]
+    .iter()
+    .all(|x| *x)
+}
+
+fn password_valid_range(password: u32) -> bool {
+    // Password must be six digits
+    (100_000..1_000_000).contains(&password)
+}
+
+fn password_monotonically_incr(mut password: u32) -> bool {
+    let mut last_digit = u32::MAX;
+    let mut current_digit: u32;
+    loop {
+        current_digit = password % 10;
+        if current_digit > last_digit {
+            return false;
+        }
+        // Trim the least significant digit
+        last_digit = current_digit;
+        password /= 10;
+        // No more digits left
+        if password == 0 {
+            break;
+        }
+    }
+    true
+}
+
+// Checks that the password has a streak that has at most `limit` of the same
+// digit in a row.
+fn has_max_consecutive_digits(mut password: u32, limit: u32) -> bool {
+    let mut last_digit = u32::MAX;
+    let mut current_digit: u32;
+    let mut streak_length = 0u32;
+    loop {
+        current_digit = password % 10;
+        if current_digit == last_digit {
+            streak_length += 1;
+        } else {
+            // The streak is over. If the streak length is up to limit long, return true
+            if (2..limit + 1).contains(&streak_length) {
+                return true;
+            }
+            streak_length = 1;
+        }
+        // Trim the least significant digit
+        last_digit = current_digit;
+        password /= 10;
+        // No more digits left
+        if password == 0 {
+            break;
+        }
+    }
+    (2..limit + 1).contains(&streak_length)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// The range the password is in.
+    type Input = RangeInclusive<u32>;
+
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Secure Container"
+    }
+
+    fn parse(&self, input: &str) -> Result<RangeInclusive<u32>, AocError> {
+        let lines = util::lines(input)?;
+        match bounds(&lines[0]) {
+            Some((lower, upper)) if lower <= upper => Ok(lower..=upper),
+            _ => Err(AocError::parse(
+                1,
+                &lines[0],
+                format!(
+                    "expected a range like 123456-654321 but found '{}'",
+                    lines[0]
+                ),
+            )),
+        }
+    }
+
+    fn part1(&self, range: &RangeInclusive<u32>) -> Result<Answer, AocError> {
+        Ok(part1(range).into())
+    }
+
+    fn part2(&self, range: &RangeInclusive<u32>) -> Result<Answer, AocError> {
+        Ok(part2(range).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_max_consecutive_digits, is_valid_part1, is_valid_part2, part1, part2};
+
+    #[test]
+    fn test_password_part1() {
+        assert!(is_valid_part1(111111));
+        assert!(is_valid_part1(112233));
+        assert!(is_valid_part1(145677));
+        assert!(is_valid_part1(778899));
+        // Digits not monotonically increasing
+        assert!(!is_valid_part1(101111));
+        // Number is too small
+        assert!(!is_valid_part1(10));
+        assert!(!is_valid_part1(99999));
+        // Number is too big
+        assert!(!is_valid_part1(1111111));
+        // No adjacent numbers that are the same
+        assert!(!is_valid_part1(123456));
+    }
+
+    #[test]
+    fn test_password_part2() {
+        assert!(!is_valid_part2(111111));
+        assert!(is_valid_part2(112233));
+        assert!(is_valid_part2(145677));
+        assert!(is_valid_part2(778899));
+        // Digits not monotonically increasing
+        assert!(!is_valid_part2(101111));
+        // Number is too small
+        assert!(!is_valid_part2(10));
+        assert!(!is_valid_part2(99999));
+        // Number is too big
+        assert!(!is_valid_part2(1111111));
+        // No adjacent numbers that are the same
+        assert!(!is_valid_part2(123456));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&(111111..=111112)), 2);
+        // The upper bound can be as high as there are numbers.
+        assert_eq!(part1(&(u32::MAX - 1..=u32::MAX)), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&(111111..=111112)), 0);
+        assert_eq!(part2(&(111111..=111122)), 1);
+    }
+
+    #[test]
+    fn test_consecutive_digits() {
+        assert!(!has_max_consecutive_digits(123, 2));
+        assert!(has_max_consecutive_digits(112233, 2));
+        assert!(!has_max_consecutive_digits(111222333, 2));
+        assert!(has_max_consecutive_digits(111222333, 3));
+        assert!(has_max_consecutive_digits(111222333, 4));
+        assert!(has_max_consecutive_digits(111222333, 5));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{int_code, util, Answer, AocError, Solution};
+
+pub fn part1(memory: &[i64]) -> Result<i64, AocError> {
+    let mem = memory.to_owned();
+    // Set the noun and verb according to puzzle
+    let mut computer1 = int_code::IntCodeComputer::new(mem);
+    let input_stream = int_code::DataStream::new();
+    computer1.attach_input(input_stream);
+    computer1.input.write(1);
+    super::run_int_code(&mut computer1)?;
+    let output_stream = computer1.dump_output().read_all();
+    for (ind, val) in output_stream.iter().enumerate() {
+        if *val != 0 && ind != output_stream.len() - 1 {
+            return Err(AocError::unsolvable(
+                "test failed, expected a non-zero output for test diagnostic",
+            ));
+        }
+    }
+    let out = last_output(&output_stream)?;
+    computer1.dump_output().close();
+    Ok(out)
+}
+
+pub fn part2(memory: &[i64]) -> Result<i64, AocError> {
+    let mem = memory.to_owned();
+    // Set the noun and verb according to puzzle
+    let mut computer1 = int_code::IntCodeComputer::new(mem);
+    computer1.input.write(5);
+    super::run_int_code(&mut computer1)?;
+    let output_stream = computer1.dump_output().read_all();
+    last_output(&output_stream)
+}
+
+/// Returns the diagnostic code, which is the last value the program output.
+fn last_output(output: &[i64]) -> Result<i64, AocError> {
+    output
+        .last()
+        .copied()
+        .ok_or_else(|| AocError::unsolvable("the diagnostic program didn't output anything"))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<i64>;
+
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Sunny with a Chance of Asteroids"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, AocError> {
+        util::parse_int_code(input)
+    }
+
+    fn part1(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part1(memory).map(Answer::from)
+    }
+
+    fn part2(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part2(memory).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2};
+
+    #[test]
+    fn test_diagnostics() {
+        // Outputs the system ID it is given.
+        let echo = [3, 0, 4, 0, 99];
+        assert_eq!(part1(&echo).unwrap(), 1);
+        assert_eq!(part2(&echo).unwrap(), 5);
+        // A failed test before the diagnostic code.
+        assert!(part1(&[104, 3, 104, 0, 99]).is_err());
+        assert!(part2(&[99]).is_err());
+    }
+
+    #[test]
+    fn test_truncated_program() {
+        // Adds and then runs off the end of the program.
+        let msg = "the program failed: unexpected val for opcode 0 (instruction at 4)";
+        assert_eq!(part1(&[1, 0, 0]).unwrap_err().to_string(), msg);
+        assert_eq!(part2(&[1, 0, 0]).unwrap_err().to_string(), msg);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::{util, Answer, AocError, Solution};
+
+/// Orbits maps each body to the one it orbits.
+pub type Orbits = HashMap<String, String>;
+
+/// Returns the bodies `body` orbits, directly or not, each with how many orbits away it is.
+fn primaries<'a>(body: &'a str, orbits: &'a Orbits) -> impl Iterator<Item = (&'a str, u32)> {
+    let mut body = body;
+    (1..).map_while(move |counter| {
+        body = orbits.get(body)?;
+        Some((body, counter))
+    })
+}
+
+pub fn part1(orbits: &Orbits) -> u32 {
+    // For every body, go down the primaries until you get to the COM.
+    orbits
+        .keys()
+        .map(|body| primaries(body, orbits).count() as u32)
+        .sum()
+}
+
+fn dist_to_com<'a>(body: &'a str, orbits: &'a Orbits) -> Result<HashMap<&'a str, u32>, AocError> {
+    if !orbits.contains_key(body) {
+        return Err(AocError::unsolvable(format!(
+            "{} isn't orbiting anything",
+            body
+        )));
+    }
+    // The distance is counted from the body `body` orbits.
+    Ok(primaries(body, orbits)
+        .map(|(primary, d)| (primary, d - 1))
+        .collect())
+}
+
+pub fn part2(orbits: &Orbits) -> Result<u32, AocError> {
+    // Create a map indicating how many steps it took to go from YOU to the given body.
+    // Create a map indicating how many steps it took to go from SAN to a given body.
+    // Find the body that they have in common with the smallest commulative distance.
+    let dist_to_you = dist_to_com("YOU", orbits)?;
+    let dist_to_santa = dist_to_com("SAN", orbits)?;
+
+    dist_to_you
+        .iter()
+        .filter_map(|(body, d1)| Some(d1 + dist_to_santa.get(body)?))
+        .min()
+        .ok_or_else(|| AocError::unsolvable("YOU and SAN orbit nothing in common"))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Orbits;
+
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Universal Orbit Map"
+    }
+
+    fn parse(&self, input: &str) -> Result<Orbits, AocError> {
+        let lines = util::lines(input)?;
+        let mut orbits = Orbits::new();
+        for (i, line) in lines.iter().enumerate() {
+            let (primary, satellite) = match line.split_once(')') {
+                Some((primary, satellite)) if !primary.is_empty() && !satellite.is_empty() => {
+                    (primary, satellite)
+                }
+                _ => {
+                    return Err(AocError::parse(
+                        i + 1,
+                        line,
+                        format!("expected an orbit like A)B but found '{}'", line),
+                    ))
+                }
+            };
+            if orbits
+                .insert(satellite.to_string(), primary.to_string())
+                .is_some()
+            {
+                return Err(AocError::parse(
+                    i + 1,
+                    satellite,
+                    format!("{} orbits more than one body", satellite),
+                ));
+            }
+        }
+        // Following the orbits down from any body has to come to an end, so it can't pass more
+        // bodies than there are orbits.
+        for (i, line) in lines.iter().enumerate() {
+            let satellite = &line[line.find(')').map_or(0, |p| p + 1)..];
+            if primaries(satellite, &orbits).nth(orbits.len()).is_some() {
+                return Err(AocError::parse(
+                    i + 1,
+                    satellite,
+                    format!("the orbits down from {} go round in a circle", satellite),
+                ));
+            }
+        }
+        Ok(orbits)
+    }
+
+    fn part1(&self, orbits: &Orbits) -> Result<Answer, AocError> {
+        Ok(part1(orbits).into())
+    }
+
+    fn part2(&self, orbits: &Orbits) -> Result<Answer, AocError> {
+        part2(orbits).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2, Day06};
+    use crate::Solution;
+
+    #[test]
+    fn test_part1() {
+        let input = "COM)B B)C C)D D)E E)F B)G G)H D)I E)J J)K K)L";
+        let orbits = Day06.parse(&input.replace(' ', "\n")).unwrap();
+        assert_eq!(part1(&orbits), 42)
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "COM)B B)C C)D D)E E)F B)G G)H D)I E)J J)K K)L K)YOU I)SAN";
+        let orbits = Day06.parse(&input.replace(' ', "\n")).unwrap();
+        assert_eq!(part2(&orbits).unwrap(), 4);
+        let orbits = Day06.parse("COM)B\nB)YOU").unwrap();
+        assert_eq!(
+            part2(&orbits).unwrap_err().to_string(),
+            "SAN isn't orbiting anything"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = vec![
+            ("COM)B\nC)B", "line 2: B orbits more than one body"),
+            (
+                "B)X\nA)B\nB)A",
+                "line 1: the orbits down from X go round in a circle",
+            ),
+        ];
+        for (text, msg) in cases {
+            let error = Day06.parse(text).expect_err(text);
+            assert_eq!(error.to_string(), msg, "{:?}", text);
+        }
+    }
+}
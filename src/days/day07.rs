@@ -0,0 +1,119 @@
+use crate::int_code;
+use crate::{util, Answer, AocError, Solution};
+
+/// Runs `configure` on the amplifiers for every order of phase settings, returning the largest
+/// signal that reaches the thrusters.
+fn max_signal<F>(program: &[i64], configure: F) -> Result<i64, AocError>
+where
+    F: Fn(&mut [int_code::IntCodeComputer], &[u64]) -> Result<i64, String> + Sync,
+{
+    let num_apps = 5;
+    // Err compares greater than every Ok, so if any order of settings fails, that is reported.
+    let (_, signal) = int_code::search::Search::new(program)
+        .max_by_key(util::Permutations::new(num_apps), |bench, perm| {
+            configure(bench.computers(num_apps), perm)
+        })
+        .ok_or_else(|| AocError::unsolvable("no phase settings to try"))?;
+    signal.map_err(AocError::unsolvable)
+}
+
+/// Returns the signal an amplifier sent on, or an error if it stopped without sending one.
+fn signal(comp: &mut int_code::IntCodeComputer) -> Result<i64, String> {
+    comp.drain_output()
+        .first()
+        .copied()
+        .ok_or_else(|| "an amplifier stopped without sending a signal".to_string())
+}
+
+pub fn part1(program: &[i64]) -> Result<i64, AocError> {
+    max_signal(program, run_linear_configuration)
+}
+
+fn run_linear_configuration(
+    amps: &mut [int_code::IntCodeComputer],
+    phase_settings: &[u64],
+) -> Result<i64, String> {
+    for (comp, phase) in amps.iter_mut().zip(phase_settings) {
+        comp.input.write(*phase as i64);
+    }
+
+    let mut output = 0i64;
+    for comp in amps.iter_mut() {
+        comp.input.write(output);
+        super::run_int_code(comp).map_err(|e| e.to_string())?;
+        output = signal(comp)?;
+    }
+    Ok(output)
+}
+
+pub fn part2(program: &[i64]) -> Result<i64, AocError> {
+    max_signal(program, run_circular_configuration)
+}
+
+fn run_circular_configuration(
+    amps: &mut [int_code::IntCodeComputer],
+    phase_settings: &[u64],
+) -> Result<i64, String> {
+    for (comp, phase) in amps.iter_mut().zip(phase_settings) {
+        // The shift settings for the new amplifiers are 5-9
+        comp.input.write(5 + *phase as i64);
+    }
+
+    let mut output = 0i64;
+    while !amps.iter().all(|x| x.is_halted()) {
+        for comp in amps.iter_mut() {
+            comp.input.write(output);
+            super::run_int_code(comp).map_err(|e| e.to_string())?;
+            output = signal(comp)?;
+        }
+    }
+    Ok(output)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<i64>;
+
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Amplification Circuit"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, AocError> {
+        util::parse_int_code(input)
+    }
+
+    fn part1(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part1(memory).map(Answer::from)
+    }
+
+    fn part2(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part2(memory).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2};
+
+    #[test]
+    fn test_no_signal() {
+        // Reads the phase setting and the signal, and sends nothing on.
+        let memory = vec![3, 0, 3, 0, 99];
+        let msg = "an amplifier stopped without sending a signal";
+        assert_eq!(part1(&memory).unwrap_err().to_string(), msg);
+        assert_eq!(part2(&memory).unwrap_err().to_string(), msg);
+    }
+
+    #[test]
+    fn test_truncated_program() {
+        // Adds and then runs off the end of the program.
+        let msg = "the program failed: unexpected val for opcode 0 (instruction at 4)";
+        assert_eq!(part1(&[1, 0, 0]).unwrap_err().to_string(), msg);
+        assert_eq!(part2(&[1, 0, 0]).unwrap_err().to_string(), msg);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{util, Answer, AocError, Solution};
+
+/// The size of the picture the Elves' password is in.
+const HEIGHT: usize = 6;
+const WIDTH: usize = 25;
+
+pub fn part1(image: &Image) -> usize {
+    // Go through all the layers. The accumulator here is (index, value), and we keep the minimum
+    // value and the layer it happened. It's may not be faster, but it was an interesting way
+    // to write it as an iterator
+    let layer_with_least_zeroes = (0..image.layers)
+        .fold((0, usize::MAX), |acc, i| {
+            let val = image.digits_in_layer(i, 0);
+            if val < acc.1 {
+                (i, val)
+            } else {
+                acc
+            }
+        })
+        .0;
+    image.digits_in_layer(layer_with_least_zeroes, 1)
+        * image.digits_in_layer(layer_with_least_zeroes, 2)
+}
+
+pub fn part2(image: &Image) -> String {
+    image.image_string()
+}
+
+pub struct Image {
+    pixels: Vec<u8>,
+    height: usize,
+    width: usize,
+    layer_size: usize,
+    layers: usize,
+}
+
+impl Image {
+    /// Reads an image from its digits, which have to make up one or more whole layers.
+    fn new(input: &str, height: usize, width: usize) -> Result<Self, AocError> {
+        let pixels = input
+            .chars()
+            .map(|c| {
+                c.to_digit(10).map(|n| n as u8).ok_or_else(|| {
+                    AocError::parse(1, &c.to_string(), format!("invalid digit '{}'", c))
+                })
+            })
+            .collect::<Result<Vec<u8>, AocError>>()?;
+
+        let layer_size = height * width;
+        let num_pixels = pixels.len();
+        if num_pixels == 0 || !num_pixels.is_multiple_of(layer_size) {
+            return Err(AocError::parse(
+                1,
+                input,
+                format!(
+                    "the image has {} digits, which isn't a whole number of {}x{} layers",
+                    num_pixels, width, height
+                ),
+            ));
+        }
+        Ok(Self {
+            pixels,
+            height,
+            width,
+            layer_size,
+            layers: num_pixels / layer_size,
+        })
+    }
+
+    fn digits_in_layer(&self, layer: usize, digit: u8) -> usize {
+        self.pixels[self.layer_size * layer..self.layer_size * (layer + 1)]
+            .iter()
+            .filter(|n| **n == digit)
+            .count()
+    }
+
+    fn pixel(&self, layer: usize, x: usize, y: usize) -> Option<u8> {
+        if x > self.width || y > self.height || layer > self.layers {
+            return None;
+        }
+        Some(self.pixels[layer * self.layer_size + x + y * self.width])
+    }
+
+    fn image_string(&self) -> String {
+        let mut out: String = String::from("");
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.color_for_pixel(x, y) {
+                    Color::Black => {
+                        out.push_str(&String::from(" "));
+                    }
+                    Color::White => {
+                        out.push_str(&String::from("*"));
+                    }
+                    _ => (),
+                }
+            }
+            out.push_str(&String::from("\n"));
+        }
+        out
+    }
+
+    fn color_for_pixel(&self, x: usize, y: usize) -> Color {
+        for layer in 0..self.layers {
+            match self.pixel(layer, x, y).unwrap() {
+                0 => return Color::Black,
+                1 => return Color::White,
+                _ => continue,
+            }
+        }
+        Color::Transparent
+    }
+}
+
+enum Color {
+    Black,
+    White,
+    Transparent,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Image;
+
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Space Image Format"
+    }
+
+    fn parse(&self, input: &str) -> Result<Image, AocError> {
+        let lines = util::lines(input)?;
+        Image::new(&lines[0], HEIGHT, WIDTH)
+    }
+
+    fn part1(&self, image: &Image) -> Result<Answer, AocError> {
+        Ok(part1(image).into())
+    }
+
+    fn part2(&self, image: &Image) -> Result<Answer, AocError> {
+        Ok(part2(image).into())
+    }
+}
+
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2, Image};
+
+    #[test]
+    fn test_part1_simple() {
+        let image = Image::new("000111222033111322", 3, 3).unwrap();
+        assert_eq!(part1(&image), 6);
+    }
+
+    #[test]
+    fn test_part2_simple() {
+        let digits = "222222222222222222222222111111000000000000000000000000";
+        let image = Image::new(digits, 3, 6).unwrap();
+        let expected = concat!("      \n", "******\n", "      \n");
+        assert_eq!(part2(&image), expected);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{int_code, util, Answer, AocError, Solution};
+
+/// Runs the BOOST program with the given input and returns the single value it outputs. In test
+/// mode (input 1) any extra outputs are opcodes the computer failed to implement correctly.
+fn run_boost(memory: &[i64], input: i64) -> Result<i64, AocError> {
+    let mem = memory.to_owned();
+    let mut computer1 = int_code::IntCodeComputer::new(mem);
+    let input_stream = int_code::DataStream::new();
+    computer1.attach_input(input_stream);
+    computer1.input.write(input);
+    super::run_int_code(&mut computer1)?;
+    let output = computer1.dump_output().read_all();
+    match output[..] {
+        [value] => Ok(value),
+        [] => Err(AocError::unsolvable("BOOST didn't output anything")),
+        _ => Err(AocError::unsolvable(format!(
+            "BOOST reported malfunctioning opcodes: {:?}",
+            output
+        ))),
+    }
+}
+
+pub fn part1(memory: &[i64]) -> Result<i64, AocError> {
+    run_boost(memory, 1)
+}
+
+pub fn part2(memory: &[i64]) -> Result<i64, AocError> {
+    run_boost(memory, 2)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<i64>;
+
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Sensor Boost"
+    }
+
+    fn parse(&self, input: &str) -> Result<Vec<i64>, AocError> {
+        util::parse_int_code(input)
+    }
+
+    fn part1(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part1(memory).map(Answer::from)
+    }
+
+    fn part2(&self, memory: &Vec<i64>) -> Result<Answer, AocError> {
+        part2(memory).map(Answer::from)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2};
+
+    #[test]
+    fn test_boost() {
+        // Outputs the mode it is run in.
+        let echo = [3, 0, 4, 0, 99];
+        assert_eq!(part1(&echo).unwrap(), 1);
+        assert_eq!(part2(&echo).unwrap(), 2);
+        assert_eq!(
+            part1(&[104, 203, 104, 0, 99]).unwrap_err().to_string(),
+            "BOOST reported malfunctioning opcodes: [203, 0]"
+        );
+    }
+
+    #[test]
+    fn test_truncated_program() {
+        // Adds and then runs off the end of the program.
+        let msg = "the program failed: unexpected val for opcode 0 (instruction at 4)";
+        assert_eq!(part1(&[1, 0, 0]).unwrap_err().to_string(), msg);
+        assert_eq!(part2(&[1, 0, 0]).unwrap_err().to_string(), msg);
+    }
+}
@@ -0,0 +1,109 @@
+//! The errors a solution gives for input it can't read or make sense of, rather than panicking.
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file couldn't be read.
+    Io { path: PathBuf, error: io::Error },
+    /// A line of the input, counting from 1, didn't make sense. The message names the token that
+    /// was wrong, which is empty if something was missing instead.
+    Parse {
+        path: Option<PathBuf>,
+        line: usize,
+        token: String,
+        message: String,
+    },
+    /// The input made sense, but there is no answer to be found from it.
+    Unsolvable(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, token: &str, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            path: None,
+            line,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        AocError::Unsolvable(message.into())
+    }
+
+    /// Returns the error for input that came from the file at `path`.
+    pub fn in_file(self, path: impl AsRef<Path>) -> Self {
+        match self {
+            AocError::Parse {
+                line,
+                token,
+                message,
+                ..
+            } => AocError::Parse {
+                path: Some(path.as_ref().to_path_buf()),
+                line,
+                token,
+                message,
+            },
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+            AocError::Parse {
+                path: Some(path),
+                line,
+                message,
+                ..
+            } => write!(f, "{}:{}: {}", path.display(), line, message),
+            AocError::Parse { line, message, .. } => write!(f, "line {}: {}", line, message),
+            AocError::Unsolvable(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { error, .. } => Some(error),
+            AocError::Parse { .. } | AocError::Unsolvable(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::error::AocError;
+
+    #[test]
+    fn test_display() {
+        let error = AocError::parse(3, "X12", "invalid segment 'X12'");
+        assert_eq!(error.to_string(), "line 3: invalid segment 'X12'");
+        let error = error.in_file("input/day03.txt");
+        assert_eq!(
+            error.to_string(),
+            "input/day03.txt:3: invalid segment 'X12'"
+        );
+        match error {
+            AocError::Parse { token, .. } => assert_eq!(token, "X12"),
+            e => panic!("unexpected error {:?}", e),
+        }
+
+        let error = AocError::Io {
+            path: "input/day99.txt".into(),
+            error: io::Error::new(io::ErrorKind::NotFound, "not found"),
+        };
+        assert_eq!(
+            error.in_file("elsewhere").to_string(),
+            "input/day99.txt: not found"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Finds the input for a day's puzzle.
+//!
+//! The input can be read from a path given on the command line, or from stdin if the path is `-`.
+//! Otherwise it is `dayNN.txt` in the directory the `AOC_INPUT_DIR` environment variable names,
+//! if it's set. Failing that, it is the input that was embedded in the binary when it was built
+//! with the `embedded-inputs` feature, or else `./input/dayNN.txt` in the current directory.
+//!
+//! Whichever it comes from, the input is text, which the puzzle parses the same way.
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{days, util, AocError};
+
+/// The environment variable naming the directory to read inputs from.
+pub const DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The path that means stdin.
+pub const STDIN: &str = "-";
+
+/// Input is the text of a puzzle input and where it came from.
+pub struct Input {
+    /// The path of the file the input was read from, or `<stdin>`. An embedded input is named
+    /// `./input/dayNN.txt`, after the file it was embedded from.
+    pub name: String,
+    pub text: String,
+}
+
+/// The checked in inputs, embedded in order of day.
+#[cfg(feature = "embedded-inputs")]
+const EMBEDDED: [&str; 9] = [
+    include_str!("../input/day01.txt"),
+    include_str!("../input/day02.txt"),
+    include_str!("../input/day03.txt"),
+    include_str!("../input/day04.txt"),
+    include_str!("../input/day05.txt"),
+    include_str!("../input/day06.txt"),
+    include_str!("../input/day07.txt"),
+    include_str!("../input/day08.txt"),
+    include_str!("../input/day09.txt"),
+];
+
+/// Returns the input for `day` embedded in the binary, if it was built with the
+/// `embedded-inputs` feature.
+pub fn embedded(day: u32) -> Option<&'static str> {
+    #[cfg(feature = "embedded-inputs")]
+    {
+        EMBEDDED.get((day as usize).checked_sub(1)?).copied()
+    }
+    #[cfg(not(feature = "embedded-inputs"))]
+    {
+        let _ = day;
+        None
+    }
+}
+
+fn file_name(day: u32) -> String {
+    format!("day{:02}.txt", day)
+}
+
+/// Returns the input for `day`, read from `path` if it is given, as described in the module docs.
+pub fn resolve(day: u32, path: Option<&str>) -> Result<Input, AocError> {
+    resolve_with(day, path, env::var_os(DIR_VAR))
+}
+
+/// Resolves the input as resolve does, with `dir` in place of the AOC_INPUT_DIR variable.
+pub(crate) fn resolve_with(
+    day: u32,
+    path: Option<&str>,
+    dir: Option<OsString>,
+) -> Result<Input, AocError> {
+    match (path, dir) {
+        (Some(STDIN), _) => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|error| AocError::Io {
+                    path: PathBuf::from("<stdin>"),
+                    error,
+                })?;
+            Ok(Input {
+                name: "<stdin>".to_string(),
+                text,
+            })
+        }
+        (Some(path), _) => read(Path::new(path)),
+        (None, Some(dir)) => read(&Path::new(&dir).join(file_name(day))),
+        (None, None) => match embedded(day) {
+            Some(text) => Ok(Input {
+                name: days::input_path(day),
+                text: text.to_string(),
+            }),
+            None => read(Path::new(&days::input_path(day))),
+        },
+    }
+}
+
+fn read(path: &Path) -> Result<Input, AocError> {
+    Ok(Input {
+        name: path.display().to_string(),
+        text: util::read_to_string(path)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::input::{embedded, resolve_with};
+    use crate::AocError;
+
+    #[test]
+    fn test_resolve() {
+        let input = resolve_with(3, Some("./input/day01.txt"), None).unwrap();
+        assert_eq!(input.name, "./input/day01.txt");
+        assert!(input.text.starts_with("74666\n"));
+
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day04.txt"), "111111-111122\n").unwrap();
+        let input = resolve_with(4, None, Some(dir.clone().into())).unwrap();
+        assert_eq!(input.name, dir.join("day04.txt").display().to_string());
+        assert_eq!(input.text, "111111-111122\n");
+        let missing = resolve_with(5, None, Some(dir.clone().into()));
+        fs::remove_dir_all(&dir).unwrap();
+        match missing {
+            Err(AocError::Io { path, .. }) => assert_eq!(path, dir.join("day05.txt")),
+            _ => panic!("expected an I/O error"),
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        let input = resolve_with(6, None, None).unwrap();
+        assert_eq!(input.name, "./input/day06.txt");
+        assert!(input.text.starts_with("Z9G)Q7N\n"));
+        assert_eq!(embedded(6).is_some(), cfg!(feature = "embedded-inputs"));
+        assert_eq!(embedded(10), None);
+    }
+}
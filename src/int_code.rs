@@ -1,42 +1,71 @@
-use log::{debug, info};
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+use std::slice;
+
+pub mod asm;
+pub mod asynchronous;
+mod bignum;
+mod cell;
+pub mod coverage;
+pub mod fuzz;
+pub mod input;
+pub mod lang;
+pub mod loader;
+pub mod observer;
+pub mod optimize;
+pub mod output;
+pub mod patch;
+pub mod reference;
+pub mod search;
+pub mod symbolic;
+pub mod transpile;
+pub mod tui;
+
+pub use bignum::{BigInt, ParseBigIntError};
+pub use cell::Cell;
+pub use input::InputSource;
+pub use observer::Observer;
+pub use output::OutputSink;
 
 // IntCodeComputer Section
-enum DsRead {
+enum DsRead<T> {
     // Signals that indicate a read could not succeed. Maybe make these errors?
     Closed,
     NoData,
     // The actual value
-    Data(i64),
+    Data(T),
 }
 
 /// DataStream is represents a shared buffer that a producer and consumer can write and read to.
-pub struct DataStream {
-    buffer: Memory,
+pub struct DataStream<T: Cell = i64> {
+    buffer: Memory<T>,
     is_closed: bool,
     producer_ind: u64,
     consumer_ind: u64,
 }
 
-impl Default for DataStream {
+impl<T: Cell> Default for DataStream<T> {
     fn default() -> Self {
-        DataStream::new()
-    }
-}
-
-impl DataStream {
-    pub fn new() -> Self {
         Self {
             buffer: Memory {
-                memory: vec![0i64; 1000],
+                memory: vec![T::zero(); 1000],
             },
             is_closed: false,
             producer_ind: 0,
             consumer_ind: 0,
         }
     }
+}
+
+impl DataStream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
+impl<T: Cell> DataStream<T> {
+    /// Empties the stream. The old values stay in the buffer, but nothing reads past the producer.
     fn reset(&mut self) {
-        self.buffer.clear();
         self.producer_ind = 0;
         self.consumer_ind = 0;
         self.is_closed = false;
@@ -54,12 +83,12 @@ impl DataStream {
         }
     }
 
-    pub fn write(&mut self, val: i64) {
+    pub fn write(&mut self, val: T) {
         self.buffer.write(self.producer_ind, val);
         self.producer_ind += 1;
     }
 
-    fn read(&mut self) -> DsRead {
+    fn read(&mut self) -> DsRead<T> {
         if self.is_closed {
             return DsRead::Closed;
         }
@@ -72,7 +101,17 @@ impl DataStream {
         DsRead::Data(out)
     }
 
-    pub fn read_all(&mut self) -> Vec<i64> {
+    /// Returns the next value, None if the producer hasn't written one yet, or an error if the
+    /// stream has been closed.
+    pub fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        match self.read() {
+            DsRead::Closed => Err(ErrorKind::ReadFromClosedStream),
+            DsRead::NoData => Ok(None),
+            DsRead::Data(d) => Ok(Some(d)),
+        }
+    }
+
+    pub fn read_all(&mut self) -> Vec<T> {
         let mut output = vec![];
         loop {
             let out = self.read();
@@ -85,6 +124,32 @@ impl DataStream {
         }
     }
 
+    /// Returns the values written since the last read and marks them as read, without copying
+    /// them. Like read_all, it returns nothing once the stream is closed.
+    pub fn drain(&mut self) -> &[T] {
+        if self.is_closed {
+            return &[];
+        }
+        let start = self.consumer_ind as usize;
+        self.consumer_ind = self.producer_ind;
+        &self.buffer.memory[start..self.producer_ind as usize]
+    }
+
+    /// Returns the values written since the last read, leaving them unread.
+    pub fn unread(&self) -> &[T] {
+        &self.buffer.memory[self.consumer_ind as usize..self.producer_ind as usize]
+    }
+
+    /// Returns the most recently written value, whether it has been read or not.
+    pub fn last(&self) -> Option<&T> {
+        self.iter().last()
+    }
+
+    /// Iterates over every value written to the stream, whether it has been read or not.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.buffer.memory[..self.producer_ind as usize].iter()
+    }
+
     pub fn close(&mut self) {
         self.is_closed = true;
     }
@@ -114,62 +179,215 @@ mod datastream_test {
         ds.close();
         assert!(matches!(ds.read(), DsRead::Closed));
     }
+
+    #[test]
+    fn test_borrowing_reads() {
+        let mut ds = DataStream::new();
+        assert_eq!(ds.drain(), &[] as &[i64]);
+        assert_eq!(ds.last(), None);
+        ds.write(1);
+        ds.write(2);
+        assert_eq!(ds.unread(), &[1, 2]);
+        assert_eq!(ds.drain(), &[1, 2]);
+        assert_eq!(ds.unread(), &[] as &[i64]);
+        ds.write(3);
+        assert_eq!(ds.last(), Some(&3));
+        assert_eq!(ds.drain(), &[3]);
+        // Reading doesn't change what iter and last see.
+        assert_eq!(ds.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(ds.last(), Some(&3));
+
+        // Writing past the initial buffer grows it.
+        for v in 0..2000 {
+            ds.write(v);
+        }
+        assert_eq!(ds.drain().len(), 2000);
+        assert_eq!(ds.last(), Some(&1999));
+        ds.close();
+        assert_eq!(ds.drain(), &[] as &[i64]);
+    }
 }
 
 /// Memory manages the memory of the IntCodeComputer. It can read from address, or it can read from
 /// pointer. It can also write to address and write to pointer
-pub struct Memory {
-    memory: Vec<i64>,
+pub struct Memory<T: Cell = i64> {
+    memory: Vec<T>,
 }
 
-impl Memory {
-    /// Returns the value at the specified address
-    pub fn read(&self, addr: u64) -> i64 {
-        self.memory[addr as usize]
-    }
-
-    fn clear(&mut self) {
-        for i in 0..self.memory.len() {
-            self.memory[i] = 0
+impl<T: Cell> Memory<T> {
+    /// Returns the value at the specified address. Addresses past the end of memory read as 0.
+    pub fn read(&self, addr: u64) -> T {
+        match self.memory.get(addr as usize) {
+            Some(v) => v.clone(),
+            None => T::zero(),
         }
     }
 
-    fn read_mode(&self, val: u64, rel_pos: u64, m: &ParamMode) -> i64 {
+    fn read_mode(&self, val: u64, rel_pos: i64, m: &ParamMode) -> Result<T, ErrorKind> {
         match m {
             ParamMode::Position => self.read_ptr(val),
-            ParamMode::Immediate => self.read(val),
+            ParamMode::Immediate => Ok(self.read(val)),
             ParamMode::RelativeMode => {
                 // Read out the value at the given address and then adjust it with the relative postition.
                 // Then use that as the new address to read from.
-                let val = (self.read(val) + rel_pos as i64) as u64;
-                self.read(val)
+                let val = to_i64(&self.read(val))?
+                    .checked_add(rel_pos)
+                    .ok_or(ErrorKind::Overflow)?;
+                Ok(self.read(to_addr(val)?))
             }
         }
     }
 
     /// Returns the value at the pointer. It will read out the value at the given address, then use
     /// that value itself as an address and return what that points to.
-    fn read_ptr(&self, ptr: u64) -> i64 {
-        let addr = self.read(ptr);
-        if addr < 0 {
-            panic!("cannot use negative value {} as pointer", addr)
+    fn read_ptr(&self, ptr: u64) -> Result<T, ErrorKind> {
+        let addr = to_addr(to_i64(&self.read(ptr))?)?;
+        Ok(self.read(addr))
+    }
+
+    /// Writes the specified value at the specified address, growing memory if needed.
+    fn write(&mut self, addr: u64, val: T) {
+        let addr = addr as usize;
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, T::zero());
+        }
+        self.memory[addr] = val;
+    }
+
+    /// Returns the number of cells in memory. Reading past it gives 0.
+    pub fn len(&self) -> usize {
+        self.memory.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memory.is_empty()
+    }
+
+    /// Returns the cells in `range`. Like indexing a slice, it panics if the range goes past the
+    /// end of memory.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> &[T] {
+        &self.memory[(range.start_bound().cloned(), range.end_bound().cloned())]
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.memory.iter()
+    }
+
+    /// Returns every address whose value differs in `other`, with the value here as `old` and
+    /// the value there as `new`. Memories of different lengths are compared as if the shorter
+    /// one were padded with zeros.
+    pub fn diff(&self, other: &Memory<T>) -> Vec<Change<T>> {
+        let len = self.len().max(other.len()) as u64;
+        (0..len)
+            .filter_map(|addr| {
+                let (old, new) = (self.read(addr), other.read(addr));
+                if old == new {
+                    None
+                } else {
+                    Some(Change { addr, old, new })
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the addresses that hold `value`, in order.
+    pub fn find<'a>(&'a self, value: &'a T) -> impl Iterator<Item = u64> + 'a {
+        self.memory
+            .iter()
+            .enumerate()
+            .filter(move |(_, v)| *v == value)
+            .map(|(addr, _)| addr as u64)
+    }
+
+    /// Returns a Display of the cells in `range`, formatted in rows like hexdump, although the
+    /// values are decimal. See Hexdump.
+    pub fn hexdump(&self, range: impl RangeBounds<usize>) -> Hexdump<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        Hexdump {
+            start,
+            cells: self.slice(range),
         }
-        self.read(addr as u64)
     }
+}
+
+/// Change is a cell that differs between two memories. See Memory::diff.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change<T = i64> {
+    pub addr: u64,
+    pub old: T,
+    pub new: T,
+}
+
+/// The number of cells on each row of a Hexdump.
+const HEXDUMP_ROW: usize = 8;
+
+/// Hexdump shows cells in rows of eight, each starting with the address of its first cell, with
+/// the values right aligned. As in hexdump, a row that repeats the one before it is shown as a
+/// single `*`, however many times it repeats, so a run of zeros at the end takes one line.
+pub struct Hexdump<'a, T> {
+    start: usize,
+    cells: &'a [T],
+}
 
-    /// Writes the specified value at the specified address.
-    fn write(&mut self, addr: u64, val: i64) {
-        self.memory[addr as usize] = val;
+impl<T: Cell> fmt::Display for Hexdump<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let end = self.start + self.cells.len();
+        let addr_width = end.saturating_sub(1).to_string().len();
+        let width = self
+            .cells
+            .iter()
+            .map(|v| v.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let mut previous: Option<&[T]> = None;
+        let mut repeated = false;
+        for (i, row) in self.cells.chunks(HEXDUMP_ROW).enumerate() {
+            if previous == Some(row) {
+                if !repeated {
+                    writeln!(f, "*")?;
+                    repeated = true;
+                }
+                continue;
+            }
+            previous = Some(row);
+            repeated = false;
+            write!(f, "{:>w$}:", self.start + i * HEXDUMP_ROW, w = addr_width)?;
+            for v in row {
+                write!(f, " {:>w$}", v.to_string(), w = width)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
+/// Interprets a cell as an i64. Cells used as addresses, opcodes or offsets must fit in 64 bits.
+fn to_i64<T: Cell>(val: &T) -> Result<i64, ErrorKind> {
+    val.to_i64().ok_or(ErrorKind::ValueOutOfRange)
+}
+
+/// Checks that a value can be used as an address.
+fn to_addr(val: i64) -> Result<u64, ErrorKind> {
+    if val < 0 {
+        return Err(ErrorKind::NegativeAddress(val));
+    }
+    if val > MAX_ADDRESS {
+        return Err(ErrorKind::AddressOutOfRange(val));
+    }
+    Ok(val as u64)
+}
+
 #[cfg(test)]
 mod memory_tests {
-    use crate::int_code::Memory;
+    use crate::int_code::{Change, ErrorKind, Memory};
 
     #[test]
     fn test_read() {
-        let m = Memory {
+        let m: Memory = Memory {
             memory: vec![5, 4, 3, 2, 1],
         };
         assert_eq!(m.read(0), 5);
@@ -181,36 +399,154 @@ mod memory_tests {
 
     #[test]
     fn test_read_ptr() {
-        let m = Memory {
+        let m: Memory = Memory {
             memory: vec![1, 2, 3, 4, 0],
         };
-        assert_eq!(m.read_ptr(0), 2);
-        assert_eq!(m.read_ptr(1), 3);
-        assert_eq!(m.read_ptr(2), 4);
-        assert_eq!(m.read_ptr(3), 0);
-        assert_eq!(m.read_ptr(4), 1);
+        assert_eq!(m.read_ptr(0), Ok(2));
+        assert_eq!(m.read_ptr(1), Ok(3));
+        assert_eq!(m.read_ptr(2), Ok(4));
+        assert_eq!(m.read_ptr(3), Ok(0));
+        assert_eq!(m.read_ptr(4), Ok(1));
     }
 
     #[test]
     fn test_write() {
-        let mut m = Memory { memory: vec![0] };
+        let mut m: Memory = Memory { memory: vec![0] };
         m.write(0, 2);
         assert_eq!(m.memory[0], 2);
         m.write(0, 5);
         assert_eq!(m.memory[0], 5);
     }
-}
 
+    #[test]
+    fn test_out_of_range() {
+        let mut m: Memory = Memory { memory: vec![0] };
+        assert_eq!(m.read(5), 0);
+        m.write(5, 3);
+        assert_eq!(m.read(5), 3);
+        assert_eq!(m.memory.len(), 6);
+        let m: Memory = Memory { memory: vec![-1] };
+        assert_eq!(m.read_ptr(0), Err(ErrorKind::NegativeAddress(-1)));
+    }
+
+    #[test]
+    fn test_inspection() {
+        let m: Memory = Memory {
+            memory: vec![1, 0, 0, 3, 99, 3],
+        };
+        assert_eq!(m.len(), 6);
+        assert_eq!(m.slice(3..5), &[3, 99]);
+        assert_eq!(m.slice(4..), &[99, 3]);
+        assert_eq!(m.iter().sum::<i64>(), 106);
+        assert_eq!(m.find(&3).collect::<Vec<u64>>(), vec![3, 5]);
+        assert_eq!(m.find(&7).next(), None);
+    }
+
+    #[test]
+    fn test_diff() {
+        let old: Memory = Memory {
+            memory: vec![1, 0, 0, 3, 99],
+        };
+        let new: Memory = Memory {
+            memory: vec![2, 0, 0, 3, 99, 0, 5],
+        };
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                Change {
+                    addr: 0,
+                    old: 1,
+                    new: 2
+                },
+                Change {
+                    addr: 6,
+                    old: 0,
+                    new: 5
+                },
+            ]
+        );
+        assert_eq!(new.diff(&old)[1].old, 5);
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let mut memory = vec![0; 40];
+        memory[..5].copy_from_slice(&[1, 0, 0, 3, 99]);
+        memory[33] = -1200;
+        let m: Memory = Memory { memory };
+        assert_eq!(
+            m.hexdump(..).to_string(),
+            " 0:     1     0     0     3    99     0     0     0
+ 8:     0     0     0     0     0     0     0     0
+*
+32:     0 -1200     0     0     0     0     0     0
+"
+        );
+        assert_eq!(m.hexdump(2..5).to_string(), "2:  0  3 99\n");
+        assert_eq!(m.hexdump(2..2).to_string(), "");
+    }
+}
 
 /// IntCodeComputer is initialized with memory and executes instructions until it encounters the
 /// end of program code. It does not validate the code.
-pub struct IntCodeComputer {
+///
+/// The computer is generic over the Cell type stored in memory. The default is i64, which is what
+/// every puzzle uses; i128 and BigInt are available for programs that overflow 64 bits.
+///
+/// It is also generic over an Observer, which is told about everything the program does. The
+/// default, `()`, ignores it all and compiles away to nothing. And it is generic over where input
+/// comes from and output goes: by default DataStreams that the caller writes input into and
+/// reads output from between runs, but any InputSource can answer the program's reads as they
+/// happen, and any OutputSink can handle its output as it is written.
+pub struct IntCodeComputer<
+    T: Cell = i64,
+    O: Observer<T> = (),
+    I: InputSource<T> = DataStream<T>,
+    S: OutputSink<T> = DataStream<T>,
+> {
     ptr: u64,
-    memory: Memory,
-    pub input: DataStream,
-    rel_pos: u64,
-    output: DataStream,
+    memory: Memory<T>,
+    pub input: I,
+    rel_pos: i64,
+    pub output: S,
     state: ComputerState,
+    observer: O,
+    /// What reset needs to put the computer back the way it was loaded.
+    loaded: Loaded<T>,
+}
+
+/// The state a computer was loaded in. Memory isn't copied up front: the first time the program
+/// writes to a page, the page's loaded contents are saved, so reset only restores those.
+struct Loaded<T> {
+    /// How many cells of memory hold the program; the rest were zeros.
+    program_len: usize,
+    ptr: u64,
+    rel_pos: i64,
+    /// A bit for each page of memory the program has written to since it was loaded.
+    dirty: Vec<u64>,
+    /// Where each saved page starts and how many of its cells were loaded.
+    pages: Vec<(usize, usize)>,
+    /// The loaded cells of the saved pages, one page after another.
+    cells: Vec<T>,
+}
+
+impl<T> Loaded<T> {
+    fn new(program_len: usize, ptr: u64, rel_pos: i64) -> Self {
+        Self {
+            program_len,
+            ptr,
+            rel_pos,
+            dirty: vec![],
+            pages: vec![],
+            cells: vec![],
+        }
+    }
+
+    /// The length of memory when it was loaded.
+    fn len(&self) -> usize {
+        self.program_len.max(MIN_MEMORY)
+    }
 }
 
 type BinaryModes = [ParamMode; 2];
@@ -236,265 +572,559 @@ enum ParamMode {
 }
 
 impl ParamMode {
-    fn parse(v: i64) -> Self {
+    fn parse(v: i64) -> Result<Self, ErrorKind> {
         match v {
-            0 => ParamMode::Position,
-            1 => ParamMode::Immediate,
-            2 => ParamMode::RelativeMode,
-            _ => {
-                panic!("unexpected val for param mode {}", v)
-            }
+            0 => Ok(ParamMode::Position),
+            1 => Ok(ParamMode::Immediate),
+            2 => Ok(ParamMode::RelativeMode),
+            _ => Err(ErrorKind::InvalidParamMode(v)),
         }
     }
 }
 
 type InstructionPointer = u64;
 
-#[derive(Copy, Clone)]
-enum ComputerState {
+/// ComputerState is what the computer will do the next time it runs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ComputerState {
     Halted,
     ReadyForInstruction,
     WaitingForInput,
-    Panic,
+    Crashed(IntCodeError),
 }
 
-fn parse_instruction(val: i64) -> Instruction {
+/// ErrorKind says what went wrong when a program did something the computer can't execute.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    InvalidOpcode(i64),
+    InvalidParamMode(i64),
+    NegativeAddress(i64),
+    AddressOutOfRange(i64),
+    /// A value used as an address, opcode or offset does not fit in 64 bits.
+    ValueOutOfRange,
+    /// Arithmetic on the cell type overflowed.
+    Overflow,
+    ReadFromClosedStream,
+    /// An InputSource couldn't read or parse the next value.
+    InvalidInput,
+}
+
+impl ErrorKind {
+    /// Returns the name of the kind without its payload, for grouping errors together.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ErrorKind::InvalidOpcode(_) => "invalid opcode",
+            ErrorKind::InvalidParamMode(_) => "invalid param mode",
+            ErrorKind::NegativeAddress(_) => "negative address",
+            ErrorKind::AddressOutOfRange(_) => "address out of range",
+            ErrorKind::ValueOutOfRange => "value out of range",
+            ErrorKind::Overflow => "overflow",
+            ErrorKind::ReadFromClosedStream => "read from closed stream",
+            ErrorKind::InvalidInput => "invalid input",
+        }
+    }
+}
+
+/// IntCodeError is an ErrorKind together with the address of the instruction that caused it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntCodeError {
+    pub ptr: u64,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for IntCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ErrorKind::InvalidOpcode(v) => write!(f, "unexpected val for opcode {}", v),
+            ErrorKind::InvalidParamMode(v) => write!(f, "unexpected val for param mode {}", v),
+            ErrorKind::NegativeAddress(v) => {
+                write!(f, "cannot use negative value {} as address", v)
+            }
+            ErrorKind::AddressOutOfRange(v) => {
+                write!(f, "address {} is past the end of addressable memory", v)
+            }
+            ErrorKind::ValueOutOfRange => write!(f, "value does not fit in 64 bits"),
+            ErrorKind::Overflow => write!(f, "arithmetic overflow"),
+            ErrorKind::ReadFromClosedStream => write!(f, "reading from a closed data stream"),
+            ErrorKind::InvalidInput => write!(f, "input could not be read as a value"),
+        }?;
+        write!(f, " (instruction at {})", self.ptr)
+    }
+}
+
+impl std::error::Error for IntCodeError {}
+
+/// RunOutcome says why a call to run_with_budget returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RunOutcome {
+    Halted,
+    WaitingForInput,
+    BudgetExhausted,
+}
+
+/// The most instructions a single call to run will execute before assuming the program is stuck.
+const MAX_INSTRUCTIONS_PER_RUN: u64 = 10_000_000;
+/// The largest address a program may use. Memory grows on demand up to this size.
+pub const MAX_ADDRESS: i64 = 1 << 20;
+
+fn parse_instruction(val: i64) -> Result<Instruction, ErrorKind> {
     let op_code = val % 100;
-    match op_code {
+    Ok(match op_code {
         1 => Instruction::Add {
             modes: [
-                ParamMode::parse((val / 100) % 10),
-                ParamMode::parse((val / 1000) % 10),
-                ParamMode::parse((val / 10000) % 10),
+                ParamMode::parse((val / 100) % 10)?,
+                ParamMode::parse((val / 1000) % 10)?,
+                ParamMode::parse((val / 10000) % 10)?,
             ],
         },
         2 => Instruction::Mult {
             modes: [
-                ParamMode::parse(val / 100 % 10),
-                ParamMode::parse(val / 1000 % 10),
-                ParamMode::parse(val / 10000 % 10),
+                ParamMode::parse(val / 100 % 10)?,
+                ParamMode::parse(val / 1000 % 10)?,
+                ParamMode::parse(val / 10000 % 10)?,
             ],
         },
         3 => Instruction::ReadInput {
-            modes: ParamMode::parse(val / 100 % 10),
+            modes: ParamMode::parse(val / 100 % 10)?,
         },
         4 => Instruction::WriteOutput {
-            modes: ParamMode::parse(val / 100 % 10),
+            modes: ParamMode::parse(val / 100 % 10)?,
         },
         5 => Instruction::JumpIfTrue {
             modes: [
-                ParamMode::parse((val / 100) % 10),
-                ParamMode::parse((val / 1000) % 10),
+                ParamMode::parse((val / 100) % 10)?,
+                ParamMode::parse((val / 1000) % 10)?,
             ],
         },
         6 => Instruction::JumpIfFalse {
             modes: [
-                ParamMode::parse((val / 100) % 10),
-                ParamMode::parse((val / 1000) % 10),
+                ParamMode::parse((val / 100) % 10)?,
+                ParamMode::parse((val / 1000) % 10)?,
             ],
         },
         7 => Instruction::LessThan {
             modes: [
-                ParamMode::parse((val / 100) % 10),
-                ParamMode::parse((val / 1000) % 10),
-                ParamMode::parse((val / 10000) % 10),
+                ParamMode::parse((val / 100) % 10)?,
+                ParamMode::parse((val / 1000) % 10)?,
+                ParamMode::parse((val / 10000) % 10)?,
             ],
         },
         8 => Instruction::Equals {
             modes: [
-                ParamMode::parse((val / 100) % 10),
-                ParamMode::parse((val / 1000) % 10),
-                ParamMode::parse((val / 10000) % 10),
+                ParamMode::parse((val / 100) % 10)?,
+                ParamMode::parse((val / 1000) % 10)?,
+                ParamMode::parse((val / 10000) % 10)?,
             ],
         },
         9 => Instruction::AdjustRelativePosition {
-            modes: ParamMode::parse((val / 100) % 10),
+            modes: ParamMode::parse((val / 100) % 10)?,
         },
         99 => Instruction::End,
-        _ => panic!("unexpected val for opcode {}", op_code),
-    }
+        _ => return Err(ErrorKind::InvalidOpcode(val)),
+    })
 }
 
-fn pad_memory(memory: Vec<i64>) -> Vec<i64> {
-    let mut new_memory = memory.clone();
-    new_memory.resize(10000, 0);
+/// Programs are padded with zeros to at least this many cells.
+const MIN_MEMORY: usize = 10000;
+/// Memory is divided into pages of 1 << PAGE_BITS cells, to track which parts a program wrote.
+const PAGE_BITS: u32 = 6;
+
+fn pad_memory<T: Cell>(memory: Vec<T>) -> Vec<T> {
+    let mut new_memory = memory;
+    if new_memory.len() < MIN_MEMORY {
+        new_memory.resize(MIN_MEMORY, T::zero());
+    }
     new_memory
 }
 
 impl IntCodeComputer {
     /// Returns an IntCodeComputer initialized with the given memory.
     pub fn new(memory: Vec<i64>) -> Self {
+        Self::with_cells(memory)
+    }
+}
+
+impl<T: Cell> IntCodeComputer<T> {
+    /// Returns an IntCodeComputer over any Cell type initialized with the given memory. For
+    /// example, `IntCodeComputer::<BigInt>::with_cells(program)`.
+    pub fn with_cells(memory: Vec<T>) -> Self {
         Self {
             ptr: 0,
-            memory: Memory { memory: pad_memory(memory) },
-            input: DataStream::new(),
+            loaded: Loaded::new(memory.len(), 0, 0),
+            memory: Memory {
+                memory: pad_memory(memory),
+            },
+            input: DataStream::default(),
             rel_pos: 0,
-            output: DataStream::new(),
+            output: DataStream::default(),
             state: ComputerState::ReadyForInstruction,
+            observer: (),
+        }
+    }
+
+    /// Returns an IntCodeComputer that picks up a program part way through: `memory` as it is
+    /// now, the next instruction at `ptr` and the relative base at `relative_base`. It is how
+    /// code generated by the transpiler hands a program back to the interpreter. Resetting it
+    /// goes back to this state, not to the start of the program, which it never saw.
+    pub fn from_state(memory: Vec<T>, ptr: u64, relative_base: i64) -> Self {
+        Self {
+            ptr,
+            rel_pos: relative_base,
+            loaded: Loaded::new(memory.len(), ptr, relative_base),
+            ..Self::with_cells(memory)
+        }
+    }
+}
+
+impl<T: Cell, O: Observer<T>> IntCodeComputer<T, O> {
+    pub fn attach_input(&mut self, input: DataStream<T>) {
+        self.input = input
+    }
+}
+
+impl<T: Cell, O: Observer<T>, I: InputSource<T>> IntCodeComputer<T, O, I> {
+    /// Returns the output written since the last time it was read, and marks it as read. Unlike
+    /// dump_output, it doesn't copy anything.
+    pub fn drain_output(&mut self) -> &[T] {
+        self.output.drain()
+    }
+
+    /// Returns the last value the program output.
+    pub fn last_output(&self) -> Option<&T> {
+        self.output.last()
+    }
+
+    /// Iterates over everything the program has output since the output was last cleared.
+    pub fn outputs(&self) -> std::slice::Iter<'_, T> {
+        self.output.iter()
+    }
+
+    /// Returns a copy of the output with every value unread, so that all of it can be read again.
+    pub fn dump_output(&self) -> DataStream<T> {
+        let mut out = self.output.copy();
+        // Reset the consumer ind to allow the caller to fully read out the output
+        // TODO: Maybe just return the memory here?
+        out.consumer_ind = 0;
+        out
+    }
+
+    pub fn clear_output(&mut self) {
+        self.output.reset()
+    }
+}
+
+impl<T: Cell, O: Observer<T>, I: InputSource<T>, S: OutputSink<T>> IntCodeComputer<T, O, I, S> {
+    /// Returns the computer with an observer attached, which is told about every instruction the
+    /// computer runs from now on.
+    pub fn with_observer<P: Observer<T>>(self, observer: P) -> IntCodeComputer<T, P, I, S> {
+        IntCodeComputer {
+            ptr: self.ptr,
+            memory: self.memory,
+            input: self.input,
+            rel_pos: self.rel_pos,
+            output: self.output,
+            state: self.state,
+            observer,
+            loaded: self.loaded,
+        }
+    }
+
+    /// Returns the computer reading its input from `input` from now on. Values still queued in
+    /// the old input are dropped.
+    pub fn with_input<J: InputSource<T>>(self, input: J) -> IntCodeComputer<T, O, J, S> {
+        IntCodeComputer {
+            ptr: self.ptr,
+            memory: self.memory,
+            input,
+            rel_pos: self.rel_pos,
+            output: self.output,
+            state: self.state,
+            observer: self.observer,
+            loaded: self.loaded,
+        }
+    }
+
+    /// Returns the computer writing its output to `output` from now on. Values the old output
+    /// still holds are dropped.
+    pub fn with_output<Q: OutputSink<T>>(self, output: Q) -> IntCodeComputer<T, O, I, Q> {
+        IntCodeComputer {
+            ptr: self.ptr,
+            memory: self.memory,
+            input: self.input,
+            rel_pos: self.rel_pos,
+            output,
+            state: self.state,
+            observer: self.observer,
+            loaded: self.loaded,
+        }
+    }
+
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Returns the observer, detaching it from the computer.
+    pub fn into_observer(self) -> O {
+        self.observer
+    }
+
+    pub fn state(&self) -> ComputerState {
+        self.state
+    }
+
+    /// Writes a value to memory on behalf of the program.
+    fn store(&mut self, addr: u64, val: T) {
+        self.observer.memory_written(addr, &val);
+        self.save_page(addr as usize);
+        self.memory.write(addr, val);
+    }
+
+    /// Saves the loaded contents of the page holding `addr`, the first time the program writes
+    /// to it.
+    fn save_page(&mut self, addr: usize) {
+        let loaded = &mut self.loaded;
+        let page = addr >> PAGE_BITS;
+        if page / 64 >= loaded.dirty.len() {
+            loaded.dirty.resize(page / 64 + 1, 0);
+        }
+        let bit = 1 << (page % 64);
+        if loaded.dirty[page / 64] & bit != 0 {
+            return;
+        }
+        loaded.dirty[page / 64] |= bit;
+        let start = page << PAGE_BITS;
+        let end = (start + (1 << PAGE_BITS)).min(loaded.len());
+        if start < end {
+            loaded.pages.push((start, end - start));
+            loaded
+                .cells
+                .extend_from_slice(&self.memory.memory[start..end]);
+        }
+    }
+
+    /// Puts the computer back the way it was when it was loaded: the program's memory, the
+    /// pointer and relative base where they started, and its input and output empty. Only the
+    /// pages of memory the program has written to are copied back, so this is much cheaper than
+    /// making a new computer.
+    pub fn reset(&mut self) {
+        let loaded = &mut self.loaded;
+        self.memory.memory.truncate(loaded.len());
+        let mut cells = &loaded.cells[..];
+        for &(start, len) in loaded.pages.iter() {
+            self.memory.memory[start..start + len].clone_from_slice(&cells[..len]);
+            cells = &cells[len..];
+        }
+        loaded.pages.clear();
+        loaded.cells.clear();
+        for word in loaded.dirty.iter_mut() {
+            *word = 0;
+        }
+        self.ptr = loaded.ptr;
+        self.rel_pos = loaded.rel_pos;
+        self.state = ComputerState::ReadyForInstruction;
+        self.input.reset();
+        self.output.reset();
+    }
+
+    /// Resets the computer with a different program, reusing its memory. Only the cells of the
+    /// new program, the rest of the old one and the pages the old one changed are written.
+    pub fn reset_with(&mut self, program: &[T]) {
+        self.loaded.ptr = 0;
+        self.loaded.rel_pos = 0;
+        self.reset();
+        let len = program.len().max(MIN_MEMORY);
+        self.memory.memory.resize(len, T::zero());
+        // The rest of the old program has to become zeros, like the memory after it.
+        let old_end = self.loaded.program_len.min(len);
+        for cell in self.memory.memory[program.len().min(old_end)..old_end].iter_mut() {
+            *cell = T::zero();
         }
+        self.memory.memory[..program.len()].clone_from_slice(program);
+        self.loaded.program_len = program.len();
     }
 
-    fn exec_add(&mut self, modes: TrinaryModes) {
-        let (a, b, addr) = self.parse_trinary_op(modes);
-        debug!("inst: ADD");
-        debug!("a:    {}", a);
-        debug!("b:    {}", b);
-        debug!("addr: {}", addr);
-        self.add(a, b, addr);
+    fn exec_add(&mut self, modes: TrinaryModes) -> Result<(), ErrorKind> {
+        let (a, b, addr) = self.parse_trinary_op(modes)?;
+        self.add(a, b, addr)?;
         self.ptr += 4;
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_mult(&mut self, modes: TrinaryModes) {
-        let (a, b, addr) = self.parse_trinary_op(modes);
-        debug!("inst: MULT");
-        debug!("a:    {}", a);
-        debug!("b:    {}", b);
-        debug!("addr: {}", addr);
-        self.mult(a, b, addr);
+    fn exec_mult(&mut self, modes: TrinaryModes) -> Result<(), ErrorKind> {
+        let (a, b, addr) = self.parse_trinary_op(modes)?;
+        self.mult(a, b, addr)?;
         self.ptr += 4;
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_read(&mut self, mode: ParamMode) {
-        match self.input.read() {
-            DsRead::Closed => {
-                panic!("Reading from a closed data stream")
-            }
-            DsRead::NoData => {
-                debug!("attempted to read from input but there was none available");
+    fn exec_read(&mut self, op_code: i64, mode: ParamMode) -> Result<(), ErrorKind> {
+        let value = self.input.try_read();
+        // Waiting doesn't run the instruction, so it is only reported once there is input, or an
+        // error.
+        if value != Ok(None) {
+            self.observer.instruction_decoded(self.ptr, op_code);
+        }
+        match value? {
+            None => {
                 self.state = ComputerState::WaitingForInput;
             }
-            DsRead::Data(d) => {
-                let addr = self.memory.read(self.ptr + 1);
+            Some(d) => {
+                let addr = to_i64(&self.memory.read(self.ptr + 1))?;
                 //                let addr = self.memory.read_mode(self.ptr + 1, self.rel_pos, &mode);
-                debug!("inst: READ");
-                debug!("addr: {}", addr);
-                debug!("data: {}", d);
-                match mode {
+                self.observer.input_consumed(&d);
+                let addr = match mode {
                     ParamMode::RelativeMode => {
-                        self.memory.write((addr + self.rel_pos as i64) as u64, d);
-                    }
-                    _ => {
-                        self.memory.write(addr as u64, d);
+                        addr.checked_add(self.rel_pos).ok_or(ErrorKind::Overflow)?
                     }
-                }
+                    _ => addr,
+                };
+                self.store(to_addr(addr)?, d);
                 self.ptr += 2;
                 self.state = ComputerState::ReadyForInstruction;
             }
         }
+        Ok(())
     }
 
-    fn exec_write(&mut self, mode: ParamMode) {
-        let val = self.parse_unary_op(&mode);
-        debug!("inst: WRITE");
-        debug!("val: {}", val);
+    fn exec_write(&mut self, mode: ParamMode) -> Result<(), ErrorKind> {
+        let val = self.parse_unary_op(&mode)?;
+        self.observer.output_produced(&val);
         self.output.write(val);
         self.ptr += 2;
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_jump_if_true(&mut self, modes: BinaryModes) {
-        let (expr, addr) = self.parse_binary_op(modes);
-        debug!("inst: JUMP_IF_TRUE");
-        debug!("expr: {}", expr);
-        debug!("addr: {}", addr);
-        self.ptr = if expr != 0 {
-            addr as u64
+    fn exec_jump_if_true(&mut self, modes: BinaryModes) -> Result<(), ErrorKind> {
+        let (expr, addr) = self.parse_binary_op(modes)?;
+        let taken = !expr.is_zero();
+        let ptr = self.ptr;
+        self.ptr = if taken {
+            to_addr(to_i64(&addr)?)?
         } else {
             self.ptr + 3
         };
+        self.observer.jump_resolved(ptr, taken);
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_jump_if_false(&mut self, modes: BinaryModes) {
-        let (expr, addr) = self.parse_binary_op(modes);
-        debug!("inst: JUMP_IF_FALSE");
-        debug!("expr: {}", expr);
-        debug!("addr: {}", addr);
-        self.ptr = if expr == 0 {
-            addr as u64
+    fn exec_jump_if_false(&mut self, modes: BinaryModes) -> Result<(), ErrorKind> {
+        let (expr, addr) = self.parse_binary_op(modes)?;
+        let taken = expr.is_zero();
+        let ptr = self.ptr;
+        self.ptr = if taken {
+            to_addr(to_i64(&addr)?)?
         } else {
             self.ptr + 3
         };
+        self.observer.jump_resolved(ptr, taken);
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_less_than(&mut self, modes: TrinaryModes) {
-        let (a, b, addr) = self.parse_trinary_op(modes);
-        let val = if a < b { 1 } else { 0 };
-        debug!("inst: LESS_THAN");
-        debug!("addr: {}", addr);
-        debug!("val: {}", val);
-        self.memory.write(addr, val);
+    fn exec_less_than(&mut self, modes: TrinaryModes) -> Result<(), ErrorKind> {
+        let (a, b, addr) = self.parse_trinary_op(modes)?;
+        let val = if a < b { T::one() } else { T::zero() };
+        self.store(addr, val);
         self.ptr += 4;
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_equals(&mut self, modes: TrinaryModes) {
-        let (a, b, addr) = self.parse_trinary_op(modes);
-        let val = if a == b { 1 } else { 0 };
-        debug!("inst: EQUALS");
-        debug!("addr: {}", addr);
-        debug!("val: {}", val);
-        self.memory.write(addr, val);
+    fn exec_equals(&mut self, modes: TrinaryModes) -> Result<(), ErrorKind> {
+        let (a, b, addr) = self.parse_trinary_op(modes)?;
+        let val = if a == b { T::one() } else { T::zero() };
+        self.store(addr, val);
         self.ptr += 4;
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
-    fn exec_adjust_rel_pos(&mut self, mode: ParamMode) {
-        let val = self.parse_unary_op(&mode);
-        debug!("inst: ADJUST REL POS");
-        debug!("val: {}", val);
-        self.rel_pos = (self.rel_pos as i64 + val) as u64;
+    fn exec_adjust_rel_pos(&mut self, mode: ParamMode) -> Result<(), ErrorKind> {
+        let val = to_i64(&self.parse_unary_op(&mode)?)?;
+        self.rel_pos = self.rel_pos.checked_add(val).ok_or(ErrorKind::Overflow)?;
         self.ptr += 2;
         self.state = ComputerState::ReadyForInstruction;
+        Ok(())
     }
 
     /// execute evaluates a single instruction. It returns a code indicating whether the execution
     /// was successful.
     fn execute(&mut self) -> (ComputerState, InstructionPointer) {
         let last_ptr = self.ptr;
-        debug!("===========================");
-        debug!("Ptr:    {}", last_ptr);
-        debug!("OpCode: {}", self.memory.read(self.ptr));
-        let instruction = parse_instruction(self.memory.read(self.ptr));
-        match instruction {
-            Instruction::Add { modes } => { self.exec_add(modes); }
-            Instruction::AdjustRelativePosition { modes } => { self.exec_adjust_rel_pos(modes); }
-            Instruction::Mult { modes } => { self.exec_mult(modes); }
-            Instruction::ReadInput { modes } => { self.exec_read(modes); }
-            Instruction::WriteOutput { modes } => { self.exec_write(modes); }
-            Instruction::JumpIfTrue { modes } => { self.exec_jump_if_true(modes); }
-            Instruction::JumpIfFalse { modes } => { self.exec_jump_if_false(modes); }
-            Instruction::LessThan { modes } => { self.exec_less_than(modes); }
-            Instruction::Equals { modes } => { self.exec_equals(modes); }
-            Instruction::End => { self.state = ComputerState::Halted; }
+        let last_state = self.state;
+        if let Err(kind) = self.try_execute() {
+            self.state = ComputerState::Crashed(IntCodeError {
+                ptr: last_ptr,
+                kind,
+            });
+        }
+        if self.state != last_state {
+            self.observer.state_changed(&self.state);
         }
         (self.state, last_ptr)
     }
 
+    fn try_execute(&mut self) -> Result<(), ErrorKind> {
+        let op_code = to_i64(&self.memory.read(self.ptr))?;
+        let instruction = parse_instruction(op_code)?;
+        if !matches!(instruction, Instruction::ReadInput { .. }) {
+            self.observer.instruction_decoded(self.ptr, op_code);
+        }
+        match instruction {
+            Instruction::Add { modes } => self.exec_add(modes),
+            Instruction::AdjustRelativePosition { modes } => self.exec_adjust_rel_pos(modes),
+            Instruction::Mult { modes } => self.exec_mult(modes),
+            Instruction::ReadInput { modes } => self.exec_read(op_code, modes),
+            Instruction::WriteOutput { modes } => self.exec_write(modes),
+            Instruction::JumpIfTrue { modes } => self.exec_jump_if_true(modes),
+            Instruction::JumpIfFalse { modes } => self.exec_jump_if_false(modes),
+            Instruction::LessThan { modes } => self.exec_less_than(modes),
+            Instruction::Equals { modes } => self.exec_equals(modes),
+            Instruction::End => {
+                self.state = ComputerState::Halted;
+                Ok(())
+            }
+        }
+    }
+
+    /// Runs until the program halts or needs input. It panics if the program does something the
+    /// computer can't execute, or runs for so long that it is probably stuck in a loop. Use
+    /// run_with_budget to handle those cases instead.
     pub fn run(&mut self) {
-        let mut counter = 0;
-        loop {
-            counter += 1;
-            let (out, ptr_addr) = self.execute();
+        match self.run_with_budget(MAX_INSTRUCTIONS_PER_RUN) {
+            Ok(RunOutcome::Halted) | Ok(RunOutcome::WaitingForInput) => (),
+            Ok(RunOutcome::BudgetExhausted) => panic!(
+                "program has run more than {} operations. Probably stuck in a loop.",
+                MAX_INSTRUCTIONS_PER_RUN
+            ),
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Runs at most `budget` instructions. Unlike run, it never panics: a program that does
+    /// something the computer can't execute returns the error, and so does every later call.
+    pub fn run_with_budget(&mut self, budget: u64) -> Result<RunOutcome, IntCodeError> {
+        if let ComputerState::Crashed(e) = self.state {
+            return Err(e);
+        }
+        for _ in 0..budget {
+            let (out, _) = self.execute();
             match out {
-                ComputerState::Halted => return,
+                ComputerState::Halted => return Ok(RunOutcome::Halted),
                 ComputerState::ReadyForInstruction => (),
-                ComputerState::Panic => panic!(
-                    "encountered unknown opcode. Please inspect memory at {}",
-                    ptr_addr
-                ),
-                ComputerState::WaitingForInput => {
-                    debug!("int code computer halted, waiting on input");
-                    return;
-                }
-            }
-            if counter >= 10000 {
-                panic!("program has run more than 10000 operations. Probably stuck in a loop.")
+                ComputerState::WaitingForInput => return Ok(RunOutcome::WaitingForInput),
+                ComputerState::Crashed(e) => return Err(e),
             }
         }
+        Ok(RunOutcome::BudgetExhausted)
     }
 
     pub fn is_halted(&self) -> bool {
@@ -505,27 +1135,40 @@ impl IntCodeComputer {
         matches!(self.state, ComputerState::WaitingForInput)
     }
 
+    /// Returns the computer's memory, without copying it.
+    pub fn memory(&self) -> &Memory<T> {
+        &self.memory
+    }
+
     /// Returns a copy of memory. Note that this only represents a current snapshot; it will not be
     /// updated.
-    pub fn dump_memory(&self) -> Memory {
+    pub fn dump_memory(&self) -> Memory<T> {
         Memory {
             memory: self.memory.memory.clone(),
         }
     }
 
-    fn parse_unary_op(&self, mode: &ParamMode) -> i64 {
+    fn parse_unary_op(&self, mode: &ParamMode) -> Result<T, ErrorKind> {
         self.memory.read_mode(self.ptr + 1, self.rel_pos, mode)
     }
 
-    fn parse_binary_op(&self, modes: BinaryModes) -> (i64, i64) {
-        let a = self.memory.read_mode(self.ptr + 1, self.rel_pos, &modes[0]);
-        let b = self.memory.read_mode(self.ptr + 2, self.rel_pos, &modes[1]);
-        (a, b)
+    fn parse_binary_op(&self, modes: BinaryModes) -> Result<(T, T), ErrorKind> {
+        let a = self
+            .memory
+            .read_mode(self.ptr + 1, self.rel_pos, &modes[0])?;
+        let b = self
+            .memory
+            .read_mode(self.ptr + 2, self.rel_pos, &modes[1])?;
+        Ok((a, b))
     }
 
-    fn parse_trinary_op(&self, modes: TrinaryModes) -> (i64, i64, u64) {
-        let a = self.memory.read_mode(self.ptr + 1, self.rel_pos, &modes[0]);
-        let b = self.memory.read_mode(self.ptr + 2, self.rel_pos, &modes[1]);
+    fn parse_trinary_op(&self, modes: TrinaryModes) -> Result<(T, T, u64), ErrorKind> {
+        let a = self
+            .memory
+            .read_mode(self.ptr + 1, self.rel_pos, &modes[0])?;
+        let b = self
+            .memory
+            .read_mode(self.ptr + 2, self.rel_pos, &modes[1])?;
         // The last param is never supposed to be interpreted as a pointer, it should be read
         // as an immediate. However, according to docs, the last one is never an immediate, it's always
         // a postitional. It seems like there are two types: ints and pointers. The first two arguments
@@ -541,51 +1184,35 @@ impl IntCodeComputer {
         // I'll see how the int code evolves, but I will probably have to change the type system
         // to accomodate this apparently contradictory statement about the write arg never being
         // in immediate, even though it clearly is here. >:(
+        // In relative mode the address is the raw param offset by the relative position, the same
+        // way exec_read computes its destination.
+        let raw = to_i64(&self.memory.read(self.ptr + 3))?;
         let addr = match modes[2] {
-            ParamMode::RelativeMode => {
-                self.memory.read_mode(self.ptr + 3, self.rel_pos, &ParamMode::RelativeMode)
-            }
-            _ => {
-                self.memory.read_mode(self.ptr + 3, self.rel_pos, &ParamMode::Immediate)
-            }
+            ParamMode::RelativeMode => raw.checked_add(self.rel_pos).ok_or(ErrorKind::Overflow)?,
+            _ => raw,
         };
-        if addr < 0 {
-            panic!("cannot use negative value {} as address", addr)
-        }
-        (a, b, addr as u64)
+        Ok((a, b, to_addr(addr)?))
     }
 
     /// Computation Operation are simple and just perform arithmetic operations and write to the
     /// specified location. Any kind of work to determine if an operand is read from address or
     /// pointer should be done before calling the function.
-    fn add(&mut self, a: i64, b: i64, addr: u64) {
-        self.memory.write(addr, a + b)
-    }
-
-    fn mult(&mut self, a: i64, b: i64, addr: u64) {
-        self.memory.write(addr, a * b)
-    }
-
-    pub fn attach_input(&mut self, input: DataStream) {
-        self.input = input
-    }
-
-    pub fn dump_output(&self) -> DataStream {
-        let mut out = self.output.copy();
-        // Reset the consumer ind to allow the caller to fully read out the output
-        // TODO: Maybe just return the memory here?
-        out.consumer_ind = 0;
-        out
+    fn add(&mut self, a: T, b: T, addr: u64) -> Result<(), ErrorKind> {
+        let val = a.checked_add(&b).ok_or(ErrorKind::Overflow)?;
+        self.store(addr, val);
+        Ok(())
     }
 
-    pub fn clear_output(&mut self) {
-        self.output.reset()
+    fn mult(&mut self, a: T, b: T, addr: u64) -> Result<(), ErrorKind> {
+        let val = a.checked_mul(&b).ok_or(ErrorKind::Overflow)?;
+        self.store(addr, val);
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::int_code::{DataStream, DsRead, IntCodeComputer, Memory, pad_memory};
+    use crate::int_code::{fuzz, pad_memory, BigInt, Cell, IntCodeComputer};
 
     struct IntCodeDump {
         memory: Vec<i64>,
@@ -718,6 +1345,90 @@ mod tests {
         assert_eq!(computer.dump_memory().memory, pad_memory(vec![1, 0, 99]));
     }
 
+    #[test]
+    fn test_reset() {
+        // Writes its input to 9 and to 20000, then outputs both cells.
+        let program = vec![3, 9, 3, 20000, 4, 9, 4, 20000, 99, 0];
+        let mut computer = IntCodeComputer::new(program.clone());
+        for input in 1..4 {
+            computer.input.write(input);
+            computer.input.write(input * 10);
+            computer.run();
+            assert!(computer.is_halted());
+            assert_eq!(computer.drain_output(), &[input, input * 10]);
+            computer.reset();
+            assert_eq!(computer.dump_memory().memory, pad_memory(program.clone()));
+            assert!(computer.outputs().next().is_none());
+        }
+
+        // A shorter program leaves no trace of the longer one.
+        let long: Vec<i64> = (0..20000).map(|i| i % 7).collect();
+        computer.reset_with(&long);
+        assert_eq!(computer.dump_memory().memory, long);
+        computer.reset_with(&[104, 3, 99]);
+        assert_eq!(computer.dump_memory().memory, pad_memory(vec![104, 3, 99]));
+        computer.run();
+        assert_eq!(computer.drain_output(), &[3]);
+    }
+
+    #[test]
+    fn test_reset_from_state() {
+        // Picks up at the second input of a program that outputs the sum of two inputs.
+        let memory: Vec<i64> = vec![3, 11, 3, 12, 1, 11, 12, 11, 4, 11, 99, 4, 0];
+        let mut computer = IntCodeComputer::from_state(memory.clone(), 2, 0);
+        computer.input.write(5);
+        computer.run();
+        assert_eq!(computer.drain_output(), &[9]);
+        computer.reset();
+        assert_eq!(computer.dump_memory().memory, pad_memory(memory));
+        computer.input.write(6);
+        computer.run();
+        assert_eq!(computer.drain_output(), &[10]);
+    }
+
+    #[test]
+    fn test_reset_matches_new_computer() {
+        let mut rng = fuzz::Rng::new(41);
+        let mut reused = IntCodeComputer::new(vec![]);
+        for _ in 0..2000 {
+            let program = fuzz::random_program(&mut rng, 40);
+            let input = fuzz::random_input(&mut rng, 5);
+            let mut fresh = IntCodeComputer::new(program.clone());
+            reused.reset_with(&program);
+            assert_eq!(reused.memory().diff(fresh.memory()), vec![]);
+            for v in input.iter() {
+                fresh.input.write(*v);
+                reused.input.write(*v);
+            }
+            let outcome = fresh.run_with_budget(1000);
+            assert_eq!(reused.run_with_budget(1000), outcome, "{:?}", program);
+            assert_eq!(reused.memory().diff(fresh.memory()), vec![]);
+            assert_eq!(reused.drain_output(), fresh.drain_output());
+        }
+    }
+
+    #[test]
+    fn test_output_access() {
+        // Outputs each input twice.
+        let mut computer = IntCodeComputer::new(vec![3, 9, 4, 9, 4, 9, 1105, 1, 0, 0]);
+        computer.input.write(4);
+        computer.run();
+        assert_eq!(computer.drain_output(), &[4, 4]);
+        computer.input.write(5);
+        computer.run();
+        assert_eq!(computer.last_output(), Some(&5));
+        assert_eq!(computer.drain_output(), &[5, 5]);
+        assert_eq!(computer.drain_output(), &[] as &[i64]);
+        assert_eq!(
+            computer.outputs().copied().collect::<Vec<_>>(),
+            vec![4, 4, 5, 5]
+        );
+        // The copy still starts from the beginning.
+        assert_eq!(computer.dump_output().read_all(), vec![4, 4, 5, 5]);
+        computer.clear_output();
+        assert_eq!(computer.last_output(), None);
+    }
+
     #[test]
     fn test_large_input_example() {
         // This program returns 999 if the input is less than 8, 1000 if the input is 8, and 1001
@@ -747,19 +1458,37 @@ mod tests {
             run_int_code_computer(quine_program.clone()),
             quine_program.clone(),
         );
-        let output_16_digit = vec![
-            1102, 34915192, 34915192, 7, 4, 7, 99, 0,
-        ];
+        let output_16_digit = vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0];
         assert_int_code_computer_output_matches(
             run_int_code_computer(output_16_digit.clone()),
             vec![1219070632396864],
         );
-        let output_16_digit = vec![
-            104, 1125899906842624, 99,
-        ];
+        let output_16_digit = vec![104, 1125899906842624, 99];
         assert_int_code_computer_output_matches(
             run_int_code_computer(output_16_digit.clone()),
             vec![1125899906842624],
         );
     }
+    #[test]
+    fn test_wide_cells() {
+        // Squares 2^40 and outputs the result, which overflows an i64.
+        let program: Vec<i64> = vec![2, 7, 7, 8, 4, 8, 99, 1 << 40, 0];
+        let mut computer =
+            IntCodeComputer::with_cells(program.iter().map(|v| *v as i128).collect());
+        computer.run();
+        assert!(computer.is_halted());
+        assert_eq!(computer.dump_output().read_all(), vec![1i128 << 80]);
+
+        // Squares it again, which overflows an i128 as well.
+        let program: Vec<i64> = vec![2, 11, 11, 12, 2, 12, 12, 12, 4, 12, 99, 1 << 40, 0];
+        let mut computer =
+            IntCodeComputer::with_cells(program.iter().map(|v| BigInt::from_i64(*v)).collect());
+        computer.run();
+        assert_eq!(
+            computer.dump_output().read_all(),
+            vec!["1461501637330902918203684832716283019655932542976"
+                .parse::<BigInt>()
+                .unwrap()]
+        );
+    }
 }
@@ -0,0 +1,459 @@
+//! A small assembler for Intcode.
+//!
+//! Each line holds at most one instruction or directive, optionally preceded by labels, and `#`
+//! starts a comment:
+//!
+//! ```text
+//! # Echoes its input until it reads a zero.
+//! loop:   in   [x]
+//!         jf   [x], done
+//!         out  [x]
+//!         jt   1, loop
+//! done:   hlt
+//! x:      .data 0
+//! ```
+//!
+//! Operands are written the way they are read. `5` and `loop` are immediates, `[5]` and `[x]` are
+//! positions, and `[rb]`, `[rb+2]` and `[rb-1]` are relative to the relative base. A value may
+//! add or subtract several numbers and labels, as in `[x+1]` or `end-start`. Instructions that
+//! write take their destination last, and it can't be an immediate.
+//!
+//! | mnemonic | opcode | operands               |
+//! |----------|--------|------------------------|
+//! | `add`    | 1      | a, b, dest             |
+//! | `mul`    | 2      | a, b, dest             |
+//! | `in`     | 3      | dest                   |
+//! | `out`    | 4      | a                      |
+//! | `jt`     | 5      | condition, target      |
+//! | `jf`     | 6      | condition, target      |
+//! | `lt`     | 7      | a, b, dest             |
+//! | `eq`     | 8      | a, b, dest             |
+//! | `arb`    | 9      | offset                 |
+//! | `hlt`    | 99     |                        |
+//!
+//! `.data` emits its operands as raw values, so data can be mixed in with code.
+use std::collections::HashMap;
+
+use crate::int_code::loader::ParseError;
+
+/// The mnemonic, opcode and whether each parameter is written to, for every instruction.
+const INSTRUCTIONS: [(&str, i64, &[bool]); 10] = [
+    ("add", 1, &[false, false, true]),
+    ("mul", 2, &[false, false, true]),
+    ("in", 3, &[true]),
+    ("out", 4, &[false]),
+    ("jt", 5, &[false, false]),
+    ("jf", 6, &[false, false]),
+    ("lt", 7, &[false, false, true]),
+    ("eq", 8, &[false, false, true]),
+    ("arb", 9, &[false]),
+    ("hlt", 99, &[]),
+];
+
+/// Decodes the instruction at `addr` into assembler syntax, returning the text and the number of
+/// cells it takes. It returns None if the cell isn't an instruction this module could have
+/// assembled: an unknown opcode or mode, a write in immediate mode, or operands that run past the
+/// end of the program.
+pub fn disassemble(program: &[i64], addr: usize) -> Option<(String, usize)> {
+    let instruction = *program.get(addr)?;
+    let (name, _, writes) = INSTRUCTIONS
+        .iter()
+        .find(|(_, op_code, _)| *op_code == instruction % 100)?;
+    if instruction < 0 || instruction / 10i64.pow(writes.len() as u32 + 2) != 0 {
+        return None;
+    }
+    let mut text = name.to_string();
+    let mut modes = instruction / 100;
+    for (i, writes) in writes.iter().enumerate() {
+        let value = *program.get(addr + 1 + i)?;
+        let operand = match (modes % 10, writes) {
+            (0, _) => format!("[{}]", value),
+            (1, false) => value.to_string(),
+            (2, _) if value < 0 => format!("[rb{}]", value),
+            (2, _) => format!("[rb+{}]", value),
+            _ => return None,
+        };
+        text += if i == 0 { " " } else { ", " };
+        text += &operand;
+        modes /= 10;
+    }
+    Some((text, writes.len() + 1))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Position = 0,
+    Immediate = 1,
+    Relative = 2,
+}
+
+/// A value still waiting for the labels it refers to.
+struct Fixup {
+    index: usize,
+    label: String,
+    negate: bool,
+    line: usize,
+    column: usize,
+}
+
+struct Assembler {
+    program: Vec<i64>,
+    /// The address the program will be loaded at, which labels are relative to.
+    origin: i64,
+    labels: HashMap<String, i64>,
+    fixups: Vec<Fixup>,
+    line: usize,
+}
+
+/// Assembles source in the format described in the module docs into a program.
+pub fn assemble(src: &str) -> Result<Vec<i64>, ParseError> {
+    assemble_at(src, 0)
+}
+
+/// Assembles code that will be placed at `origin` in another program, so that its labels hold
+/// the addresses they will end up at.
+pub fn assemble_at(src: &str, origin: usize) -> Result<Vec<i64>, ParseError> {
+    let mut asm = Assembler {
+        program: vec![],
+        origin: origin as i64,
+        labels: HashMap::new(),
+        fixups: vec![],
+        line: 0,
+    };
+    for (i, line) in src.lines().enumerate() {
+        asm.line = i + 1;
+        let code = match line.find('#') {
+            Some(end) => &line[..end],
+            None => line,
+        };
+        asm.assemble_line(code)?;
+    }
+    for fixup in asm.fixups.iter() {
+        let addr = match asm.labels.get(&fixup.label) {
+            Some(addr) => *addr,
+            None => {
+                return Err(ParseError::new(
+                    fixup.line,
+                    fixup.column,
+                    format!("undefined label '{}'", fixup.label),
+                ))
+            }
+        };
+        let value = &mut asm.program[fixup.index];
+        *value = if fixup.negate {
+            value.checked_sub(addr)
+        } else {
+            value.checked_add(addr)
+        }
+        .ok_or_else(|| {
+            ParseError::new(
+                fixup.line,
+                fixup.column,
+                "value does not fit in 64 bits".to_string(),
+            )
+        })?;
+    }
+    Ok(asm.program)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.'
+}
+
+/// Returns the column at which `part`, a slice of `line`, starts, counting from 1.
+pub(crate) fn column(line: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - line.as_ptr() as usize;
+    line[..offset].chars().count() + 1
+}
+
+impl Assembler {
+    fn error(&self, line: &str, rest: &str, message: String) -> ParseError {
+        ParseError::new(self.line, column(line, rest), message)
+    }
+
+    fn assemble_line(&mut self, line: &str) -> Result<(), ParseError> {
+        let mut rest = line.trim_start();
+        loop {
+            if rest.is_empty() {
+                return Ok(());
+            }
+            let end = rest.find(|c| !is_word_char(c)).unwrap_or(rest.len());
+            let word = &rest[..end];
+            if word.is_empty() {
+                return Err(self.error(line, rest, "expected a label or instruction".to_string()));
+            }
+            let after = rest[end..].trim_start();
+            if let Some(after) = after.strip_prefix(':') {
+                if word.starts_with(|c: char| c.is_ascii_digit()) || word.starts_with('.') {
+                    return Err(self.error(line, rest, format!("invalid label '{}'", word)));
+                }
+                if self.labels.contains_key(word) {
+                    return Err(self.error(line, rest, format!("label '{}' defined twice", word)));
+                }
+                self.labels
+                    .insert(word.to_string(), self.origin + self.program.len() as i64);
+                rest = after.trim_start();
+                continue;
+            }
+            return self.assemble_statement(line, rest, word, after);
+        }
+    }
+
+    fn assemble_statement(
+        &mut self,
+        line: &str,
+        at: &str,
+        word: &str,
+        operands: &str,
+    ) -> Result<(), ParseError> {
+        let operands = self.split_operands(line, operands)?;
+        if word == ".data" {
+            if operands.is_empty() {
+                return Err(self.error(line, at, ".data needs at least one value".to_string()));
+            }
+            for operand in operands {
+                let mode = self.push_operand(line, operand)?;
+                if mode != Mode::Immediate {
+                    return Err(self.error(
+                        line,
+                        operand,
+                        ".data values can't have modes".to_string(),
+                    ));
+                }
+            }
+            return Ok(());
+        }
+        let (op_code, writes) = match INSTRUCTIONS.iter().find(|(name, _, _)| *name == word) {
+            Some((_, op_code, writes)) => (*op_code, *writes),
+            None => return Err(self.error(line, at, format!("unknown instruction '{}'", word))),
+        };
+        if operands.len() != writes.len() {
+            return Err(self.error(
+                line,
+                at,
+                format!(
+                    "{} takes {} operands but {} were given",
+                    word,
+                    writes.len(),
+                    operands.len()
+                ),
+            ));
+        }
+        let index = self.program.len();
+        self.program.push(op_code);
+        let mut scale = 100;
+        for (operand, writes) in operands.into_iter().zip(writes.iter()) {
+            let mode = self.push_operand(line, operand)?;
+            if *writes && mode == Mode::Immediate {
+                return Err(self.error(
+                    line,
+                    operand,
+                    format!("{} can't write to an immediate", word),
+                ));
+            }
+            self.program[index] += mode as i64 * scale;
+            scale *= 10;
+        }
+        Ok(())
+    }
+
+    /// Splits a comma separated operand list, returning each operand as a slice of the line so
+    /// that errors can point at it.
+    fn split_operands<'a>(
+        &self,
+        line: &'a str,
+        operands: &'a str,
+    ) -> Result<Vec<&'a str>, ParseError> {
+        let operands = operands.trim_end();
+        if operands.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut out = vec![];
+        let mut rest = operands;
+        loop {
+            let end = rest.find(',').unwrap_or(rest.len());
+            let operand = rest[..end].trim_end();
+            if operand.is_empty() {
+                return Err(self.error(line, rest, "expected an operand".to_string()));
+            }
+            out.push(operand);
+            if end == rest.len() {
+                return Ok(out);
+            }
+            rest = rest[end + 1..].trim_start();
+        }
+    }
+
+    /// Parses an operand, appends its value to the program and returns its mode.
+    fn push_operand(&mut self, line: &str, operand: &str) -> Result<Mode, ParseError> {
+        let (mode, expr) = match operand.strip_prefix('[') {
+            None => (Mode::Immediate, operand),
+            Some(inner) => {
+                let inner = match inner.strip_suffix(']') {
+                    Some(inner) => inner.trim(),
+                    None => return Err(self.error(line, operand, "missing ']'".to_string())),
+                };
+                match inner.strip_prefix("rb") {
+                    Some(offset) if !offset.starts_with(is_word_char) => {
+                        let offset = offset.trim_start();
+                        if offset.is_empty() {
+                            (Mode::Relative, "0")
+                        } else if offset.starts_with('+') || offset.starts_with('-') {
+                            (Mode::Relative, offset)
+                        } else {
+                            return Err(self.error(
+                                line,
+                                offset,
+                                "expected '+' or '-'".to_string(),
+                            ));
+                        }
+                    }
+                    _ => (Mode::Position, inner),
+                }
+            }
+        };
+        let index = self.program.len();
+        self.program.push(0);
+        self.push_expr(line, expr, index)?;
+        Ok(mode)
+    }
+
+    /// Evaluates a sum of numbers and labels into program[index]. Labels are resolved once the
+    /// whole program has been read.
+    fn push_expr(&mut self, line: &str, expr: &str, index: usize) -> Result<(), ParseError> {
+        let mut rest = expr.trim();
+        let mut negate = false;
+        let mut first = true;
+        loop {
+            if !first || rest.starts_with('+') || rest.starts_with('-') {
+                match rest.chars().next() {
+                    Some('+') => negate = false,
+                    Some('-') => negate = true,
+                    _ => return Err(self.error(line, rest, "expected '+' or '-'".to_string())),
+                }
+                rest = rest[1..].trim_start();
+            }
+            first = false;
+            let end = rest.find(|c| !is_word_char(c)).unwrap_or(rest.len());
+            let term = &rest[..end];
+            if term.is_empty() {
+                return Err(self.error(line, rest, "expected a number or label".to_string()));
+            }
+            if term.starts_with(|c: char| c.is_ascii_digit()) {
+                let value: i64 = term
+                    .parse()
+                    .map_err(|_| self.error(line, rest, format!("invalid number '{}'", term)))?;
+                let total = self.program[index];
+                self.program[index] = if negate {
+                    total.checked_sub(value)
+                } else {
+                    total.checked_add(value)
+                }
+                .ok_or_else(|| {
+                    self.error(line, rest, "value does not fit in 64 bits".to_string())
+                })?;
+            } else {
+                self.fixups.push(Fixup {
+                    index,
+                    label: term.to_string(),
+                    negate,
+                    line: self.line,
+                    column: column(line, rest),
+                });
+            }
+            rest = rest[end..].trim_start();
+            if rest.is_empty() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::asm::{assemble, assemble_at, disassemble};
+    use crate::int_code::IntCodeComputer;
+
+    #[test]
+    fn test_assemble() {
+        let src = "
+            # Echoes its input until it reads a zero.
+            loop:   in   [x]
+                    jf   [x], done
+                    out  [x]
+                    jt   1, loop
+            done:   hlt
+            x:      .data 0
+        ";
+        let program = assemble(src).unwrap();
+        assert_eq!(program, vec![3, 11, 1006, 11, 10, 4, 11, 1105, 1, 0, 99, 0]);
+
+        let mut computer = IntCodeComputer::new(program);
+        for v in &[4, 5, 0] {
+            computer.input.write(*v);
+        }
+        computer.run();
+        assert!(computer.is_halted());
+        assert_eq!(computer.dump_output().read_all(), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_modes_and_expressions() {
+        let src = "arb 3\nadd [rb-1], [rb], [rb+2]\nmul [end-1], -2, [7]\nend: .data 1, end+1";
+        assert_eq!(
+            assemble(src).unwrap(),
+            vec![109, 3, 22201, -1, 0, 2, 1002, 9, -2, 7, 1, 11]
+        );
+    }
+
+    #[test]
+    fn test_origin() {
+        assert_eq!(
+            assemble_at("loop: jt 1, loop\nx: .data x", 20).unwrap(),
+            vec![1105, 1, 20, 23]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let cases = vec![
+            ("nop", "1:1: unknown instruction 'nop'"),
+            ("add 1, 2", "1:1: add takes 3 operands but 2 were given"),
+            ("\n  add 1, 2, 3", "2:13: add can't write to an immediate"),
+            ("out [missing", "1:5: missing ']'"),
+            ("jt 1, nowhere", "1:7: undefined label 'nowhere'"),
+            ("a: hlt\na: hlt", "2:1: label 'a' defined twice"),
+            ("out 1,, 2", "1:7: expected an operand"),
+            (".data [3]", "1:7: .data values can't have modes"),
+        ];
+        for (src, msg) in cases {
+            assert_eq!(assemble(src).unwrap_err().to_string(), msg, "{:?}", src);
+        }
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let program = vec![
+            109, 3, 22201, -1, 0, 2, 1002, 9, -2, 7, 99, 11101, 1, 2, 3, 1, 1,
+        ];
+        let mut addr = 0;
+        let mut lines = vec![];
+        while let Some((text, len)) = disassemble(&program, addr) {
+            lines.push(text);
+            addr += len;
+        }
+        assert_eq!(
+            lines,
+            vec![
+                "arb 3",
+                "add [rb-1], [rb+0], [rb+2]",
+                "mul [9], -2, [7]",
+                "hlt"
+            ]
+        );
+        // Writes in immediate mode, and instructions cut off by the end of the program.
+        assert_eq!(addr, 11);
+        assert_eq!(disassemble(&program, 15), None);
+        assert_eq!(assemble(&lines.join("\n")).unwrap(), program[..11].to_vec());
+    }
+}
@@ -0,0 +1,421 @@
+//! Running Intcode programs as futures.
+//!
+//! `IntCodeComputer::run_async` runs a program to completion, awaiting each input from a Stream
+//! and sending each output into a channel, whose Receiver is itself a Stream. Machines connected
+//! this way run concurrently on an Executor with no polling loop around is_waiting_for_input, and
+//! can be mixed with any other futures.
+//!
+//! Everything here is deliberately small. Channels and tasks are single threaded, and the
+//! executor runs tasks on the thread that calls it, parking that thread while nothing is ready.
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::int_code::{Cell, ErrorKind, IntCodeComputer, IntCodeError, Observer, RunOutcome};
+
+/// How many instructions run_async executes before yielding to other tasks.
+const INSTRUCTIONS_PER_POLL: u64 = 10_000;
+
+/// Stream is an asynchronous iterator: a source of values that may not be ready yet. It has the
+/// same shape as the `Stream` trait of the futures crate.
+pub trait Stream {
+    type Item;
+
+    /// Returns the next value if there is one ready, or arranges for the task to be woken when
+    /// there is. Ready(None) means the stream has ended.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+
+    /// Returns a future that resolves to the next value, or None once the stream has ended.
+    fn next(&mut self) -> Next<'_, Self>
+    where
+        Self: Unpin + Sized,
+    {
+        Next { stream: self }
+    }
+}
+
+pub struct Next<'a, S> {
+    stream: &'a mut S,
+}
+
+impl<S: Stream + Unpin> Future for Next<'_, S> {
+    type Output = Option<S::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.stream).poll_next(cx)
+    }
+}
+
+struct Shared<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    waker: Option<Waker>,
+}
+
+/// Creates an unbounded channel. Values sent into the Sender come out of the Receiver in order,
+/// and the Receiver's stream ends once every Sender has been dropped.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Rc::new(RefCell::new(Shared {
+        queue: VecDeque::new(),
+        senders: 1,
+        waker: None,
+    }));
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+pub struct Sender<T> {
+    shared: Rc<RefCell<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    /// Queues a value. It never blocks; the channel has no capacity limit.
+    pub fn send(&self, value: T) {
+        let mut shared = self.shared.borrow_mut();
+        shared.queue.push_back(value);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.borrow_mut().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut shared = self.shared.borrow_mut();
+        shared.senders -= 1;
+        if shared.senders == 0 {
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    shared: Rc<RefCell<Shared<T>>>,
+}
+
+impl<T> Receiver<T> {
+    /// Returns a value if one is queued, without waiting.
+    pub fn try_recv(&self) -> Option<T> {
+        self.shared.borrow_mut().queue.pop_front()
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut shared = self.shared.borrow_mut();
+        if let Some(value) = shared.queue.pop_front() {
+            return Poll::Ready(Some(value));
+        }
+        if shared.senders == 0 {
+            return Poll::Ready(None);
+        }
+        shared.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// Returns a future that gives other tasks a chance to run before it completes.
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// The tasks that have been woken since the executor last looked, and the thread to unpark when
+/// there are some. Wakers can be sent to other threads, so this is shared through a Mutex.
+struct ReadyQueue {
+    ready: Mutex<VecDeque<usize>>,
+    thread: Thread,
+}
+
+struct TaskWaker {
+    queue: Arc<ReadyQueue>,
+    task: usize,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.queue.ready.lock().unwrap().push_back(self.task);
+        self.queue.thread.unpark();
+    }
+}
+
+type Task<'a> = Pin<Box<dyn Future<Output = ()> + 'a>>;
+
+/// Executor runs spawned futures on the current thread until they complete.
+pub struct Executor<'a> {
+    tasks: Vec<Option<Task<'a>>>,
+    queue: Arc<ReadyQueue>,
+}
+
+impl<'a> Default for Executor<'a> {
+    fn default() -> Self {
+        Self {
+            tasks: vec![],
+            queue: Arc::new(ReadyQueue {
+                ready: Mutex::new(VecDeque::new()),
+                thread: thread::current(),
+            }),
+        }
+    }
+}
+
+impl<'a> Executor<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a future to run. It starts running on the next call to run or run_until_stalled.
+    pub fn spawn(&mut self, future: impl Future<Output = ()> + 'a) {
+        self.queue.ready.lock().unwrap().push_back(self.tasks.len());
+        self.tasks.push(Some(Box::pin(future)));
+    }
+
+    /// Polls tasks until none of them are ready, and returns how many have not completed. A
+    /// non-zero result with nothing else to wake them means the tasks are deadlocked, for example
+    /// machines that are all waiting for input from each other.
+    pub fn run_until_stalled(&mut self) -> usize {
+        loop {
+            let next = self.queue.ready.lock().unwrap().pop_front();
+            let task = match next {
+                Some(task) => task,
+                None => break,
+            };
+            let future = match self.tasks[task].as_mut() {
+                Some(future) => future,
+                // Woken after it completed.
+                None => continue,
+            };
+            let waker = Waker::from(Arc::new(TaskWaker {
+                queue: self.queue.clone(),
+                task,
+            }));
+            if future
+                .as_mut()
+                .poll(&mut Context::from_waker(&waker))
+                .is_ready()
+            {
+                self.tasks[task] = None;
+            }
+        }
+        self.tasks.iter().filter(|t| t.is_some()).count()
+    }
+
+    /// Runs until every task has completed, parking the thread while waiting for a waker. It
+    /// only returns once something wakes the remaining tasks, so a deadlock blocks forever; use
+    /// run_until_stalled if that is possible.
+    pub fn run(&mut self) {
+        while self.run_until_stalled() > 0 {
+            if self.queue.ready.lock().unwrap().is_empty() {
+                thread::park();
+            }
+        }
+    }
+}
+
+/// Runs a future to completion on the current thread and returns its output.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let output = RefCell::new(None);
+    let mut executor = Executor::new();
+    executor.spawn(async {
+        *output.borrow_mut() = Some(future.await);
+    });
+    executor.run();
+    drop(executor);
+    output.into_inner().unwrap()
+}
+
+impl<T: Cell, O: Observer<T>> IntCodeComputer<T, O> {
+    /// Runs the program until it halts. Whenever the program needs input it awaits the next value
+    /// from `input`, and every value the program outputs is sent to `output`. Long stretches
+    /// without input or output yield now and then, so other tasks keep running.
+    ///
+    /// It returns an error if the program does something the computer can't execute, or needs
+    /// input after the input stream has ended. `output` is dropped when it returns, which ends
+    /// the stream of the matching Receiver.
+    pub async fn run_async<S>(
+        &mut self,
+        mut input: S,
+        output: Sender<T>,
+    ) -> Result<(), IntCodeError>
+    where
+        S: Stream<Item = T> + Unpin,
+    {
+        loop {
+            let outcome = self.run_with_budget(INSTRUCTIONS_PER_POLL);
+            for v in self.output.read_all() {
+                output.send(v);
+            }
+            match outcome? {
+                RunOutcome::Halted => return Ok(()),
+                RunOutcome::BudgetExhausted => yield_now().await,
+                RunOutcome::WaitingForInput => match input.next().await {
+                    Some(v) => self.input.write(v),
+                    None => {
+                        return Err(IntCodeError {
+                            ptr: self.ptr,
+                            kind: ErrorKind::ReadFromClosedStream,
+                        })
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use crate::int_code::asm::assemble;
+    use crate::int_code::asynchronous::{block_on, channel, Executor, Stream};
+    use crate::int_code::{ErrorKind, IntCodeComputer};
+    use crate::util;
+
+    #[test]
+    fn test_run_async() {
+        // Outputs each input plus one until it reads a zero.
+        let program = assemble(
+            "
+            loop: in  [x]
+                  jf  [x], done
+                  add [x], 1, [x]
+                  out [x]
+                  jt  1, loop
+            done: hlt
+            x:    .data 0
+            ",
+        )
+        .unwrap();
+        let (in_tx, in_rx) = channel();
+        let (out_tx, mut out_rx) = channel();
+        for v in &[1, 2, 3, 0] {
+            in_tx.send(*v);
+        }
+        let mut computer = IntCodeComputer::new(program);
+        block_on(computer.run_async(in_rx, out_tx)).unwrap();
+        assert!(computer.is_halted());
+        let outputs: Vec<i64> = std::iter::from_fn(|| out_rx.try_recv()).collect();
+        assert_eq!(outputs, vec![2, 3, 4]);
+        assert_eq!(block_on(out_rx.next()), None);
+    }
+
+    #[test]
+    fn test_closed_input() {
+        let (in_tx, in_rx) = channel();
+        let (out_tx, _out_rx) = channel();
+        drop(in_tx);
+        let mut computer = IntCodeComputer::new(vec![3, 0, 99]);
+        let err = block_on(computer.run_async(in_rx, out_tx)).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::ReadFromClosedStream);
+    }
+
+    #[test]
+    fn test_deadlock_is_detected() {
+        let (_in_tx, in_rx) = channel();
+        let (out_tx, _out_rx) = channel();
+        let mut computer = IntCodeComputer::new(vec![3, 0, 99]);
+        let mut executor = Executor::new();
+        executor.spawn(async {
+            computer.run_async(in_rx, out_tx).await.unwrap();
+        });
+        assert_eq!(executor.run_until_stalled(), 1);
+    }
+
+    /// Day 7 part 2: five amplifiers in a feedback loop, each running as its own task.
+    fn run_feedback_loop(program: &[i64], phases: &[i64]) -> i64 {
+        let channels: Vec<_> = phases
+            .iter()
+            .map(|phase| {
+                let (tx, rx) = channel();
+                tx.send(*phase);
+                (tx, rx)
+            })
+            .collect();
+        let (senders, receivers): (Vec<_>, Vec<_>) = channels.into_iter().unzip();
+        senders[0].send(0);
+        let (last_tx, mut last_rx) = channel();
+        let thrust = Rc::new(Cell::new(0));
+
+        let mut executor = Executor::new();
+        for (i, input) in receivers.into_iter().enumerate() {
+            let output = if i + 1 < phases.len() {
+                senders[i + 1].clone()
+            } else {
+                last_tx.clone()
+            };
+            let mut computer = IntCodeComputer::new(program.to_vec());
+            executor.spawn(async move {
+                computer.run_async(input, output).await.unwrap();
+            });
+        }
+        drop(last_tx);
+        // The last amplifier's output is both the thrust and the first amplifier's input.
+        let first = senders[0].clone();
+        drop(senders);
+        let result = thrust.clone();
+        executor.spawn(async move {
+            while let Some(v) = last_rx.next().await {
+                result.set(v);
+                first.send(v);
+            }
+        });
+        executor.run();
+        thrust.get()
+    }
+
+    #[test]
+    fn test_day07_feedback_loop() {
+        let program = util::read_int_code_memory("./input/day07.txt");
+        let best = util::Permutations::new(5)
+            .map(|perm| {
+                let phases: Vec<i64> = perm.iter().map(|p| *p as i64 + 5).collect();
+                run_feedback_loop(&program, &phases)
+            })
+            .max()
+            .unwrap();
+        assert_eq!(best, 17519904);
+    }
+}
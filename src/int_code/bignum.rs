@@ -0,0 +1,305 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::int_code::Cell;
+
+/// Each limb holds 32 bits of the magnitude. Products of two limbs plus carries fit in a u64.
+const LIMB_BITS: u32 = 32;
+/// Display works in chunks of nine decimal digits, the largest power of ten that fits in a limb.
+const DECIMAL_CHUNK: u64 = 1_000_000_000;
+
+/// BigInt is an arbitrary-precision signed integer. It is deliberately small: it supports exactly
+/// what an IntCodeComputer needs (addition, multiplication, comparison, parsing and printing) and
+/// nothing else.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    // Little endian limbs of the magnitude. Never has trailing zero limbs, and zero is stored as
+    // an empty vector that is never negative.
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        let negative = negative && !magnitude.is_empty();
+        Self {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        if self.negative == other.negative {
+            return Self::from_parts(
+                self.negative,
+                Self::add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+        // The signs differ, so subtract the smaller magnitude from the larger one and keep the
+        // sign of the larger.
+        match Self::cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => Self::from_parts(
+                other.negative,
+                Self::sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => Self::from_parts(
+                self.negative,
+                Self::sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::from_parts(
+            self.negative != other.negative,
+            Self::mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+
+    fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+        if a.len() != b.len() {
+            return a.len().cmp(&b.len());
+        }
+        for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+            if x != y {
+                return x.cmp(y);
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut out = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0u64;
+        for i in 0..a.len().max(b.len()) {
+            let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+            out.push(sum as u32);
+            carry = sum >> LIMB_BITS;
+        }
+        if carry != 0 {
+            out.push(carry as u32);
+        }
+        out
+    }
+
+    /// Returns a - b. The caller must make sure that |a| >= |b|.
+    fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut out = Vec::with_capacity(a.len());
+        let mut borrow = 0i64;
+        for (i, x) in a.iter().enumerate() {
+            let mut diff = *x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+            if diff < 0 {
+                diff += 1 << LIMB_BITS;
+                borrow = 1;
+            } else {
+                borrow = 0;
+            }
+            out.push(diff as u32);
+        }
+        out
+    }
+
+    fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut out = vec![0u32; a.len() + b.len()];
+        for (i, x) in a.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, y) in b.iter().enumerate() {
+                let cur = out[i + j] as u64 + (*x as u64) * (*y as u64) + carry;
+                out[i + j] = cur as u32;
+                carry = cur >> LIMB_BITS;
+            }
+            out[i + b.len()] = carry as u32;
+        }
+        out
+    }
+
+    /// Divides the magnitude in place by a small divisor and returns the remainder.
+    fn div_rem_small(magnitude: &mut Vec<u32>, divisor: u64) -> u64 {
+        let mut rem = 0u64;
+        for limb in magnitude.iter_mut().rev() {
+            let cur = (rem << LIMB_BITS) | *limb as u64;
+            *limb = (cur / divisor) as u32;
+            rem = cur % divisor;
+        }
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        rem
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(val: i64) -> Self {
+        let abs = val.unsigned_abs();
+        Self::from_parts(val < 0, vec![abs as u32, (abs >> LIMB_BITS) as u32])
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => Self::cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => Self::cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.magnitude.is_empty() {
+            return write!(f, "0");
+        }
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = vec![];
+        while !magnitude.is_empty() {
+            chunks.push(Self::div_rem_small(&mut magnitude, DECIMAL_CHUNK));
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", chunks.last().unwrap())?;
+        for chunk in chunks.iter().rev().skip(1) {
+            write!(f, "{:09}", chunk)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid digit found in string")
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let ten = [10u32];
+        let mut magnitude = vec![];
+        for b in digits.bytes() {
+            magnitude = Self::mul_magnitude(&magnitude, &ten);
+            magnitude = Self::add_magnitude(&magnitude, &[(b - b'0') as u32]);
+        }
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+impl Cell for BigInt {
+    fn zero() -> Self {
+        Self::from_parts(false, vec![])
+    }
+
+    fn one() -> Self {
+        Self::from_parts(false, vec![1])
+    }
+
+    fn from_i64(val: i64) -> Self {
+        Self::from(val)
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let mut abs = 0u64;
+        for (i, limb) in self.magnitude.iter().enumerate() {
+            abs |= (*limb as u64) << (LIMB_BITS * i as u32);
+        }
+        if self.negative {
+            if abs > i64::MAX as u64 + 1 {
+                return None;
+            }
+            Some((abs as i64).wrapping_neg())
+        } else {
+            i64::try_from(abs).ok()
+        }
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self.add(other))
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self.mul(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::{BigInt, Cell};
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for s in &[
+            "0",
+            "1",
+            "-1",
+            "4294967296",
+            "-123456789012345678901234567890",
+        ] {
+            assert_eq!(big(s).to_string(), *s);
+        }
+        assert_eq!(big("-0").to_string(), "0");
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = big("99999999999999999999");
+        let b = big("-1");
+        assert_eq!(a.add(&b), big("99999999999999999998"));
+        assert_eq!(b.add(&a), big("99999999999999999998"));
+        assert_eq!(b.add(&big("1")), BigInt::zero());
+        assert_eq!(a.mul(&a), big("9999999999999999999800000000000000000001"));
+        assert_eq!(a.mul(&b), big("-99999999999999999999"));
+    }
+
+    #[test]
+    fn test_i64_conversion() {
+        for v in &[0, 1, -1, i64::MAX, i64::MIN, 1 << 40] {
+            assert_eq!(BigInt::from_i64(*v).to_i64(), Some(*v));
+            assert_eq!(BigInt::from_i64(*v).to_string(), v.to_string());
+        }
+        assert_eq!(big("9223372036854775808").to_i64(), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(big("-5") < big("-4"));
+        assert!(big("-5") < big("0"));
+        assert!(big("18446744073709551616") > big("18446744073709551615"));
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// Cell is the numeric type stored in every memory address of an IntCodeComputer. Programs only
+/// ever need a handful of operations on their values: arithmetic for ADD and MULT, comparisons for
+/// LT and EQ, and a way to turn a value into an address, opcode or relative offset. Anything that
+/// implements those can back a computer, which lets programs that overflow 64 bits use a wider
+/// type instead. Cells are parsed from text with FromStr, so input can hold values of any size.
+pub trait Cell: Clone + PartialEq + PartialOrd + Debug + Display + FromStr {
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    fn from_i64(val: i64) -> Self;
+
+    /// Returns the value as an i64, or None if it does not fit. Addresses, opcodes and relative
+    /// base adjustments are always small, so the computer uses this to interpret them.
+    fn to_i64(&self) -> Option<i64>;
+
+    /// Returns self + other, or None if the result does not fit in the cell type.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Returns self * other, or None if the result does not fit in the cell type.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+impl Cell for i64 {
+    #[inline]
+    fn zero() -> Self {
+        0
+    }
+
+    #[inline]
+    fn one() -> Self {
+        1
+    }
+
+    #[inline]
+    fn from_i64(val: i64) -> Self {
+        val
+    }
+
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        Some(*self)
+    }
+
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        (*self).checked_add(*other)
+    }
+
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        (*self).checked_mul(*other)
+    }
+}
+
+impl Cell for i128 {
+    #[inline]
+    fn zero() -> Self {
+        0
+    }
+
+    #[inline]
+    fn one() -> Self {
+        1
+    }
+
+    #[inline]
+    fn from_i64(val: i64) -> Self {
+        val as i128
+    }
+
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        if *self < i64::MIN as i128 || *self > i64::MAX as i128 {
+            return None;
+        }
+        Some(*self as i64)
+    }
+
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        (*self).checked_add(*other)
+    }
+
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        (*self).checked_mul(*other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::Cell;
+
+    #[test]
+    fn test_i64_cell() {
+        assert_eq!(Cell::checked_add(&2i64, &3), Some(5));
+        assert_eq!(Cell::checked_mul(&2i64, &3), Some(6));
+        assert_eq!(Cell::checked_mul(&i64::MAX, &2), None);
+        assert!(<i64 as Cell>::zero().is_zero());
+        assert_eq!(<i64 as Cell>::to_i64(&-7), Some(-7));
+    }
+
+    #[test]
+    fn test_i128_cell() {
+        let big = Cell::checked_mul(&(i64::MAX as i128), &4).unwrap();
+        assert_eq!(big, i64::MAX as i128 * 4);
+        assert_eq!(big.to_i64(), None);
+        assert_eq!(<i128 as Cell>::from_i64(-3).to_i64(), Some(-3));
+    }
+}
@@ -0,0 +1,323 @@
+//! Coverage of Intcode programs.
+//!
+//! A Coverage runs a program any number of times, on different inputs, and counts how often each
+//! address was executed as an instruction and which way each jump went, as an Observer of the
+//! computer. The totals can be rendered as an annotated listing, which is valid input for the
+//! assembler, and as an lcov tracefile that refers to the listing's lines.
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::int_code::asm;
+use crate::int_code::{IntCodeComputer, IntCodeError, Observer, RunOutcome};
+
+/// Data cells are listed this many to a line.
+const DATA_PER_LINE: usize = 8;
+
+pub struct Coverage {
+    program: Vec<i64>,
+    counts: Counts,
+    runs: usize,
+}
+
+/// Counts is the Observer that collects the totals.
+#[derive(Default)]
+struct Counts {
+    hits: BTreeMap<u64, u64>,
+    /// How often each jump at an address was taken and not taken.
+    branches: BTreeMap<u64, [u64; 2]>,
+}
+
+impl Observer for Counts {
+    fn instruction_decoded(&mut self, ptr: u64, _op_code: i64) {
+        *self.hits.entry(ptr).or_insert(0) += 1;
+    }
+
+    fn jump_resolved(&mut self, ptr: u64, taken: bool) {
+        self.branches.entry(ptr).or_insert([0, 0])[!taken as usize] += 1;
+    }
+}
+
+/// Summary counts the instructions and branch directions in the listing, and how many of them
+/// were exercised.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub runs: usize,
+    pub instructions: usize,
+    pub instructions_hit: usize,
+    pub branches: usize,
+    pub branches_hit: usize,
+}
+
+/// Line is one line of the listing: an instruction or a run of data.
+struct Line {
+    addr: usize,
+    text: String,
+    /// None for data.
+    hits: Option<u64>,
+    branch: Option<[u64; 2]>,
+}
+
+impl Coverage {
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            program,
+            counts: Counts::default(),
+            runs: 0,
+        }
+    }
+
+    /// Runs the program on the given input for at most `budget` instructions, adding what it
+    /// executed to the totals. Instructions executed before an error still count, and so does
+    /// the one that failed if it could be decoded.
+    pub fn run(&mut self, input: &[i64], budget: u64) -> Result<RunOutcome, IntCodeError> {
+        self.runs += 1;
+        let counts = std::mem::take(&mut self.counts);
+        let mut computer = IntCodeComputer::new(self.program.clone()).with_observer(counts);
+        for v in input {
+            computer.input.write(*v);
+        }
+        let outcome = computer.run_with_budget(budget);
+        self.counts = computer.into_observer();
+        outcome
+    }
+
+    /// Returns how many times the instruction at the address was executed.
+    pub fn hits(&self, addr: u64) -> u64 {
+        self.counts.hits.get(&addr).copied().unwrap_or(0)
+    }
+
+    /// Returns how many times the jump at the address was taken and not taken, or None if no
+    /// jump there was ever executed.
+    pub fn branch(&self, addr: u64) -> Option<(u64, u64)> {
+        self.counts.branches.get(&addr).map(|b| (b[0], b[1]))
+    }
+
+    /// Splits the program into instructions and data. Executed addresses are always decoded as
+    /// instructions. Elsewhere a cell is taken to be an instruction if it decodes as one and
+    /// doesn't overlap an executed instruction. Code is listed as it was loaded, so an instruction
+    /// the program rewrote before running it may be listed as data.
+    fn lines(&self) -> Vec<Line> {
+        let mut lines: Vec<Line> = vec![];
+        let mut addr = 0;
+        while addr < self.program.len() {
+            let hits = self.hits(addr as u64);
+            let decoded = asm::disassemble(&self.program, addr).filter(|(_, len)| {
+                hits > 0 || (addr + 1..addr + len).all(|a| self.hits(a as u64) == 0)
+            });
+            if let Some((text, len)) = decoded {
+                let is_jump = matches!(self.program[addr] % 100, 5 | 6);
+                lines.push(Line {
+                    addr,
+                    text,
+                    hits: Some(hits),
+                    branch: if is_jump {
+                        Some(
+                            self.counts
+                                .branches
+                                .get(&(addr as u64))
+                                .copied()
+                                .unwrap_or([0, 0]),
+                        )
+                    } else {
+                        None
+                    },
+                });
+                addr += len;
+                continue;
+            }
+            let value = self.program[addr].to_string();
+            match lines.last_mut() {
+                Some(line)
+                    if line.hits.is_none()
+                        && line.addr + DATA_PER_LINE > addr
+                        && self.hits(addr as u64) == 0 =>
+                {
+                    line.text += ", ";
+                    line.text += &value;
+                }
+                _ => lines.push(Line {
+                    addr,
+                    text: format!(".data {}", value),
+                    hits: None,
+                    branch: None,
+                }),
+            }
+            addr += 1;
+        }
+        lines
+    }
+
+    pub fn summary(&self) -> Summary {
+        let lines = self.lines();
+        let instructions: Vec<&Line> = lines.iter().filter(|l| l.hits.is_some()).collect();
+        let branches: Vec<[u64; 2]> = lines.iter().filter_map(|l| l.branch).collect();
+        Summary {
+            runs: self.runs,
+            instructions: instructions.len(),
+            instructions_hit: instructions.iter().filter(|l| l.hits != Some(0)).count(),
+            branches: branches.len() * 2,
+            branches_hit: branches.iter().flatten().filter(|n| **n > 0).count(),
+        }
+    }
+
+    /// Returns the program as assembly, with every line annotated with its address and how it was
+    /// covered. The first line is a summary comment.
+    pub fn listing(&self) -> String {
+        let s = self.summary();
+        let mut out = format!(
+            "# {} runs: {}/{} instructions, {}/{} branch directions\n",
+            s.runs, s.instructions_hit, s.instructions, s.branches_hit, s.branches
+        );
+        for line in self.lines() {
+            let note = match (line.hits, line.branch) {
+                // The program rewrote this cell before running it.
+                (None, _) if self.hits(line.addr as u64) > 0 => {
+                    format!("{} hits as modified code", self.hits(line.addr as u64))
+                }
+                (None, _) => String::new(),
+                (Some(0), _) => "never run".to_string(),
+                (Some(n), None) => format!("{} hits", n),
+                (Some(n), Some([taken, not_taken])) => {
+                    format!("{} hits, taken {}, not taken {}", n, taken, not_taken)
+                }
+            };
+            let annotated = format!("{:<40} # {:>5} {}", line.text, line.addr, note);
+            writeln!(out, "{}", annotated.trim_end()).unwrap();
+        }
+        out
+    }
+
+    /// Returns an lcov tracefile for the listing, which is assumed to be saved at
+    /// `listing_path`. Each instruction is a line and each jump has a taken and a not-taken
+    /// branch.
+    pub fn lcov(&self, listing_path: &str) -> String {
+        let mut out = format!("TN:\nSF:{}\n", listing_path);
+        let (mut lines_found, mut lines_hit, mut branches_found, mut branches_hit) = (0, 0, 0, 0);
+        // Line 1 of the listing is the summary.
+        for (i, line) in self.lines().iter().enumerate() {
+            let line_no = i + 2;
+            let hits = match line.hits {
+                Some(hits) => hits,
+                None => continue,
+            };
+            if let Some(directions) = line.branch {
+                for (branch, n) in directions.iter().enumerate() {
+                    // lcov marks the branches of a line that never ran with '-'.
+                    let taken = if hits == 0 {
+                        "-".to_string()
+                    } else {
+                        n.to_string()
+                    };
+                    writeln!(out, "BRDA:{},0,{},{}", line_no, branch, taken).unwrap();
+                    branches_found += 1;
+                    branches_hit += (*n > 0) as usize;
+                }
+            }
+            writeln!(out, "DA:{},{}", line_no, hits).unwrap();
+            lines_found += 1;
+            lines_hit += (hits > 0) as usize;
+        }
+        writeln!(
+            out,
+            "BRF:{}\nBRH:{}\nLF:{}\nLH:{}\nend_of_record",
+            branches_found, branches_hit, lines_found, lines_hit
+        )
+        .unwrap();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::asm::assemble;
+    use crate::int_code::coverage::{Coverage, Summary};
+    use crate::int_code::RunOutcome;
+    use crate::util;
+
+    /// Outputs 1 if the input is 8 and 0 otherwise.
+    const EQUALS_8: &str = "
+        in  [x]
+        eq  [x], 8, [x]
+        jt  [x], yes
+        out 0
+        hlt
+    yes:
+        out 1
+        hlt
+    x:  .data 0
+    ";
+
+    #[test]
+    fn test_branch_directions() {
+        let program = assemble(EQUALS_8).unwrap();
+        let mut coverage = Coverage::new(program.clone());
+        assert_eq!(coverage.run(&[8], 100), Ok(RunOutcome::Halted));
+        assert_eq!(coverage.branch(6), Some((1, 0)));
+        assert_eq!(coverage.hits(9), 0);
+        assert_eq!(
+            coverage.summary(),
+            Summary {
+                runs: 1,
+                instructions: 7,
+                instructions_hit: 5,
+                branches: 2,
+                branches_hit: 1,
+            }
+        );
+
+        coverage.run(&[3], 100).unwrap();
+        let summary = coverage.summary();
+        assert_eq!(summary.instructions_hit, 7);
+        assert_eq!(summary.branches_hit, 2);
+
+        // The listing assembles back into the program.
+        assert_eq!(assemble(&coverage.listing()).unwrap(), program);
+    }
+
+    #[test]
+    fn test_listing_and_lcov() {
+        let mut coverage = Coverage::new(assemble(EQUALS_8).unwrap());
+        coverage.run(&[8], 100).unwrap();
+        let listing = coverage.listing();
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(
+            lines[0],
+            "# 1 runs: 5/7 instructions, 1/2 branch directions"
+        );
+        assert_eq!(
+            lines[3].trim_end(),
+            format!("{:<40} #     6 1 hits, taken 1, not taken 0", "jt [15], 12")
+        );
+        assert!(lines[4].ends_with("never run"));
+        assert!(lines[8].starts_with(".data 0"));
+
+        let lcov = coverage.lcov("equals8.asm");
+        assert!(lcov.starts_with("TN:\nSF:equals8.asm\n"));
+        assert!(lcov.contains("BRDA:4,0,0,1\nBRDA:4,0,1,0\nDA:4,1\n"));
+        assert!(lcov.contains("DA:5,0\n"));
+        assert!(lcov.ends_with("BRF:2\nBRH:1\nLF:7\nLH:5\nend_of_record\n"));
+    }
+
+    #[test]
+    fn test_day05_inputs_aggregate() {
+        let program = util::read_int_code_memory("./input/day05.txt");
+        let mut per_input = vec![];
+        for input in &[1, 5] {
+            let mut coverage = Coverage::new(program.clone());
+            coverage.run(&[*input], 1_000_000).unwrap();
+            per_input.push(coverage.summary());
+        }
+        let mut coverage = Coverage::new(program);
+        coverage.run(&[1], 1_000_000).unwrap();
+        coverage.run(&[5], 1_000_000).unwrap();
+        let both = coverage.summary();
+        assert_eq!(both.runs, 2);
+        for summary in per_input.iter() {
+            assert!(both.instructions_hit > summary.instructions_hit);
+        }
+        // Input 1 only runs the tests of part 1, which don't jump, so every branch is input 5's.
+        assert_eq!(per_input[0].branches_hit, 0);
+        assert!(both.branches_hit > 0);
+        assert_eq!(both.branches_hit, per_input[1].branches_hit);
+    }
+}
@@ -0,0 +1,435 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::int_code::{IntCodeComputer, RunOutcome};
+
+/// Values that tend to find bugs: boundaries of the opcode and mode encodings, the limits of i64
+/// and addresses just inside and outside of memory.
+const INTERESTING_VALUES: [i64; 16] = [
+    0,
+    1,
+    -1,
+    2,
+    3,
+    99,
+    100,
+    203,
+    22201,
+    30001,
+    9999,
+    10000,
+    1 << 20,
+    (1 << 20) + 1,
+    i64::MAX,
+    i64::MIN,
+];
+
+/// Rng is a xorshift64* generator. It is small, fast and reproducible from its seed, which is all
+/// a fuzzer needs.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // A zero state would only ever produce zeros, so the one seed that would give it starts
+        // from another state instead.
+        let state = match seed ^ 0x9E37_79B9_7F4A_7C15 {
+            0 => 0x2545_F491_4F6C_DD1D,
+            state => state,
+        };
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a value in 0..n.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Returns a value in lo..=hi.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo + 1) as u64) as i64
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// Case is one program together with the input it is run with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+    pub program: Vec<i64>,
+    pub input: Vec<i64>,
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |v: &[i64]| {
+            v.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        };
+        write!(
+            f,
+            "program: {}\ninput:   {}",
+            join(&self.program),
+            join(&self.input)
+        )
+    }
+}
+
+/// Outcome classifies how a case ended.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    Halted,
+    WaitingForInput,
+    BudgetExhausted,
+    /// The computer reported an error of the given kind.
+    Error(&'static str),
+    /// The computer panicked. Every panic is a bug in the computer.
+    Panic(String),
+}
+
+impl Outcome {
+    pub fn is_crash(&self) -> bool {
+        matches!(self, Outcome::Panic(_))
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Halted => write!(f, "halt"),
+            Outcome::WaitingForInput => write!(f, "input-wait"),
+            Outcome::BudgetExhausted => write!(f, "budget exhausted"),
+            Outcome::Error(kind) => write!(f, "error: {}", kind),
+            Outcome::Panic(msg) => write!(f, "PANIC: {}", msg),
+        }
+    }
+}
+
+/// Runs a case for at most `budget` instructions and classifies what happened. Panics are caught,
+/// so the caller sees them as Outcome::Panic instead of unwinding.
+pub fn run_case(case: &Case, budget: u64) -> Outcome {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut computer = IntCodeComputer::new(case.program.clone());
+        for v in case.input.iter() {
+            computer.input.write(*v);
+        }
+        computer.run_with_budget(budget)
+    }));
+    match result {
+        Ok(Ok(RunOutcome::Halted)) => Outcome::Halted,
+        Ok(Ok(RunOutcome::WaitingForInput)) => Outcome::WaitingForInput,
+        Ok(Ok(RunOutcome::BudgetExhausted)) => Outcome::BudgetExhausted,
+        Ok(Err(e)) => Outcome::Error(e.kind.name()),
+        Err(payload) => Outcome::Panic(panic_message(payload.as_ref())),
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+    "unknown panic".to_string()
+}
+
+fn random_value(rng: &mut Rng, len: usize) -> i64 {
+    match rng.below(4) {
+        0 => *rng.pick(&INTERESTING_VALUES),
+        1 => rng.range(0, len as i64 + 2),
+        2 => rng.range(-10, 100),
+        _ => rng.next_u64() as i64,
+    }
+}
+
+fn random_instruction(rng: &mut Rng) -> i64 {
+    let op_code = *rng.pick(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 99]);
+    let mut modes = 0;
+    for digit in &[100, 1000, 10000] {
+        // Mostly valid modes, sometimes an invalid one.
+        let mode = if rng.chance(90) {
+            rng.range(0, 2)
+        } else {
+            rng.range(3, 9)
+        };
+        modes += mode * digit;
+    }
+    modes + op_code
+}
+
+/// Generates a random program: a mix of plausible instructions and arbitrary operands.
+pub fn random_program(rng: &mut Rng, max_len: usize) -> Vec<i64> {
+    let len = 1 + rng.below(max_len as u64) as usize;
+    let mut program = Vec::with_capacity(len);
+    while program.len() < len {
+        program.push(random_instruction(rng));
+        for _ in 0..rng.below(4) {
+            program.push(random_value(rng, len));
+        }
+    }
+    program.truncate(len);
+    program
+}
+
+pub fn random_input(rng: &mut Rng, max_len: usize) -> Vec<i64> {
+    let len = rng.below(max_len as u64 + 1) as usize;
+    (0..len).map(|_| random_value(rng, 10)).collect()
+}
+
+/// Returns a copy of the program with a few random edits: replaced, inserted or deleted cells and
+/// tweaked modes.
+pub fn mutate(rng: &mut Rng, program: &[i64]) -> Vec<i64> {
+    let mut out = program.to_vec();
+    for _ in 0..1 + rng.below(4) {
+        if out.is_empty() {
+            out.push(random_instruction(rng));
+            continue;
+        }
+        let i = rng.below(out.len() as u64) as usize;
+        match rng.below(5) {
+            0 => out[i] = random_value(rng, program.len()),
+            1 => out[i] = random_instruction(rng),
+            2 => out.insert(i, random_value(rng, program.len())),
+            3 => {
+                out.remove(i);
+            }
+            _ => out[i] = out[i].wrapping_add(rng.range(-2, 2) * *rng.pick(&[1, 100, 1000])),
+        }
+    }
+    out
+}
+
+fn field(case: &mut Case, which: usize) -> &mut Vec<i64> {
+    if which == 0 {
+        &mut case.program
+    } else {
+        &mut case.input
+    }
+}
+
+/// Shrinks a case while `keep` still holds: removes chunks of the program and input, then moves
+/// each remaining value towards zero. The result is usually small enough to read.
+pub fn minimize<F: Fn(&Case) -> bool>(case: &Case, keep: F) -> Case {
+    let mut best = case.clone();
+    let mut progress = true;
+    while progress {
+        progress = false;
+        for which in 0..2 {
+            let mut chunk = (field(&mut best, which).len() / 2).max(1);
+            loop {
+                let mut start = 0;
+                while start < field(&mut best, which).len() {
+                    let mut candidate = best.clone();
+                    let values = field(&mut candidate, which);
+                    let end = (start + chunk).min(values.len());
+                    values.drain(start..end);
+                    if keep(&candidate) {
+                        best = candidate;
+                        progress = true;
+                    } else {
+                        start += chunk;
+                    }
+                }
+                if chunk == 1 {
+                    break;
+                }
+                chunk /= 2;
+            }
+            for i in 0..field(&mut best, which).len() {
+                for simpler in simpler_values(field(&mut best, which)[i]) {
+                    let mut candidate = best.clone();
+                    field(&mut candidate, which)[i] = simpler;
+                    if keep(&candidate) {
+                        best = candidate;
+                        progress = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Returns values closer to zero than v, simplest first.
+fn simpler_values(v: i64) -> Vec<i64> {
+    let mut out = vec![];
+    for c in &[0, 1, v / 2, v - v.signum()] {
+        if c.unsigned_abs() < v.unsigned_abs() && !out.contains(c) {
+            out.push(*c);
+        }
+    }
+    out
+}
+
+/// Config controls a fuzzing session.
+pub struct Config {
+    pub seed: u64,
+    pub iterations: usize,
+    /// Instructions each case may run before it counts as BudgetExhausted.
+    pub budget: u64,
+    pub max_program_len: usize,
+    pub max_input_len: usize,
+    /// Programs to mutate. When empty, every case is generated from scratch.
+    pub seeds: Vec<Vec<i64>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            iterations: 1000,
+            budget: 10_000,
+            max_program_len: 32,
+            max_input_len: 4,
+            seeds: vec![],
+        }
+    }
+}
+
+/// Report summarizes a fuzzing session.
+#[derive(Default)]
+pub struct Report {
+    /// How many cases ended with each outcome. Panics are counted under their message.
+    pub outcomes: BTreeMap<Outcome, usize>,
+    /// One minimized case for every distinct panic message.
+    pub crashes: Vec<(Case, String)>,
+}
+
+/// Runs a fuzzing session. Half of the cases mutate one of the seeds, if there are any.
+pub fn fuzz(config: &Config) -> Report {
+    let mut rng = Rng::new(config.seed);
+    let mut report = Report::default();
+    for _ in 0..config.iterations {
+        let program = if !config.seeds.is_empty() && rng.chance(50) {
+            {
+                let seed = rng.below(config.seeds.len() as u64) as usize;
+                mutate(&mut rng, &config.seeds[seed])
+            }
+        } else {
+            random_program(&mut rng, config.max_program_len)
+        };
+        let case = Case {
+            program,
+            input: random_input(&mut rng, config.max_input_len),
+        };
+        let outcome = run_case(&case, config.budget);
+        if let Outcome::Panic(msg) = &outcome {
+            if !report.crashes.iter().any(|(_, m)| m == msg) {
+                let minimized = minimize(
+                    &case,
+                    |c| matches!(run_case(c, config.budget), Outcome::Panic(m) if &m == msg),
+                );
+                report.crashes.push((minimized, msg.clone()));
+            }
+        }
+        *report.outcomes.entry(outcome).or_insert(0) += 1;
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::fuzz::{fuzz, minimize, run_case, Case, Config, Outcome, Rng};
+    use crate::util;
+
+    fn case(program: Vec<i64>, input: Vec<i64>) -> Case {
+        Case { program, input }
+    }
+
+    #[test]
+    fn test_classification() {
+        assert_eq!(run_case(&case(vec![99], vec![]), 10), Outcome::Halted);
+        assert_eq!(
+            run_case(&case(vec![3, 0, 99], vec![]), 10),
+            Outcome::WaitingForInput
+        );
+        assert_eq!(
+            run_case(&case(vec![1105, 1, 0], vec![]), 10),
+            Outcome::BudgetExhausted
+        );
+        assert_eq!(
+            run_case(&case(vec![42], vec![]), 10),
+            Outcome::Error("invalid opcode")
+        );
+    }
+
+    #[test]
+    fn test_former_panics_are_errors() {
+        // Each of these used to panic inside Memory or ParamMode::parse.
+        let cases = vec![
+            (vec![1, 0, 0, 20000, 99], Outcome::Halted),
+            (vec![4, 20000, 99], Outcome::Halted),
+            (vec![1, 0, 0, -1, 99], Outcome::Error("negative address")),
+            (
+                vec![1, 0, 0, 1 << 30, 99],
+                Outcome::Error("address out of range"),
+            ),
+            (vec![301, 0, 0, 0, 99], Outcome::Error("invalid param mode")),
+            (
+                vec![1002, 5, i64::MAX, 0, 99, 2],
+                Outcome::Error("overflow"),
+            ),
+            (vec![109, i64::MAX, 109, 1, 99], Outcome::Error("overflow")),
+        ];
+        for (program, expected) in cases {
+            assert_eq!(
+                run_case(&case(program.clone(), vec![]), 100),
+                expected,
+                "{:?}",
+                program
+            );
+        }
+        // The output buffer used to hold only 1000 values.
+        let loop_output = case(vec![104, 1, 1105, 1, 0], vec![]);
+        assert_eq!(run_case(&loop_output, 5000), Outcome::BudgetExhausted);
+    }
+
+    #[test]
+    fn test_minimize() {
+        let original = case(vec![5, 1, 2, 3, 7, 8, 9, 10], vec![4, 5, 6]);
+        // Keep any case whose program contains a value of at least 7.
+        let minimized = minimize(&original, |c| c.program.iter().any(|v| *v >= 7));
+        assert_eq!(minimized, case(vec![7], vec![]));
+    }
+
+    #[test]
+    fn test_rng_zero_state() {
+        let mut rng = Rng::new(0x9E37_79B9_7F4A_7C15);
+        assert!((0..4).map(|_| rng.next_u64()).any(|v| v != 0));
+    }
+
+    #[test]
+    fn test_fuzz_finds_no_panics() {
+        let config = Config {
+            seed: 2019,
+            iterations: 2000,
+            seeds: vec![
+                util::read_int_code_memory("./input/day05.txt"),
+                util::read_int_code_memory("./input/day09.txt"),
+            ],
+            ..Config::default()
+        };
+        let report = fuzz(&config);
+        assert!(report.crashes.is_empty(), "{:?}", report.crashes);
+        assert_eq!(report.outcomes.values().sum::<usize>(), 2000);
+    }
+}
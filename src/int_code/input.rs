@@ -0,0 +1,334 @@
+//! Where an IntCodeComputer's input comes from.
+//!
+//! The computer asks its InputSource for a value each time the program reads one. A DataStream,
+//! the default, only has what the caller wrote into it before running the program, but other
+//! sources can work the value out when it is asked for: from a closure, a file or stdin, or the
+//! output of another computer. That lets a program that plays a game be answered from inside the
+//! read, without stopping the computer to feed it.
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, StdinLock};
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::int_code::loader;
+use crate::int_code::{Cell, DataStream, ErrorKind, IntCodeComputer, Observer};
+
+pub trait InputSource<T: Cell = i64> {
+    /// Returns the next value. None means there isn't one yet, so the computer waits and asks
+    /// again the next time it runs. An error means there never will be, and crashes the program.
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind>;
+
+    /// Called when the computer is reset. Sources holding values for the old run drop them.
+    fn reset(&mut self) {}
+}
+
+impl<T: Cell> InputSource<T> for DataStream<T> {
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        DataStream::try_read(self)
+    }
+
+    fn reset(&mut self) {
+        DataStream::reset(self)
+    }
+}
+
+/// A queue of values, which the caller can add to between runs.
+impl<T: Cell> InputSource<T> for VecDeque<T> {
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        Ok(self.pop_front())
+    }
+
+    fn reset(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: Cell, S: InputSource<T> + ?Sized> InputSource<T> for Box<S> {
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        (**self).try_read()
+    }
+
+    fn reset(&mut self) {
+        (**self).reset()
+    }
+}
+
+/// A pair reads from the first source, and from the second when the first has nothing. A phase
+/// setting followed by another amplifier's output is `(VecDeque::from(vec![phase]), other)`. An
+/// error from the first source is passed on, without reading the second.
+impl<T: Cell, A: InputSource<T>, B: InputSource<T>> InputSource<T> for (A, B) {
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        match self.0.try_read()? {
+            Some(v) => Ok(Some(v)),
+            None => self.1.try_read(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+        self.1.reset();
+    }
+}
+
+/// FromFn calls a closure for each value. See from_fn.
+pub struct FromFn<F>(F);
+
+/// Returns a source that calls `f` whenever the program reads a value. `f` returns None if it
+/// doesn't have one yet.
+pub fn from_fn<T: Cell, F: FnMut() -> Option<T>>(f: F) -> FromFn<F> {
+    FromFn(f)
+}
+
+impl<T: Cell, F: FnMut() -> Option<T>> InputSource<T> for FromFn<F> {
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        Ok((self.0)())
+    }
+}
+
+/// Reader reads values from text, a line at a time as the program needs them. Lines hold values
+/// separated by commas and/or whitespace, the same as a program file, and are parsed as the cell
+/// type, so they can be as large as it allows. The end of the text ends the input, and text that
+/// isn't a value is an InvalidInput error.
+pub struct Reader<R, T = i64> {
+    reader: R,
+    pending: VecDeque<T>,
+}
+
+impl<R: BufRead, T> Reader<R, T> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<T: Cell, R: BufRead> InputSource<T> for Reader<R, T> {
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        while self.pending.is_empty() {
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return Err(ErrorKind::ReadFromClosedStream),
+                Ok(_) => (),
+                Err(_) => return Err(ErrorKind::InvalidInput),
+            }
+            // Blank lines and comments have no values, which parse_values doesn't allow.
+            if line.split('#').next().unwrap().trim().is_empty() {
+                continue;
+            }
+            let values = loader::parse_values(&line).map_err(|_| ErrorKind::InvalidInput)?;
+            self.pending.extend(values);
+        }
+        Ok(self.pending.pop_front())
+    }
+}
+
+/// Returns a source that reads values from stdin, waiting for each line the program needs.
+pub fn stdin<T>() -> Reader<StdinLock<'static>, T> {
+    Reader::new(io::stdin().lock())
+}
+
+/// Returns a source that reads values from the file at `path`.
+pub fn file<T>(path: impl AsRef<Path>) -> io::Result<Reader<BufReader<File>, T>> {
+    Ok(Reader::new(BufReader::new(File::open(path)?)))
+}
+
+/// OutputOf reads the values another computer has output, which it shares with the caller so
+/// that the caller can keep running it. Reading from a computer that has halted with nothing
+/// left to read ends the input.
+///
+/// The other computer is borrowed for each read, so it can't be one that is running, such as the
+/// computer reading: reading from a computer that is already borrowed fails with InvalidInput.
+pub struct OutputOf<C>(pub Rc<RefCell<C>>);
+
+impl<T: Cell, O: Observer<T>, I: InputSource<T>> InputSource<T>
+    for OutputOf<IntCodeComputer<T, O, I>>
+{
+    fn try_read(&mut self) -> Result<Option<T>, ErrorKind> {
+        let mut other = self
+            .0
+            .try_borrow_mut()
+            .map_err(|_| ErrorKind::InvalidInput)?;
+        match other.output.try_read()? {
+            None if other.is_halted() => Err(ErrorKind::ReadFromClosedStream),
+            v => Ok(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    use crate::int_code::asm::assemble;
+    use crate::int_code::input::{file, from_fn, InputSource, OutputOf, Reader};
+    use crate::int_code::{BigInt, ErrorKind, IntCodeComputer, Observer, RunOutcome};
+    use crate::util;
+
+    /// Doubles each input until it reads a zero.
+    const DOUBLER: &str = "
+        loop: in  [x]
+              jf  [x], done
+              mul [x], 2, [x]
+              out [x]
+              jt  1, loop
+        done: hlt
+        x:    .data 0
+    ";
+
+    /// Keeps the last output where a closure can see it.
+    struct LastOutput(Rc<RefCell<i64>>);
+
+    impl Observer for LastOutput {
+        fn output_produced(&mut self, value: &i64) {
+            *self.0.borrow_mut() = *value;
+        }
+    }
+
+    #[test]
+    fn test_closure_answers_from_previous_output() {
+        let last = Rc::new(RefCell::new(0));
+        let seen = last.clone();
+        let mut computer = IntCodeComputer::new(assemble(DOUBLER).unwrap())
+            .with_observer(LastOutput(last))
+            .with_input(from_fn(move || {
+                let last = *seen.borrow();
+                Some(if last > 100 { 0 } else { last + 1 })
+            }));
+        // The whole conversation happens in one run.
+        assert_eq!(computer.run_with_budget(1000), Ok(RunOutcome::Halted));
+        assert_eq!(
+            computer.dump_output().read_all(),
+            vec![2, 6, 14, 30, 62, 126]
+        );
+    }
+
+    #[test]
+    fn test_queue() {
+        let mut computer =
+            IntCodeComputer::new(assemble(DOUBLER).unwrap()).with_input(VecDeque::from(vec![3]));
+        assert_eq!(
+            computer.run_with_budget(100),
+            Ok(RunOutcome::WaitingForInput)
+        );
+        computer.input.extend(vec![4, 0]);
+        assert_eq!(computer.run_with_budget(100), Ok(RunOutcome::Halted));
+        assert_eq!(computer.dump_output().read_all(), vec![6, 8]);
+    }
+
+    #[test]
+    fn test_reader() {
+        let text: &[u8] = b"1, 2\n\n# a comment\n3 0\n";
+        let mut computer =
+            IntCodeComputer::new(assemble(DOUBLER).unwrap()).with_input(Reader::new(text));
+        computer.run();
+        assert_eq!(computer.dump_output().read_all(), vec![2, 4, 6]);
+
+        let read = |text: &'static [u8]| {
+            let mut computer =
+                IntCodeComputer::new(assemble(DOUBLER).unwrap()).with_input(Reader::new(text));
+            computer.run_with_budget(100).map_err(|e| e.kind)
+        };
+        assert_eq!(read(b"1\nx\n"), Err(ErrorKind::InvalidInput));
+        assert_eq!(read(b"1 2"), Err(ErrorKind::ReadFromClosedStream));
+
+        let mut day02 = file("./input/day02.txt").unwrap();
+        let first: Vec<Option<i64>> = (0..4).map(|_| day02.try_read().unwrap()).collect();
+        assert_eq!(first, vec![Some(1), Some(0), Some(0), Some(3)]);
+
+        // Values are parsed as the cell type, so they can be wider than an i64.
+        let echo: Vec<BigInt> = [3, 0, 4, 0, 99].iter().map(|v| BigInt::from(*v)).collect();
+        let text: &[u8] = b"123456789012345678901234567890\n";
+        let mut computer =
+            IntCodeComputer::<BigInt>::with_cells(echo).with_input(Reader::new(text));
+        computer.run();
+        assert_eq!(
+            computer.dump_output().read_all(),
+            vec!["123456789012345678901234567890".parse::<BigInt>().unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_pair_passes_on_errors() {
+        let mut pair = (VecDeque::from(vec![1i64]), VecDeque::from(vec![2]));
+        assert_eq!(pair.try_read(), Ok(Some(1)));
+        assert_eq!(pair.try_read(), Ok(Some(2)));
+        assert_eq!(pair.try_read(), Ok(None));
+
+        let text: &[u8] = b"x\n";
+        let mut pair = (Reader::new(text), VecDeque::from(vec![2i64]));
+        assert_eq!(pair.try_read(), Err(ErrorKind::InvalidInput));
+    }
+
+    type Amp = IntCodeComputer<i64, (), Box<dyn InputSource>>;
+
+    /// Runs the day 7 amplifiers in a feedback loop, each reading from the one before it.
+    fn feedback_loop(program: &[i64], phases: &[i64]) -> i64 {
+        let amps: Vec<Rc<RefCell<Amp>>> = phases
+            .iter()
+            .map(|_| {
+                let input: Box<dyn InputSource> = Box::new(VecDeque::new());
+                let amp = IntCodeComputer::new(program.to_vec()).with_input(input);
+                Rc::new(RefCell::new(amp))
+            })
+            .collect();
+        for (i, amp) in amps.iter().enumerate() {
+            let mut first = VecDeque::from(vec![phases[i]]);
+            if i == 0 {
+                first.push_back(0);
+            }
+            let previous = OutputOf(amps[(i + amps.len() - 1) % amps.len()].clone());
+            amp.borrow_mut().input = Box::new((first, previous));
+        }
+        let last = amps.last().unwrap();
+        while !last.borrow().is_halted() {
+            for amp in amps.iter() {
+                amp.borrow_mut().run();
+            }
+        }
+        let output = *last.borrow().dump_output().read_all().last().unwrap();
+        // The amplifiers refer to each other in a cycle, which has to be broken to free them.
+        for amp in amps.iter() {
+            amp.borrow_mut().input = Box::new(VecDeque::new());
+        }
+        output
+    }
+
+    #[test]
+    fn test_amplifier_feedback_loop() {
+        let program = util::read_int_code_memory("./input/day07.txt");
+        let mut best = 0;
+        for n in 0..5i64.pow(5) {
+            let phases: Vec<i64> = (0..5).map(|i| 5 + n / 5i64.pow(i) % 5).collect();
+            if (5..10).all(|p| phases.contains(&p)) {
+                best = best.max(feedback_loop(&program, &phases));
+            }
+        }
+        assert_eq!(best, 17519904);
+    }
+
+    #[test]
+    fn test_output_of_halted_computer() {
+        let other = Rc::new(RefCell::new(IntCodeComputer::new(vec![104, 7, 99])));
+        other.borrow_mut().run();
+        let mut source = OutputOf(other);
+        assert_eq!(source.try_read(), Ok(Some(7)));
+        assert_eq!(source.try_read(), Err(ErrorKind::ReadFromClosedStream));
+    }
+
+    #[test]
+    fn test_output_of_itself() {
+        let input: Box<dyn InputSource> = Box::new(VecDeque::new());
+        let computer: Amp = IntCodeComputer::new(vec![104, 7, 3, 0, 99]).with_input(input);
+        let computer = Rc::new(RefCell::new(computer));
+        computer.borrow_mut().input = Box::new(OutputOf(computer.clone()));
+        let result = computer.borrow_mut().run_with_budget(100);
+        assert_eq!(result.unwrap_err().kind, ErrorKind::InvalidInput);
+        // The cycle has to be broken to free the computer.
+        computer.borrow_mut().input = Box::new(VecDeque::new());
+    }
+}
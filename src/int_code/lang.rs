@@ -0,0 +1,999 @@
+//! A small language that compiles to Intcode.
+//!
+//! ```text
+//! # Prints the Fibonacci number of each input until it reads a zero.
+//! fn fib(n) {
+//!     if n < 2 {
+//!         return n;
+//!     }
+//!     return fib(n - 1) + fib(n - 2);
+//! }
+//!
+//! let n = input();
+//! while n != 0 {
+//!     output(fib(n));
+//!     n = input();
+//! }
+//! ```
+//!
+//! A program is a list of functions and statements. The statements run from top to bottom and the
+//! program halts after the last one. Every value is an i64 and conditions are true when they
+//! aren't 0.
+//!
+//! * `let x = e;` declares a variable and `x = e;` assigns to it. Variables declared outside of
+//!   functions and blocks are globals, which every function can use; the rest live until the end
+//!   of their block.
+//! * `if e { ... } else if e { ... } else { ... }`, `while e { ... }`, `break;` and `continue;`.
+//! * `fn name(a, b) { ... }` defines a function, which `return e;` or `return;` leaves. A function
+//!   that doesn't return a value returns 0. Functions can be called before they are defined, and
+//!   can call themselves.
+//! * `input()` reads a value and `output(e)` writes one.
+//! * Operators, from the loosest binding: `||`, `&&`, comparisons (`==`, `!=`, `<`, `<=`, `>`,
+//!   `>=`), `+` and `-`, `*`, and the unary `-` and `!`. Comparisons and `!` give 0 or 1, and `&&`
+//!   and `||` only evaluate their right side if they need to.
+//!
+//! The relative base is the stack pointer. Each call gets a frame above its caller's: the return
+//! address at `[rb+0]`, then the arguments, the local variables, and the temporaries that
+//! expressions need. The caller fills in the frame, moves the relative base up to it and jumps;
+//! the callee leaves its result in a global cell and jumps back, and the caller moves the
+//! relative base back down.
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::int_code::asm;
+use crate::int_code::loader::ParseError;
+
+const KEYWORDS: [&str; 8] = [
+    "fn", "let", "if", "else", "while", "return", "break", "continue",
+];
+
+/// Operators, longest first so that `<=` isn't read as `<`.
+const PUNCTUATION: [&str; 19] = [
+    "&&", "||", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "!", "=", "(", ")", "{", "}", ",",
+    ";",
+];
+
+/// The line and column of a token, both counting from 1.
+type Pos = (usize, usize);
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Num(i64),
+    Ident(String),
+    Punct(&'static str),
+    Eof,
+}
+
+fn error(pos: Pos, message: String) -> ParseError {
+    ParseError::new(pos.0, pos.1, message)
+}
+
+fn tokenize(src: &str) -> Result<Vec<(Token, Pos)>, ParseError> {
+    let mut tokens = vec![];
+    let mut pos = (1, 1);
+    for (i, line) in src.lines().enumerate() {
+        let code = match line.find('#') {
+            Some(end) => &line[..end],
+            None => line,
+        };
+        let mut rest = code;
+        // The column of the start of rest, counted as it moves on. Tokens are ASCII, so only the
+        // whitespace between them needs its characters counted.
+        let mut column = 1;
+        loop {
+            let trimmed = rest.trim_start();
+            column += rest[..rest.len() - trimmed.len()].chars().count();
+            rest = trimmed;
+            if rest.is_empty() {
+                break;
+            }
+            pos = (i + 1, column);
+            let c = rest.chars().next().unwrap();
+            if c.is_ascii_alphanumeric() || c == '_' {
+                let end = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .unwrap_or(rest.len());
+                let word = &rest[..end];
+                let token = if c.is_ascii_digit() {
+                    Token::Num(
+                        word.parse()
+                            .map_err(|_| error(pos, format!("invalid number '{}'", word)))?,
+                    )
+                } else {
+                    Token::Ident(word.to_string())
+                };
+                tokens.push((token, pos));
+                rest = &rest[end..];
+                column += end;
+                continue;
+            }
+            match PUNCTUATION.iter().find(|p| rest.starts_with(*p)) {
+                Some(p) => {
+                    tokens.push((Token::Punct(p), pos));
+                    rest = &rest[p.len()..];
+                    column += p.len();
+                }
+                None => return Err(error(pos, format!("unexpected character '{}'", c))),
+            }
+        }
+        pos = (i + 1, line.chars().count() + 1);
+    }
+    tokens.push((Token::Eof, pos));
+    Ok(tokens)
+}
+
+#[derive(Debug)]
+enum Expr {
+    Num(i64),
+    Var(String, Pos),
+    Call(String, Vec<Expr>, Pos),
+    Neg(Box<Expr>),
+    Not(Box<Expr>),
+    Binary(&'static str, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Returns whether evaluating the expression can call a function, which may change globals.
+    fn has_call(&self) -> bool {
+        match self {
+            Expr::Num(_) | Expr::Var(..) => false,
+            Expr::Call(..) => true,
+            Expr::Neg(e) | Expr::Not(e) => e.has_call(),
+            Expr::Binary(_, a, b) => a.has_call() || b.has_call(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Stmt {
+    Let(String, Expr, Pos),
+    Assign(String, Expr, Pos),
+    If(Expr, Vec<Stmt>, Vec<Stmt>),
+    While(Expr, Vec<Stmt>),
+    Return(Option<Expr>, Pos),
+    Break(Pos),
+    Continue(Pos),
+    Expr(Expr),
+}
+
+struct Function {
+    name: String,
+    pos: Pos,
+    params: Vec<String>,
+    body: Vec<Stmt>,
+}
+
+struct Parser {
+    tokens: Vec<(Token, Pos)>,
+    next: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.next].0
+    }
+
+    fn pos(&self) -> Pos {
+        self.tokens[self.next].1
+    }
+
+    fn advance(&mut self) -> (Token, Pos) {
+        let token = self.tokens[self.next].clone();
+        if token.0 != Token::Eof {
+            self.next += 1;
+        }
+        token
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let found = match self.peek() {
+            Token::Num(n) => format!("'{}'", n),
+            Token::Ident(name) => format!("'{}'", name),
+            Token::Punct(p) => format!("'{}'", p),
+            Token::Eof => "the end of the program".to_string(),
+        };
+        error(
+            self.pos(),
+            format!("expected {} but found {}", expected, found),
+        )
+    }
+
+    /// Consumes the token if it is the given punctuation or keyword.
+    fn accept(&mut self, s: &str) -> bool {
+        let matches = match self.peek() {
+            Token::Punct(p) => *p == s,
+            Token::Ident(name) => name == s,
+            _ => false,
+        };
+        if matches {
+            self.advance();
+        }
+        matches
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), ParseError> {
+        if self.accept(s) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("'{}'", s)))
+        }
+    }
+
+    fn ident(&mut self) -> Result<(String, Pos), ParseError> {
+        match self.peek() {
+            Token::Ident(name) if !KEYWORDS.contains(&name.as_str()) => {
+                let name = name.clone();
+                let (_, pos) = self.advance();
+                Ok((name, pos))
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    fn program(&mut self) -> Result<(Vec<Function>, Vec<Stmt>), ParseError> {
+        let mut functions = vec![];
+        let mut main = vec![];
+        while *self.peek() != Token::Eof {
+            if self.accept("fn") {
+                functions.push(self.function()?);
+            } else {
+                main.push(self.statement()?);
+            }
+        }
+        Ok((functions, main))
+    }
+
+    fn function(&mut self) -> Result<Function, ParseError> {
+        let (name, pos) = self.ident()?;
+        self.expect("(")?;
+        let mut params = vec![];
+        if !self.accept(")") {
+            loop {
+                params.push(self.ident()?);
+                if self.accept(")") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+        let mut seen = HashSet::new();
+        for (param, pos) in params.iter() {
+            if !seen.insert(param) {
+                return Err(error(*pos, format!("parameter '{}' declared twice", param)));
+            }
+        }
+        Ok(Function {
+            name,
+            pos,
+            params: params.into_iter().map(|(name, _)| name).collect(),
+            body: self.block()?,
+        })
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>, ParseError> {
+        self.expect("{")?;
+        let mut body = vec![];
+        while !self.accept("}") {
+            if *self.peek() == Token::Eof {
+                return Err(self.unexpected("'}'"));
+            }
+            body.push(self.statement()?);
+        }
+        Ok(body)
+    }
+
+    fn statement(&mut self) -> Result<Stmt, ParseError> {
+        let pos = self.pos();
+        let stmt = if self.accept("let") {
+            let (name, pos) = self.ident()?;
+            self.expect("=")?;
+            Stmt::Let(name, self.expr()?, pos)
+        } else if self.accept("if") {
+            return self.if_statement();
+        } else if self.accept("while") {
+            let cond = self.expr()?;
+            return Ok(Stmt::While(cond, self.block()?));
+        } else if self.accept("return") {
+            if self.accept(";") {
+                return Ok(Stmt::Return(None, pos));
+            }
+            Stmt::Return(Some(self.expr()?), pos)
+        } else if self.accept("break") {
+            Stmt::Break(pos)
+        } else if self.accept("continue") {
+            Stmt::Continue(pos)
+        } else if matches!(self.tokens[self.next + 1].0, Token::Punct("=")) {
+            let (name, pos) = self.ident()?;
+            self.expect("=")?;
+            Stmt::Assign(name, self.expr()?, pos)
+        } else {
+            Stmt::Expr(self.expr()?)
+        };
+        self.expect(";")?;
+        Ok(stmt)
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt, ParseError> {
+        let cond = self.expr()?;
+        let then = self.block()?;
+        let otherwise = if !self.accept("else") {
+            vec![]
+        } else if self.accept("if") {
+            vec![self.if_statement()?]
+        } else {
+            self.block()?
+        };
+        Ok(Stmt::If(cond, then, otherwise))
+    }
+
+    fn expr(&mut self) -> Result<Expr, ParseError> {
+        self.binary(0)
+    }
+
+    /// Parses operators of the given precedence level and tighter ones.
+    fn binary(&mut self, level: usize) -> Result<Expr, ParseError> {
+        const LEVELS: [&[&str]; 5] = [
+            &["||"],
+            &["&&"],
+            &["==", "!=", "<", "<=", ">", ">="],
+            &["+", "-"],
+            &["*"],
+        ];
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+        let mut lhs = self.binary(level + 1)?;
+        loop {
+            let op = match self.peek() {
+                Token::Punct(p) if LEVELS[level].contains(p) => *p,
+                _ => return Ok(lhs),
+            };
+            self.advance();
+            let rhs = self.binary(level + 1)?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.accept("-") {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        if self.accept("!") {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        if self.accept("(") {
+            let e = self.expr()?;
+            self.expect(")")?;
+            return Ok(e);
+        }
+        if let Token::Num(n) = self.peek() {
+            let n = *n;
+            self.advance();
+            return Ok(Expr::Num(n));
+        }
+        let (name, pos) = self.ident()?;
+        if !self.accept("(") {
+            return Ok(Expr::Var(name, pos));
+        }
+        let mut args = vec![];
+        if !self.accept(")") {
+            loop {
+                args.push(self.expr()?);
+                if self.accept(")") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+        Ok(Expr::Call(name, args, pos))
+    }
+}
+
+/// Returns the number of variables declared in the statements, including nested blocks.
+fn count_lets(stmts: &[Stmt]) -> i64 {
+    stmts
+        .iter()
+        .map(|stmt| match stmt {
+            Stmt::Let(..) => 1,
+            Stmt::If(_, then, otherwise) => count_lets(then) + count_lets(otherwise),
+            Stmt::While(_, body) => count_lets(body),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn is_immediate(operand: &str) -> bool {
+    !operand.starts_with('[')
+}
+
+/// Codegen turns the syntax tree into assembly.
+struct Codegen {
+    out: String,
+    labels: usize,
+    /// The number of parameters of each function.
+    functions: HashMap<String, usize>,
+    /// Globals that can be used: all of them inside functions, and those declared so far at the
+    /// top level.
+    globals: HashSet<String>,
+    /// The slot of each variable in the current frame, innermost block last.
+    scopes: Vec<HashMap<String, i64>>,
+    next_slot: i64,
+    /// The first slot for temporaries.
+    temps: i64,
+    /// The continue and break labels of the loops around the current statement.
+    loops: Vec<(String, String)>,
+    in_function: bool,
+}
+
+impl Codegen {
+    fn emit(&mut self, line: String) {
+        writeln!(self.out, "    {}", line).unwrap();
+    }
+
+    fn label(&mut self) -> String {
+        self.labels += 1;
+        format!("l.{}", self.labels)
+    }
+
+    fn place(&mut self, label: &str) {
+        writeln!(self.out, "{}:", label).unwrap();
+    }
+
+    fn temp(&self, depth: i64) -> String {
+        format!("[rb+{}]", self.temps + depth)
+    }
+
+    fn mov(&mut self, from: &str, to: &str) {
+        if from != to {
+            self.emit(format!("add {}, 0, {}", from, to));
+        }
+    }
+
+    /// Sets up a frame for a function with the given parameters and body.
+    fn enter(&mut self, params: &[String], body: &[Stmt], in_function: bool) {
+        let mut scope = HashMap::new();
+        for (i, param) in params.iter().enumerate() {
+            scope.insert(param.clone(), i as i64 + 1);
+        }
+        self.scopes = vec![scope];
+        self.next_slot = params.len() as i64 + 1;
+        self.temps = self.next_slot + count_lets(body);
+        self.in_function = in_function;
+    }
+
+    fn variable(&self, name: &str, pos: Pos) -> Result<String, ParseError> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(slot) = scope.get(name) {
+                return Ok(format!("[rb+{}]", slot));
+            }
+        }
+        if self.globals.contains(name) {
+            return Ok(format!("[var.{}]", name));
+        }
+        Err(error(pos, format!("undefined variable '{}'", name)))
+    }
+
+    fn block(&mut self, stmts: &[Stmt]) -> Result<(), ParseError> {
+        self.scopes.push(HashMap::new());
+        for stmt in stmts {
+            self.statement(stmt)?;
+        }
+        self.scopes.pop();
+        Ok(())
+    }
+
+    fn statement(&mut self, stmt: &Stmt) -> Result<(), ParseError> {
+        match stmt {
+            Stmt::Let(name, e, pos) => {
+                let value = self.expr(e, 0)?;
+                let scope = self.scopes.last_mut().unwrap();
+                if scope.contains_key(name) {
+                    return Err(error(*pos, format!("'{}' is already declared", name)));
+                }
+                scope.insert(name.clone(), self.next_slot);
+                let slot = format!("[rb+{}]", self.next_slot);
+                self.next_slot += 1;
+                self.mov(&value, &slot);
+            }
+            Stmt::Assign(name, e, pos) => {
+                let dest = self.variable(name, *pos)?;
+                let value = self.expr(e, 0)?;
+                self.mov(&value, &dest);
+            }
+            Stmt::If(cond, then, otherwise) => {
+                let (else_label, end) = (self.label(), self.label());
+                let c = self.expr(cond, 0)?;
+                self.emit(format!("jf {}, {}", c, else_label));
+                self.block(then)?;
+                self.emit(format!("jt 1, {}", end));
+                self.place(&else_label);
+                self.block(otherwise)?;
+                self.place(&end);
+            }
+            Stmt::While(cond, body) => {
+                let (top, end) = (self.label(), self.label());
+                self.place(&top);
+                let c = self.expr(cond, 0)?;
+                self.emit(format!("jf {}, {}", c, end));
+                self.loops.push((top.clone(), end.clone()));
+                self.block(body)?;
+                self.loops.pop();
+                self.emit(format!("jt 1, {}", top));
+                self.place(&end);
+            }
+            Stmt::Return(e, pos) => {
+                if !self.in_function {
+                    return Err(error(*pos, "return outside of a function".to_string()));
+                }
+                let value = match e {
+                    Some(e) => self.expr(e, 0)?,
+                    None => "0".to_string(),
+                };
+                self.mov(&value, "[ret.value]");
+                self.emit("jt 1, [rb+0]".to_string());
+            }
+            Stmt::Break(pos) | Stmt::Continue(pos) => {
+                let (top, end) = match self.loops.last() {
+                    Some(labels) => labels.clone(),
+                    None => return Err(error(*pos, "not inside a loop".to_string())),
+                };
+                let target = if matches!(stmt, Stmt::Break(_)) {
+                    end
+                } else {
+                    top
+                };
+                self.emit(format!("jt 1, {}", target));
+            }
+            Stmt::Expr(e) => {
+                self.expr(e, 0)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Emits code for the expression and returns an operand that holds its value. `depth` is the
+    /// first temporary the code may use; a result that needs a temporary goes in that one.
+    fn expr(&mut self, e: &Expr, depth: i64) -> Result<String, ParseError> {
+        let result = self.temp(depth);
+        match e {
+            Expr::Num(n) => Ok(n.to_string()),
+            Expr::Var(name, pos) => self.variable(name, *pos),
+            Expr::Neg(e) => match self.expr(e, depth)? {
+                n if is_immediate(&n) && n != i64::MIN.to_string() => {
+                    Ok((-n.parse::<i64>().unwrap()).to_string())
+                }
+                operand => {
+                    self.emit(format!("mul {}, -1, {}", operand, result));
+                    Ok(result)
+                }
+            },
+            Expr::Not(e) => {
+                let operand = self.expr(e, depth)?;
+                self.emit(format!("eq {}, 0, {}", operand, result));
+                Ok(result)
+            }
+            Expr::Binary(op @ ("&&" | "||"), a, b) => {
+                let (short, end) = (self.label(), self.label());
+                let a = self.expr(a, depth)?;
+                self.emit(format!("eq {}, 0, {}", a, result));
+                // result is now !a, which decides && when it is 1 and || when it is 0.
+                let jump = if *op == "&&" { "jt" } else { "jf" };
+                self.emit(format!("{} {}, {}", jump, result, short));
+                let b = self.expr(b, depth)?;
+                self.emit(format!("eq {}, 0, {}", b, result));
+                self.emit(format!("eq {}, 0, {}", result, result));
+                self.emit(format!("jt 1, {}", end));
+                self.place(&short);
+                let value = if *op == "&&" { 0 } else { 1 };
+                self.emit(format!("add {}, 0, {}", value, result));
+                self.place(&end);
+                Ok(result)
+            }
+            Expr::Binary(op, a, b) => {
+                let mut x = self.expr(a, depth)?;
+                // A call could change the global x refers to, so its value is copied first.
+                if b.has_call() && x.starts_with("[var.") {
+                    self.mov(&x, &result);
+                    x = result.clone();
+                }
+                let y = self.expr(b, depth + 1)?;
+                let (instruction, x, y, negate) = match *op {
+                    "+" => ("add", x, y, false),
+                    "*" => ("mul", x, y, false),
+                    "-" => {
+                        let y = match y.parse::<i64>() {
+                            Ok(n) if n != i64::MIN => (-n).to_string(),
+                            _ => {
+                                let t = self.temp(depth + 1);
+                                self.emit(format!("mul {}, -1, {}", y, t));
+                                t
+                            }
+                        };
+                        ("add", x, y, false)
+                    }
+                    "==" => ("eq", x, y, false),
+                    "!=" => ("eq", x, y, true),
+                    "<" => ("lt", x, y, false),
+                    ">" => ("lt", y, x, false),
+                    "<=" => ("lt", y, x, true),
+                    _ => ("lt", x, y, true),
+                };
+                self.emit(format!("{} {}, {}, {}", instruction, x, y, result));
+                if negate {
+                    self.emit(format!("eq {}, 0, {}", result, result));
+                }
+                Ok(result)
+            }
+            Expr::Call(name, args, pos) => self.call(name, args, *pos, depth),
+        }
+    }
+
+    fn call(
+        &mut self,
+        name: &str,
+        args: &[Expr],
+        pos: Pos,
+        depth: i64,
+    ) -> Result<String, ParseError> {
+        let result = self.temp(depth);
+        let arity = match name {
+            "input" => 0,
+            "output" => 1,
+            _ => match self.functions.get(name) {
+                Some(arity) => *arity,
+                None => return Err(error(pos, format!("undefined function '{}'", name))),
+            },
+        };
+        if args.len() != arity {
+            return Err(error(
+                pos,
+                format!(
+                    "{} takes {} arguments but {} were given",
+                    name,
+                    arity,
+                    args.len()
+                ),
+            ));
+        }
+        if name == "input" {
+            self.emit(format!("in {}", result));
+            return Ok(result);
+        }
+        if name == "output" {
+            let value = self.expr(&args[0], depth)?;
+            self.emit(format!("out {}", value));
+            return Ok("0".to_string());
+        }
+        // The callee's frame starts at the result's slot, with the arguments after the return
+        // address. Each argument is evaluated straight into its slot, using the slots above it.
+        for (i, arg) in args.iter().enumerate() {
+            let slot = self.temp(depth + 1 + i as i64);
+            let value = self.expr(arg, depth + 1 + i as i64)?;
+            self.mov(&value, &slot);
+        }
+        let back = self.label();
+        let frame = self.temps + depth;
+        self.emit(format!("add {}, 0, {}", back, result));
+        self.emit(format!("arb {}", frame));
+        self.emit(format!("jt 1, fn.{}", name));
+        self.place(&back);
+        self.emit(format!("arb {}", -frame));
+        self.mov("[ret.value]", &result);
+        Ok(result)
+    }
+}
+
+/// Compiles a program to assembly for the `asm` module, which is useful to see what the compiler
+/// did.
+pub fn compile_to_asm(src: &str) -> Result<String, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(src)?,
+        next: 0,
+    };
+    let (functions, main) = parser.program()?;
+    let mut gen = Codegen {
+        out: String::new(),
+        labels: 0,
+        functions: HashMap::new(),
+        globals: HashSet::new(),
+        scopes: vec![],
+        next_slot: 0,
+        temps: 0,
+        loops: vec![],
+        in_function: false,
+    };
+    for f in functions.iter() {
+        if ["input", "output"].contains(&f.name.as_str()) {
+            return Err(error(f.pos, format!("'{}' is a builtin", f.name)));
+        }
+        if gen
+            .functions
+            .insert(f.name.clone(), f.params.len())
+            .is_some()
+        {
+            return Err(error(f.pos, format!("function '{}' defined twice", f.name)));
+        }
+    }
+    let globals: HashSet<String> = main
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::Let(name, ..) => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+
+    // The top level runs in a frame of its own, with globals declared as it goes.
+    gen.place("main");
+    gen.emit("arb mem.stack".to_string());
+    gen.enter(&[], &main, false);
+    // Only the variables in nested blocks need slots in the frame.
+    gen.temps -= main
+        .iter()
+        .filter(|stmt| matches!(stmt, Stmt::Let(..)))
+        .count() as i64;
+    for stmt in main.iter() {
+        match stmt {
+            Stmt::Let(name, e, pos) => {
+                let value = gen.expr(e, 0)?;
+                if !gen.globals.insert(name.clone()) {
+                    return Err(error(*pos, format!("'{}' is already declared", name)));
+                }
+                gen.mov(&value, &format!("[var.{}]", name));
+            }
+            _ => gen.statement(stmt)?,
+        }
+    }
+    gen.emit("hlt".to_string());
+
+    gen.globals = globals;
+    for f in functions.iter() {
+        gen.place(&format!("fn.{}", f.name));
+        gen.enter(&f.params, &f.body, true);
+        gen.block(&f.body)?;
+        gen.emit("add 0, 0, [ret.value]".to_string());
+        gen.emit("jt 1, [rb+0]".to_string());
+    }
+
+    let mut names: Vec<&String> = gen.globals.iter().collect();
+    names.sort();
+    for name in names {
+        writeln!(gen.out, "var.{}: .data 0", name).unwrap();
+    }
+    gen.out += "ret.value: .data 0\n";
+    // The stack starts after the program, where memory is all zeros.
+    gen.out += "mem.stack:\n";
+    Ok(gen.out)
+}
+
+/// Compiles a program in the language described in the module docs.
+pub fn compile(src: &str) -> Result<Vec<i64>, ParseError> {
+    let asm = compile_to_asm(src)?;
+    Ok(asm::assemble(&asm).expect("the compiler generated invalid assembly"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::lang::{compile, compile_to_asm};
+    use crate::int_code::reference;
+    use crate::int_code::IntCodeComputer;
+
+    /// Compiles and runs a program, checking it against the reference interpreter too.
+    fn run(src: &str, input: &[i64]) -> Vec<i64> {
+        let program = match compile(src) {
+            Ok(program) => program,
+            Err(e) => panic!("{}\n{}", e, src),
+        };
+        let agreement = reference::compare(&program, input, 10_000_000).unwrap();
+        let mut computer = IntCodeComputer::new(program);
+        for v in input {
+            computer.input.write(*v);
+        }
+        computer.run();
+        assert!(computer.is_halted());
+        let output = computer.dump_output().read_all();
+        assert_eq!(output, agreement.output);
+        output
+    }
+
+    #[test]
+    fn test_expressions() {
+        let cases = vec![
+            ("1 + 2 * 3", 7),
+            ("(1 + 2) * 3", 9),
+            ("10 - 3 - 2", 5),
+            ("-4 * -5", 20),
+            ("-(2 - 7)", 5),
+            ("3 < 4", 1),
+            ("4 < 4", 0),
+            ("4 <= 4", 1),
+            ("5 > 4", 1),
+            ("4 >= 5", 0),
+            ("2 == 2", 1),
+            ("2 != 2", 0),
+            ("!0 + !7", 1),
+            ("1 < 2 == 1", 1),
+            ("0 || 3", 1),
+            ("2 && 3", 1),
+            ("2 && 0 || 0", 0),
+        ];
+        for (e, expected) in cases {
+            assert_eq!(
+                run(&format!("output({});", e), &[]),
+                vec![expected],
+                "{}",
+                e
+            );
+            // The same with the values coming from input, so nothing is constant.
+            let src = format!("let x = input();\noutput(({}) + x);", e);
+            assert_eq!(run(&src, &[10]), vec![expected + 10], "{}", e);
+        }
+    }
+
+    #[test]
+    fn test_control_flow() {
+        let src = "
+            # Sums the inputs until a zero, skipping negative ones, and stops early at 100.
+            let sum = 0;
+            while 1 {
+                let x = input();
+                if x == 0 {
+                    break;
+                } else if x < 0 {
+                    continue;
+                }
+                sum = sum + x;
+                if sum >= 100 {
+                    break;
+                }
+            }
+            output(sum);
+        ";
+        assert_eq!(run(src, &[5, -3, 7, 0]), vec![12]);
+        assert_eq!(run(src, &[60, 50, 1, 0]), vec![110]);
+    }
+
+    #[test]
+    fn test_functions() {
+        let src = "
+            fn fib(n) {
+                if n < 2 {
+                    return n;
+                }
+                return fib(n - 1) + fib(n - 2);
+            }
+
+            fn power(base, exp) {
+                let result = 1;
+                while exp > 0 {
+                    result = result * base;
+                    exp = exp - 1;
+                }
+                return result;
+            }
+
+            # Mutual recursion, with a function defined after its first call.
+            fn is_even(n) {
+                if n == 0 { return 1; }
+                return is_odd(n - 1);
+            }
+            fn is_odd(n) {
+                if n == 0 { return 0; }
+                return is_even(n - 1);
+            }
+
+            let n = input();
+            while n != 0 {
+                output(fib(n));
+                n = input();
+            }
+            output(power(3, 4) + power(2, power(2, 3)));
+            output(is_even(10) * 10 + is_odd(7));
+        ";
+        assert_eq!(run(src, &[1, 10, 20, 0]), vec![1, 55, 6765, 81 + 256, 11]);
+    }
+
+    #[test]
+    fn test_globals_and_evaluation_order() {
+        let src = "
+            let counter = 0;
+
+            fn next() {
+                counter = counter + 1;
+                return counter;
+            }
+
+            fn say(x) {
+                output(x);
+                return x;
+            }
+
+            # The left operand is read before the call on the right changes counter.
+            output(counter + next() * 10);
+            output(next() * 10 + counter);
+            # && and || don't evaluate their right side when the left decides.
+            let t = 0 && say(1);
+            t = 1 || say(2);
+            t = 1 && say(3);
+            output(counter);
+        ";
+        assert_eq!(run(src, &[]), vec![10, 22, 3, 2]);
+    }
+
+    #[test]
+    fn test_scopes() {
+        let src = "
+            let x = 1;
+            fn f(x) {
+                let y = x * 2;
+                if 1 {
+                    let x = 100;
+                    y = y + x;
+                }
+                return y + x;
+            }
+            if 1 {
+                let x = 7;
+                output(x);
+            }
+            output(x);
+            output(f(5));
+        ";
+        assert_eq!(run(src, &[]), vec![7, 1, 115]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let cases = vec![
+            ("output(y);", "1:8: undefined variable 'y'"),
+            ("f();", "1:1: undefined function 'f'"),
+            (
+                "fn f(a) {}\nf(1, 2);",
+                "2:1: f takes 1 arguments but 2 were given",
+            ),
+            ("let x = 1;\nlet x = 2;", "2:5: 'x' is already declared"),
+            ("fn f() {}\nfn f() {}", "2:4: function 'f' defined twice"),
+            ("fn f(a, a) {}", "1:9: parameter 'a' declared twice"),
+            ("fn input() {}", "1:4: 'input' is a builtin"),
+            ("break;", "1:1: not inside a loop"),
+            ("return 1;", "1:1: return outside of a function"),
+            (
+                "output(1)",
+                "1:10: expected ';' but found the end of the program",
+            ),
+            ("let = 1;", "1:5: expected a name but found '='"),
+            (
+                "if 1 { output(1);",
+                "1:18: expected '}' but found the end of the program",
+            ),
+            ("output(1 $ 2);", "1:10: unexpected character '$'"),
+            ("output(\u{a0}\u{a0}y);", "1:10: undefined variable 'y'"),
+            (
+                "output(99999999999999999999);",
+                "1:8: invalid number '99999999999999999999'",
+            ),
+            ("x = 1;", "1:1: undefined variable 'x'"),
+        ];
+        for (src, msg) in cases {
+            assert_eq!(compile(src).unwrap_err().to_string(), msg, "{:?}", src);
+        }
+    }
+
+    #[test]
+    fn test_asm_output() {
+        let asm = compile_to_asm("let x = input();\noutput(x * 2);").unwrap();
+        assert_eq!(
+            asm,
+            "main:
+    arb mem.stack
+    in [rb+1]
+    add [rb+1], 0, [var.x]
+    mul [var.x], 2, [rb+1]
+    out [rb+1]
+    hlt
+var.x: .data 0
+ret.value: .data 0
+mem.stack:
+"
+        );
+    }
+}
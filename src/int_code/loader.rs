@@ -0,0 +1,367 @@
+//! Loads Intcode programs from files.
+//!
+//! Four formats are understood:
+//!
+//! * Values: integers separated by commas and/or whitespace, over any number of lines, with `#`
+//!   starting a comment. Puzzle inputs are in this format.
+//! * Assembly: the text format of the `asm` module. Files ending in `.asm` are read as assembly.
+//! * Source: the language of the `lang` module, in files ending in `.icl`.
+//! * Image: a compact binary format written by `encode_image`, recognised by its magic bytes. It
+//!   holds the number of cells followed by each cell as a zigzag LEB128 varint, so the small
+//!   values most programs are made of take a byte or two each.
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::int_code::{asm, lang};
+
+/// The first bytes of every image, followed by a format version.
+const IMAGE_MAGIC: &[u8] = b"INTCODE";
+const IMAGE_VERSION: u8 = 1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Values,
+    Assembly,
+    Source,
+    Image,
+}
+
+impl Format {
+    /// Works out the format of a file from its contents and name.
+    pub fn detect(path: &Path, bytes: &[u8]) -> Self {
+        if bytes.starts_with(IMAGE_MAGIC) {
+            Format::Image
+        } else if path.extension().is_some_and(|ext| ext == "asm") {
+            Format::Assembly
+        } else if path.extension().is_some_and(|ext| ext == "icl") {
+            Format::Source
+        } else {
+            Format::Values
+        }
+    }
+}
+
+/// ParseError points at the line and column, both counting from 1, where a text program stopped
+/// making sense.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: String) -> Self {
+        Self {
+            line,
+            column,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// The file looks like an image but isn't a valid one.
+    Image(&'static str),
+    /// A text file isn't valid UTF-8.
+    Encoding,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(e) => write!(f, "{}", e),
+            LoadError::Parse(e) => write!(f, "{}", e),
+            LoadError::Image(msg) => write!(f, "invalid image: {}", msg),
+            LoadError::Encoding => write!(f, "file is not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> Self {
+        LoadError::Io(e)
+    }
+}
+
+impl From<ParseError> for LoadError {
+    fn from(e: ParseError) -> Self {
+        LoadError::Parse(e)
+    }
+}
+
+/// Reads a program from a file in any of the supported formats.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<i64>, LoadError> {
+    let path = path.as_ref();
+    let bytes = fs::read(path)?;
+    decode(&bytes, Format::detect(path, &bytes))
+}
+
+/// Decodes a program held in memory.
+pub fn decode(bytes: &[u8], format: Format) -> Result<Vec<i64>, LoadError> {
+    if format == Format::Image {
+        return decode_image(bytes);
+    }
+    let text = std::str::from_utf8(bytes).map_err(|_| LoadError::Encoding)?;
+    Ok(match format {
+        Format::Assembly => asm::assemble(text)?,
+        Format::Source => lang::compile(text)?,
+        _ => parse_values(text)?,
+    })
+}
+
+/// Parses integers separated by commas and/or whitespace, as any type that parses them, such as a
+/// Cell. A comma must come between two values, so `1,,2` and a trailing comma are errors rather
+/// than being skipped.
+pub fn parse_values<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    let mut program = vec![];
+    // The position of a comma that hasn't been followed by a value yet.
+    let mut pending_comma = None;
+    let mut line_no = 0;
+    for (i, line) in text.lines().enumerate() {
+        line_no = i + 1;
+        let code = match line.find('#') {
+            Some(end) => &line[..end],
+            None => line,
+        };
+        // Columns count characters, so they're numbered as the characters are taken.
+        let mut chars = code.char_indices().enumerate().peekable();
+        while let Some((n, (start, c))) = chars.next() {
+            let column = n + 1;
+            if c.is_whitespace() {
+                continue;
+            }
+            if c == ',' {
+                if pending_comma.is_some() || program.is_empty() {
+                    return Err(ParseError::new(
+                        line_no,
+                        column,
+                        "expected a value before ','".to_string(),
+                    ));
+                }
+                pending_comma = Some((line_no, column));
+                continue;
+            }
+            let mut end = code.len();
+            while let Some((_, (j, c))) = chars.peek() {
+                if c.is_whitespace() || *c == ',' {
+                    end = *j;
+                    break;
+                }
+                chars.next();
+            }
+            let token = &code[start..end];
+            let value = token.parse().map_err(|_| {
+                ParseError::new(line_no, column, format!("invalid value '{}'", token))
+            })?;
+            program.push(value);
+            pending_comma = None;
+        }
+    }
+    if let Some((line, column)) = pending_comma {
+        return Err(ParseError::new(
+            line,
+            column,
+            "expected a value after ','".to_string(),
+        ));
+    }
+    if program.is_empty() {
+        return Err(ParseError::new(
+            line_no.max(1),
+            1,
+            "no values in program".to_string(),
+        ));
+    }
+    Ok(program)
+}
+
+/// Writes a program to a file as an image.
+pub fn save_image(path: impl AsRef<Path>, program: &[i64]) -> io::Result<()> {
+    fs::write(path, encode_image(program))
+}
+
+pub fn encode_image(program: &[i64]) -> Vec<u8> {
+    let mut out = IMAGE_MAGIC.to_vec();
+    out.push(IMAGE_VERSION);
+    write_varint(&mut out, program.len() as u64);
+    for v in program {
+        // Zigzag encoding interleaves negative and positive values so both stay short.
+        write_varint(&mut out, ((v << 1) ^ (v >> 63)) as u64);
+    }
+    out
+}
+
+pub fn decode_image(bytes: &[u8]) -> Result<Vec<i64>, LoadError> {
+    let rest = match bytes.strip_prefix(IMAGE_MAGIC) {
+        Some(rest) => rest,
+        None => return Err(LoadError::Image("missing magic bytes")),
+    };
+    let mut rest = match rest.split_first() {
+        Some((&IMAGE_VERSION, rest)) => rest,
+        Some(_) => return Err(LoadError::Image("unsupported version")),
+        None => return Err(LoadError::Image("truncated")),
+    };
+    let len = read_varint(&mut rest)?;
+    // Every cell takes at least a byte, which bounds the allocation for a corrupt length.
+    if len > rest.len() as u64 {
+        return Err(LoadError::Image("truncated"));
+    }
+    let mut program = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        let v = read_varint(&mut rest)?;
+        program.push((v >> 1) as i64 ^ -((v & 1) as i64));
+    }
+    if !rest.is_empty() {
+        return Err(LoadError::Image("trailing bytes"));
+    }
+    Ok(program)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, LoadError> {
+    let mut v = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (byte, rest) = match bytes.split_first() {
+            Some((byte, rest)) => (*byte, rest),
+            None => return Err(LoadError::Image("truncated")),
+        };
+        *bytes = rest;
+        if shift == 63 && byte > 1 {
+            return Err(LoadError::Image("value does not fit in 64 bits"));
+        }
+        v |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(v);
+        }
+    }
+    Err(LoadError::Image("value does not fit in 64 bits"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::int_code::loader::{
+        decode, decode_image, encode_image, load, parse_values, Format, LoadError,
+    };
+
+    #[test]
+    fn test_parse_values() {
+        assert_eq!(
+            parse_values::<i64>("1,0,0,3,99\n").unwrap(),
+            vec![1, 0, 0, 3, 99]
+        );
+        let text = "# day 2 example\n1, 9, 10, 3,\n2 3 11 0  # multiply\n\t99,30,40,50";
+        assert_eq!(
+            parse_values::<i64>(text).unwrap(),
+            vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = vec![
+            ("1,2,x3,4", "1:5: invalid value 'x3'"),
+            ("1,\u{a0}2,\u{a0}é,4", "1:7: invalid value 'é'"),
+            ("1,2\n3,,4", "2:3: expected a value before ','"),
+            (",1", "1:1: expected a value before ','"),
+            ("1,2,\n# done", "1:4: expected a value after ','"),
+            ("# nothing\n", "1:1: no values in program"),
+            (
+                "1 99999999999999999999",
+                "1:3: invalid value '99999999999999999999'",
+            ),
+        ];
+        for (text, msg) in cases {
+            assert_eq!(
+                parse_values::<i64>(text).unwrap_err().to_string(),
+                msg,
+                "{:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_image_round_trip() {
+        let program = vec![0, 1, -1, 99, 1 << 40, i64::MIN, i64::MAX, -64, 63, 64];
+        let image = encode_image(&program);
+        assert_eq!(decode_image(&image).unwrap(), program);
+        assert_eq!(Format::detect(Path::new("prog.txt"), &image), Format::Image);
+
+        // Small values take one byte each.
+        assert_eq!(encode_image(&[1, 0, 0, 0, 99]).len(), 7 + 1 + 1 + 4 + 2);
+
+        let truncated = &image[..image.len() - 1];
+        assert!(matches!(
+            decode_image(truncated),
+            Err(LoadError::Image("truncated"))
+        ));
+        let mut trailing = image.clone();
+        trailing.push(0);
+        assert!(matches!(
+            decode_image(&trailing),
+            Err(LoadError::Image("trailing bytes"))
+        ));
+    }
+
+    #[test]
+    fn test_decode_assembly() {
+        assert_eq!(Format::detect(Path::new("a.asm"), b"hlt"), Format::Assembly);
+        assert_eq!(
+            decode(b"out 7\nhlt", Format::Assembly).unwrap(),
+            vec![104, 7, 99]
+        );
+    }
+
+    #[test]
+    fn test_decode_source() {
+        assert_eq!(Format::detect(Path::new("a.icl"), b""), Format::Source);
+        assert_eq!(
+            decode(b"output(7);", Format::Source).unwrap(),
+            vec![109, 6, 104, 7, 99, 0]
+        );
+        assert_eq!(
+            decode(b"output(x);", Format::Source)
+                .unwrap_err()
+                .to_string(),
+            "1:8: undefined variable 'x'"
+        );
+    }
+
+    #[test]
+    fn test_load_puzzle_inputs() {
+        assert_eq!(load("./input/day02.txt").unwrap().len(), 161);
+        assert_eq!(
+            load("./input/day09.txt").unwrap()[..3],
+            [1102, 34463338, 34463338]
+        );
+        assert!(matches!(
+            load("./input/day03.txt"),
+            Err(LoadError::Parse(_))
+        ));
+    }
+}
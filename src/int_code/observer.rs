@@ -0,0 +1,266 @@
+//! Observers are told what an IntCodeComputer does as it runs.
+//!
+//! Every method has an empty default, so an observer only implements the events it cares about.
+//! The computer calls them directly rather than through a trait object, so the default observer,
+//! `()`, costs nothing. Two observers can watch the same computer as a pair.
+use log::debug;
+
+use crate::int_code::{Cell, ComputerState};
+
+pub trait Observer<T: Cell = i64> {
+    /// Called once for each instruction that runs, after it has been decoded at `ptr`. An input
+    /// instruction that has to wait is only reported when it gets its input.
+    fn instruction_decoded(&mut self, _ptr: u64, _op_code: i64) {}
+
+    /// Called when the jump at `ptr` has decided whether it is `taken`.
+    fn jump_resolved(&mut self, _ptr: u64, _taken: bool) {}
+
+    /// Called when an instruction writes `value` to `addr`.
+    fn memory_written(&mut self, _addr: u64, _value: &T) {}
+
+    /// Called when an instruction reads `value` from the input.
+    fn input_consumed(&mut self, _value: &T) {}
+
+    /// Called when an instruction writes `value` to the output.
+    fn output_produced(&mut self, _value: &T) {}
+
+    /// Called when the computer halts, crashes, starts waiting for input or gets it.
+    fn state_changed(&mut self, _state: &ComputerState) {}
+}
+
+impl<T: Cell> Observer<T> for () {}
+
+impl<T: Cell, A: Observer<T>, B: Observer<T>> Observer<T> for (A, B) {
+    fn instruction_decoded(&mut self, ptr: u64, op_code: i64) {
+        self.0.instruction_decoded(ptr, op_code);
+        self.1.instruction_decoded(ptr, op_code);
+    }
+
+    fn jump_resolved(&mut self, ptr: u64, taken: bool) {
+        self.0.jump_resolved(ptr, taken);
+        self.1.jump_resolved(ptr, taken);
+    }
+
+    fn memory_written(&mut self, addr: u64, value: &T) {
+        self.0.memory_written(addr, value);
+        self.1.memory_written(addr, value);
+    }
+
+    fn input_consumed(&mut self, value: &T) {
+        self.0.input_consumed(value);
+        self.1.input_consumed(value);
+    }
+
+    fn output_produced(&mut self, value: &T) {
+        self.0.output_produced(value);
+        self.1.output_produced(value);
+    }
+
+    fn state_changed(&mut self, state: &ComputerState) {
+        self.0.state_changed(state);
+        self.1.state_changed(state);
+    }
+}
+
+/// Logger logs each event at debug level as a single line of `key=value` pairs, for example
+/// `event=write addr=225 value=1101`.
+#[derive(Default)]
+pub struct Logger;
+
+impl<T: Cell> Observer<T> for Logger {
+    fn instruction_decoded(&mut self, ptr: u64, op_code: i64) {
+        debug!("event=instruction ptr={} op_code={}", ptr, op_code);
+    }
+
+    fn jump_resolved(&mut self, ptr: u64, taken: bool) {
+        debug!("event=jump ptr={} taken={}", ptr, taken);
+    }
+
+    fn memory_written(&mut self, addr: u64, value: &T) {
+        debug!("event=write addr={} value={}", addr, value);
+    }
+
+    fn input_consumed(&mut self, value: &T) {
+        debug!("event=input value={}", value);
+    }
+
+    fn output_produced(&mut self, value: &T) {
+        debug!("event=output value={}", value);
+    }
+
+    fn state_changed(&mut self, state: &ComputerState) {
+        match state {
+            ComputerState::Halted => debug!("event=state state=halted"),
+            ComputerState::ReadyForInstruction => debug!("event=state state=ready"),
+            ComputerState::WaitingForInput => debug!("event=state state=waiting_for_input"),
+            ComputerState::Crashed(e) => debug!(
+                "event=state state=crashed ptr={} error={:?}",
+                e.ptr,
+                e.kind.name()
+            ),
+        }
+    }
+}
+
+/// Event is one call to an Observer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event<T = i64> {
+    Instruction { ptr: u64, op_code: i64 },
+    Jump { ptr: u64, taken: bool },
+    Write { addr: u64, value: T },
+    Input(T),
+    Output(T),
+    State(ComputerState),
+}
+
+/// Recorder keeps every event, for tracing a run or checking it in a test.
+pub struct Recorder<T = i64> {
+    pub events: Vec<Event<T>>,
+}
+
+impl<T> Default for Recorder<T> {
+    fn default() -> Self {
+        Self { events: vec![] }
+    }
+}
+
+impl<T: Cell> Observer<T> for Recorder<T> {
+    fn instruction_decoded(&mut self, ptr: u64, op_code: i64) {
+        self.events.push(Event::Instruction { ptr, op_code });
+    }
+
+    fn jump_resolved(&mut self, ptr: u64, taken: bool) {
+        self.events.push(Event::Jump { ptr, taken });
+    }
+
+    fn memory_written(&mut self, addr: u64, value: &T) {
+        self.events.push(Event::Write {
+            addr,
+            value: value.clone(),
+        });
+    }
+
+    fn input_consumed(&mut self, value: &T) {
+        self.events.push(Event::Input(value.clone()));
+    }
+
+    fn output_produced(&mut self, value: &T) {
+        self.events.push(Event::Output(value.clone()));
+    }
+
+    fn state_changed(&mut self, state: &ComputerState) {
+        self.events.push(Event::State(*state));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::observer::{Event, Observer, Recorder};
+    use crate::int_code::{BigInt, ComputerState, ErrorKind, IntCodeComputer, IntCodeError};
+
+    #[test]
+    fn test_events() {
+        // Reads a value, doubles it into cell 9 and outputs it.
+        let program = vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0];
+        let mut computer = IntCodeComputer::new(program).with_observer(Recorder::default());
+        computer.run();
+        computer.input.write(21);
+        computer.run();
+        assert_eq!(
+            computer.into_observer().events,
+            vec![
+                Event::State(ComputerState::WaitingForInput),
+                Event::Instruction { ptr: 0, op_code: 3 },
+                Event::Input(21),
+                Event::Write { addr: 9, value: 21 },
+                Event::State(ComputerState::ReadyForInstruction),
+                Event::Instruction {
+                    ptr: 2,
+                    op_code: 1002
+                },
+                Event::Write { addr: 9, value: 42 },
+                Event::Instruction { ptr: 6, op_code: 4 },
+                Event::Output(42),
+                Event::Instruction {
+                    ptr: 8,
+                    op_code: 99
+                },
+                Event::State(ComputerState::Halted),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_crash() {
+        let mut computer =
+            IntCodeComputer::new(vec![1, 0, 0, -1]).with_observer(Recorder::default());
+        assert!(computer.run_with_budget(10).is_err());
+        let error = IntCodeError {
+            ptr: 0,
+            kind: ErrorKind::NegativeAddress(-1),
+        };
+        assert_eq!(
+            computer.observer().events,
+            vec![
+                Event::Instruction { ptr: 0, op_code: 1 },
+                Event::State(ComputerState::Crashed(error)),
+            ]
+        );
+        // An invalid opcode is never decoded, so the crash is the only event.
+        let mut computer = IntCodeComputer::new(vec![42]).with_observer(Recorder::default());
+        assert!(computer.run_with_budget(10).is_err());
+        assert_eq!(computer.observer().events.len(), 1);
+    }
+
+    #[test]
+    fn test_jumps() {
+        // jt 0, 99 falls through and jf 0, 7 jumps to the hlt at 7.
+        let program = vec![1105, 0, 99, 1106, 0, 7, 42, 99];
+        let mut computer = IntCodeComputer::new(program).with_observer(Recorder::default());
+        computer.run();
+        let jumps: Vec<&Event> = computer
+            .observer()
+            .events
+            .iter()
+            .filter(|e| matches!(e, Event::Jump { .. }))
+            .collect();
+        assert_eq!(
+            jumps,
+            vec![
+                &Event::Jump {
+                    ptr: 0,
+                    taken: false
+                },
+                &Event::Jump {
+                    ptr: 3,
+                    taken: true
+                },
+            ]
+        );
+    }
+
+    /// Counts outputs, to check that pairs of observers both see every event.
+    #[derive(Default)]
+    struct Outputs(usize);
+
+    impl Observer<BigInt> for Outputs {
+        fn output_produced(&mut self, _value: &BigInt) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn test_pair_with_other_cells() {
+        let program: Vec<BigInt> = [104, 1, 104, 2, 99]
+            .iter()
+            .map(|v| BigInt::from(*v))
+            .collect();
+        let mut computer = IntCodeComputer::<BigInt>::with_cells(program)
+            .with_observer((Outputs::default(), Recorder::default()));
+        computer.run();
+        let (outputs, recorder) = computer.into_observer();
+        assert_eq!(outputs.0, 2);
+        assert_eq!(recorder.events.len(), 6);
+        assert_eq!(recorder.events[1], Event::Output(BigInt::from(1)));
+    }
+}
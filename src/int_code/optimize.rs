@@ -0,0 +1,952 @@
+//! A peephole optimizer for Intcode programs.
+//!
+//! `optimize` first works out which instructions can run, and what is known about memory and the
+//! relative base each time they do. A program runs as loaded, so at first every cell holds its
+//! loaded value. After a write, the cell holds the stored value if that is the same on every path
+//! there, and is unknown otherwise. An instruction whose cells are written is decoded from what is
+//! known about them when it runs, and if its opcode is unknown, every instruction its operands
+//! allow may run. So a program can write into its own code, and use the relative base, as long as
+//! every address it reads, writes or jumps to is known. If one isn't, any cell could be affected,
+//! so the program is returned unchanged, together with the reason.
+//!
+//! Instructions whose cells are never written can be simplified:
+//!
+//! * A jump whose condition is known every time it runs becomes an unconditional `jt 1, target`.
+//!   That includes a jump through a cell with a known value, as in `add 294, 0, [0]` followed by
+//!   `jt 1, [0]`.
+//! * A jump that lands on an unconditional jump is pointed straight at its final target.
+//! * Cells that no instruction can run, read or write, such as code after an unconditional jump,
+//!   are cleared, and zeros at the end of the program are dropped.
+//!
+//! Instructions are simplified in place, never removed, so only the dropped zeros make a program
+//! shorter. In particular the instructions that set up a folded jump, like the `add` above, stay:
+//! the value they store is left in memory where the rest of the program, or whoever reads the
+//! memory once it halts, may see it.
+//!
+//! The optimized program produces the same outcome and output as the original, and leaves the
+//! same values in every cell it didn't rewrite. It runs as loaded: cells that nothing writes are
+//! treated as constants, so a program should be patched before it is optimized, not after.
+//! `verify` checks this on sample inputs.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::int_code::transpile::{decode, Param};
+use crate::int_code::{IntCodeComputer, RunOutcome, MAX_ADDRESS};
+
+/// Optimized is the result of optimize.
+#[derive(Debug)]
+pub struct Optimized {
+    pub program: Vec<i64>,
+    pub changes: Vec<Change>,
+    /// Why the program was left alone, if it was.
+    pub blocked: Option<Blocker>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// The jump at `addr` always goes to `target`, so it became unconditional.
+    Folded { addr: usize, target: usize },
+    /// The jump at `addr` was pointed past the unconditional jumps it used to land on.
+    Threaded { addr: usize, target: usize },
+    /// Cells that nothing runs, reads or writes were set to 0.
+    Cleared { start: usize, len: usize },
+    /// Zeros at the end of the program were dropped, since memory past the end reads as 0.
+    Trimmed { len: usize },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Folded { addr, target } => {
+                write!(f, "{}: jump always goes to {}", addr, target)
+            }
+            Change::Threaded { addr, target } => {
+                write!(f, "{}: jump threaded through to {}", addr, target)
+            }
+            Change::Cleared { start, len } => {
+                write!(f, "{}: cleared {} unused cells", start, len)
+            }
+            Change::Trimmed { len } => write!(f, "trimmed {} zeros from the end", len),
+        }
+    }
+}
+
+/// Blocker is the instruction that stopped the analysis.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Blocker {
+    pub addr: usize,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for Blocker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.addr, self.reason)
+    }
+}
+
+/// Instructions may run off the end of a program, where memory reads as 0, so the analysis works
+/// on a copy with this many zeros added.
+const PADDING: usize = 3;
+
+/// Returns whether a value can be used as an address.
+fn valid(addr: i64) -> bool {
+    (0..=MAX_ADDRESS).contains(&addr)
+}
+
+/// Splits an instruction into its op code and the modes of its parameters, or returns None if
+/// running it is an error.
+fn split(instruction: i64) -> Option<(i64, Vec<i64>)> {
+    let count = match instruction % 100 {
+        1 | 2 | 7 | 8 => 3,
+        5 | 6 => 2,
+        3 | 4 | 9 => 1,
+        99 => 0,
+        _ => return None,
+    };
+    let modes: Vec<i64> = (0..count)
+        .map(|i| instruction / 10_i64.pow(i + 2) % 10)
+        .collect();
+    if modes.iter().all(|m| (0..=2).contains(m)) {
+        Some((instruction % 100, modes))
+    } else {
+        None
+    }
+}
+
+/// Returns every instruction that can run: each op code with every mode for its parameters.
+fn instructions() -> Vec<i64> {
+    let mut instructions = vec![];
+    for op_code in (1..=9).chain(Some(99)) {
+        let count = split(op_code).map_or(0, |(_, modes)| modes.len() as u32);
+        for modes in 0..3_i64.pow(count) {
+            let mut instruction = op_code;
+            for i in 0..count {
+                instruction += modes / 3_i64.pow(i) % 3 * 10_i64.pow(i + 2);
+            }
+            instructions.push(instruction);
+        }
+    }
+    instructions
+}
+
+/// Operand is a parameter of an instruction: its mode, and the value in its cell if that is known.
+#[derive(Clone, Copy)]
+struct Operand {
+    mode: i64,
+    cell: Option<i64>,
+}
+
+/// Inst is an instruction that can run at some address.
+struct Inst {
+    op_code: i64,
+    operands: Vec<Operand>,
+}
+
+impl Inst {
+    fn len(&self) -> usize {
+        self.operands.len() + 1
+    }
+}
+
+/// Where a parameter points when its instruction runs.
+enum Place {
+    Immediate,
+    Cell(usize),
+    /// An address out of range, so using it is an error.
+    Invalid,
+    Unknown,
+}
+
+/// State is what is known every time an instruction runs.
+#[derive(Clone)]
+struct State {
+    /// Cells that may have been written, with their value if it is the same every time. Other
+    /// cells hold their loaded value.
+    cells: BTreeMap<usize, Option<i64>>,
+    /// The relative base, if it is the same every time.
+    base: Option<i64>,
+}
+
+impl State {
+    /// Returns what is known when a program starts.
+    fn loaded() -> Self {
+        Self {
+            cells: BTreeMap::new(),
+            base: Some(0),
+        }
+    }
+
+    fn cell(&self, program: &[i64], addr: usize) -> Option<i64> {
+        match self.cells.get(&addr) {
+            Some(value) => *value,
+            None => Some(program.get(addr).copied().unwrap_or(0)),
+        }
+    }
+
+    /// Returns where an operand points. The destination of a write is an address even in
+    /// immediate mode.
+    fn place(&self, operand: Operand, dest: bool) -> Place {
+        let addr = match (operand.mode, operand.cell, self.base) {
+            (1, _, _) if !dest => return Place::Immediate,
+            (0..=1, Some(a), _) => Some(a),
+            (2, Some(a), Some(base)) => a.checked_add(base),
+            _ => return Place::Unknown,
+        };
+        match addr {
+            Some(a) if valid(a) => Place::Cell(a as usize),
+            _ => Place::Invalid,
+        }
+    }
+
+    /// Returns the value an operand reads, if it is known.
+    fn load(&self, program: &[i64], operand: Operand) -> Option<i64> {
+        match self.place(operand, false) {
+            Place::Immediate => operand.cell,
+            Place::Cell(a) => self.cell(program, a),
+            Place::Invalid | Place::Unknown => None,
+        }
+    }
+
+    /// Forgets what isn't known in `other` too, and returns whether anything was forgotten.
+    fn join(&mut self, program: &[i64], other: &State) -> bool {
+        let addrs: Vec<usize> = self
+            .cells
+            .keys()
+            .chain(other.cells.keys())
+            .copied()
+            .collect();
+        let mut changed = false;
+        for addr in addrs {
+            let value = self.cell(program, addr);
+            if value.is_some() && value != other.cell(program, addr) {
+                self.cells.insert(addr, None);
+                changed = true;
+            }
+        }
+        if self.base.is_some() && self.base != other.base {
+            self.base = None;
+            changed = true;
+        }
+        changed
+    }
+
+    /// Returns the instructions that can run at `addr`: the one in its cells, or every one if its
+    /// opcode is unknown. Cells that can't run aren't returned.
+    fn decode(&self, program: &[i64], addr: usize) -> Vec<Inst> {
+        let candidates = match self.cell(program, addr) {
+            Some(instruction) => vec![instruction],
+            None => instructions(),
+        };
+        candidates
+            .into_iter()
+            .filter_map(split)
+            .map(|(op_code, modes)| Inst {
+                op_code,
+                operands: modes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, mode)| Operand {
+                        mode,
+                        cell: self.cell(program, addr + 1 + i),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// The cells instructions read and write through their parameters.
+#[derive(Default)]
+struct Accesses {
+    read: BTreeSet<usize>,
+    written: BTreeSet<usize>,
+}
+
+/// Returns where `inst` at `addr` can go next and what is known then, and records the cells it
+/// reads and writes. Paths on which it is an error go nowhere.
+fn step(
+    program: &[i64],
+    state: &State,
+    addr: usize,
+    inst: &Inst,
+    accesses: &mut Accesses,
+) -> Result<Vec<(usize, State)>, Blocker> {
+    let blocked = |reason| Err(Blocker { addr, reason });
+    let reads = match inst.op_code {
+        1 | 2 | 5 | 6 | 7 | 8 => 2,
+        4 | 9 => 1,
+        _ => 0,
+    };
+    let mut values = vec![];
+    for operand in inst.operands[..reads].iter() {
+        match state.place(*operand, false) {
+            Place::Immediate => (),
+            Place::Cell(a) => {
+                accesses.read.insert(a);
+            }
+            Place::Invalid => return Ok(vec![]),
+            Place::Unknown => return blocked("reads from an address that changes"),
+        }
+        values.push(state.load(program, *operand));
+    }
+    let next = addr + inst.len();
+    let mut after = state.clone();
+    match inst.op_code {
+        1 | 2 | 3 | 7 | 8 => {
+            let dest = match state.place(inst.operands[reads], true) {
+                Place::Cell(a) => a,
+                Place::Invalid => return Ok(vec![]),
+                _ => return blocked("writes to an address that changes"),
+            };
+            let value = match values[..] {
+                [Some(a), Some(b)] => {
+                    let value = match inst.op_code {
+                        1 => a.checked_add(b),
+                        2 => a.checked_mul(b),
+                        7 => Some((a < b) as i64),
+                        _ => Some((a == b) as i64),
+                    };
+                    // Overflowing is an error.
+                    if value.is_none() {
+                        return Ok(vec![]);
+                    }
+                    value
+                }
+                _ => None,
+            };
+            accesses.written.insert(dest);
+            after.cells.insert(dest, value);
+            Ok(vec![(next, after)])
+        }
+        4 => Ok(vec![(next, after)]),
+        9 => {
+            after.base = match (state.base, values[0]) {
+                (Some(base), Some(v)) => match base.checked_add(v) {
+                    Some(base) => Some(base),
+                    None => return Ok(vec![]),
+                },
+                _ => None,
+            };
+            Ok(vec![(next, after)])
+        }
+        5 | 6 => {
+            let taken = values[0].map(|c| (c != 0) == (inst.op_code == 5));
+            let mut successors = vec![];
+            if taken != Some(true) {
+                successors.push((next, state.clone()));
+            }
+            if taken != Some(false) {
+                match values[1] {
+                    Some(t) if valid(t) => successors.push((t as usize, after)),
+                    Some(_) => (),
+                    None => return blocked("jumps to an address that changes"),
+                }
+            }
+            Ok(successors)
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// Analysis holds what can happen when a program runs.
+struct Analysis {
+    /// The length of the program without the padding.
+    len: usize,
+    /// What is known each time an instruction runs, by its address.
+    states: BTreeMap<usize, State>,
+    /// The instructions that can run at each address.
+    ops: BTreeMap<usize, Vec<Inst>>,
+    /// Cells that may be written.
+    written: BTreeSet<usize>,
+    /// Cells that may be read through a parameter.
+    read: BTreeSet<usize>,
+    /// Cells of instructions that may not decode. Running into one is an error, which has to be
+    /// kept.
+    crashes: BTreeSet<usize>,
+}
+
+impl Analysis {
+    /// Works out what a program can do, or returns the first instruction that uses an address
+    /// that isn't known. Each instruction is stepped from what is known when it runs, until that
+    /// stops changing. Every change forgets something, so this ends.
+    fn new(program: &[i64]) -> Result<Self, Blocker> {
+        let mut states = BTreeMap::new();
+        states.insert(0, State::loaded());
+        let mut work = vec![0];
+        while let Some(addr) = work.pop() {
+            let state = states[&addr].clone();
+            for inst in state.decode(program, addr) {
+                let successors = step(program, &state, addr, &inst, &mut Accesses::default())?;
+                for (next, after) in successors {
+                    match states.get_mut(&next) {
+                        Some(known) => {
+                            if known.join(program, &after) {
+                                work.push(next);
+                            }
+                        }
+                        None => {
+                            states.insert(next, after);
+                            work.push(next);
+                        }
+                    }
+                }
+            }
+        }
+        // Only the final states count: earlier ones may have known more than is true.
+        let mut accesses = Accesses::default();
+        let mut ops = BTreeMap::new();
+        let mut crashes = BTreeSet::new();
+        for (addr, state) in states.iter() {
+            let insts = state.decode(program, *addr);
+            for inst in insts.iter() {
+                step(program, state, *addr, inst, &mut accesses)?;
+            }
+            if state.cell(program, *addr).and_then(split).is_none() {
+                crashes.extend(*addr..addr + 4);
+            }
+            if !insts.is_empty() {
+                ops.insert(*addr, insts);
+            }
+        }
+        Ok(Self {
+            len: program.len() - PADDING,
+            states,
+            ops,
+            written: accesses.written,
+            read: accesses.read,
+            crashes,
+        })
+    }
+
+    /// Returns the value of a parameter if it is the same every time the instruction runs,
+    /// wherever it runs from.
+    fn constant(&self, program: &[i64], param: Param) -> Option<i64> {
+        match param {
+            Param::Immediate(v) => Some(v),
+            Param::Position(a) if valid(a) && !self.written.contains(&(a as usize)) => {
+                Some(program.get(a as usize).copied().unwrap_or(0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether the instruction at `addr` can be rewritten: it is the only one that can run
+    /// there, and none of its cells are written, read as data, belong to another instruction or
+    /// lie past the end of the program.
+    fn rewritable(&self, addr: usize) -> bool {
+        let end = match self.ops.get(&addr).map(|insts| &insts[..]) {
+            Some([inst]) => addr + inst.len(),
+            _ => return false,
+        };
+        if end > self.len {
+            return false;
+        }
+        let overlaps = self
+            .ops
+            .range(addr.saturating_sub(3)..end)
+            .any(|(a, insts)| *a != addr && insts.iter().any(|inst| a + inst.len() > addr));
+        !overlaps
+            && (addr..end).all(|a| {
+                !self.read.contains(&a) && !self.written.contains(&a) && !self.crashes.contains(&a)
+            })
+    }
+
+    /// Turns jumps whose condition is known every time they run into unconditional jumps. The
+    /// instructions that stored the condition or the target are left as they are.
+    fn fold(&self, program: &[i64], out: &mut [i64], changes: &mut Vec<Change>) {
+        for (addr, insts) in self.ops.iter() {
+            let op = match &insts[..] {
+                [op] if matches!(op.op_code, 5 | 6) => op,
+                _ => continue,
+            };
+            let state = &self.states[addr];
+            // Both parameters are read even if the jump isn't taken, and reading an address out
+            // of range is an error that has to stay.
+            let readable = op
+                .operands
+                .iter()
+                .all(|o| matches!(state.place(*o, false), Place::Immediate | Place::Cell(_)));
+            let next = addr + op.len();
+            let (c, t) = (
+                state.load(program, op.operands[0]),
+                state.load(program, op.operands[1]),
+            );
+            let target = match (c, t) {
+                (Some(c), _) if (c != 0) != (op.op_code == 5) => next as i64,
+                (Some(_), Some(t)) if valid(t) => t,
+                _ => continue,
+            };
+            let jump = [1105, 1, target];
+            if readable && out[*addr..next] != jump && self.rewritable(*addr) {
+                out[*addr..next].copy_from_slice(&jump);
+                changes.push(Change::Folded {
+                    addr: *addr,
+                    target: target as usize,
+                });
+            }
+        }
+    }
+
+    /// Returns where an unconditional jump at `addr` in `out` goes, if there is one.
+    fn unconditional(&self, out: &[i64], addr: usize) -> Option<usize> {
+        if !self.ops.contains_key(&addr) {
+            return None;
+        }
+        let op = decode(out, addr)?;
+        if !matches!(op.op_code, 5 | 6)
+            || (addr..addr + op.len()).any(|a| self.written.contains(&a))
+        {
+            return None;
+        }
+        let c = self.constant(out, op.params[0])?;
+        let t = self.constant(out, op.params[1])?;
+        if (c != 0) == (op.op_code == 5) && valid(t) {
+            Some(t as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Points jumps that land on unconditional jumps at where those go.
+    fn thread(&self, out: &mut [i64], changes: &mut Vec<Change>) {
+        for addr in self.ops.keys() {
+            if !self.rewritable(*addr) {
+                continue;
+            }
+            let op = match decode(out, *addr) {
+                Some(op) if matches!(op.op_code, 5 | 6) => op,
+                _ => continue,
+            };
+            let mut target = match self.constant(out, op.params[1]) {
+                Some(t) if valid(t) => t as usize,
+                _ => continue,
+            };
+            let first = target;
+            let mut seen = BTreeSet::new();
+            while let Some(next) = self.unconditional(out, target) {
+                if !seen.insert(target) {
+                    break;
+                }
+                target = next;
+            }
+            if target == first {
+                continue;
+            }
+            // The target becomes an immediate, whatever its mode was.
+            out[*addr] = out[*addr] % 1000 + 1000;
+            out[addr + 2] = target as i64;
+            changes.push(Change::Threaded {
+                addr: *addr,
+                target,
+            });
+        }
+    }
+
+    /// Clears the cells of `out` that nothing runs, reads or writes any more, and drops the zeros
+    /// at its end.
+    fn clear(&self, out: &mut Vec<i64>, changes: &mut Vec<Change>) {
+        // The rewritten jumps may make code unreachable, and read fewer cells.
+        let used = match Self::new(out) {
+            Ok(analysis) => analysis,
+            Err(_) => {
+                out.truncate(self.len);
+                return;
+            }
+        };
+        let mut keep = vec![false; out.len()];
+        let code = used.ops.iter().flat_map(|(addr, insts)| {
+            let len = insts.iter().map(Inst::len).max().unwrap_or(0);
+            *addr..addr + len
+        });
+        for a in code
+            .chain(used.written.iter().copied())
+            .chain(used.read.iter().copied())
+            .chain(used.crashes.iter().copied())
+        {
+            if a < keep.len() {
+                keep[a] = true;
+            }
+        }
+        let mut addr = 0;
+        while addr < self.len {
+            if keep[addr] || out[addr] == 0 {
+                addr += 1;
+                continue;
+            }
+            let start = addr;
+            while addr < self.len && !keep[addr] {
+                out[addr] = 0;
+                addr += 1;
+            }
+            changes.push(Change::Cleared {
+                start,
+                len: addr - start,
+            });
+        }
+        let len = out.iter().rposition(|v| *v != 0).map_or(1, |last| last + 1);
+        if len < self.len {
+            changes.push(Change::Trimmed {
+                len: self.len - len,
+            });
+        }
+        out.truncate(len);
+    }
+}
+
+/// Optimizes a program. See the module docs.
+pub fn optimize(program: &[i64]) -> Optimized {
+    let mut padded = program.to_vec();
+    padded.resize(program.len() + PADDING, 0);
+    let analysis = match Analysis::new(&padded) {
+        Ok(analysis) => analysis,
+        Err(blocker) => {
+            return Optimized {
+                program: program.to_vec(),
+                changes: vec![],
+                blocked: Some(blocker),
+            }
+        }
+    };
+    let mut out = padded.clone();
+    let mut changes = vec![];
+    analysis.fold(&padded, &mut out, &mut changes);
+    analysis.thread(&mut out, &mut changes);
+    analysis.clear(&mut out, &mut changes);
+    Optimized {
+        program: out,
+        changes,
+        blocked: None,
+    }
+}
+
+/// Mismatch is an input on which a program and its optimized version behave differently.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub input: Vec<i64>,
+    pub message: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "input {:?}: {}", self.input, self.message)
+    }
+}
+
+/// Runs both programs on each input for at most `budget` instructions and compares their
+/// outcomes, outputs, and the final value of every cell the optimizer didn't rewrite. Inputs on
+/// which the original doesn't finish within the budget are skipped. Returns how many inputs were
+/// compared.
+pub fn verify(
+    original: &[i64],
+    optimized: &[i64],
+    inputs: &[Vec<i64>],
+    budget: u64,
+) -> Result<usize, Mismatch> {
+    let run = |program: &[i64], input: &[i64]| {
+        let mut computer = IntCodeComputer::new(program.to_vec());
+        for v in input {
+            computer.input.write(*v);
+        }
+        let outcome = computer.run_with_budget(budget).map_err(|e| e.kind.name());
+        (outcome, computer)
+    };
+    let mut compared = 0;
+    for input in inputs {
+        let mismatch = |message: String| Mismatch {
+            input: input.clone(),
+            message,
+        };
+        let (outcome, expected) = run(original, input);
+        if outcome == Ok(RunOutcome::BudgetExhausted) {
+            continue;
+        }
+        let (optimized_outcome, actual) = run(optimized, input);
+        if outcome != optimized_outcome {
+            return Err(mismatch(format!(
+                "outcome {:?}, optimized {:?}",
+                outcome, optimized_outcome
+            )));
+        }
+        let output = expected.dump_output().read_all();
+        let optimized_output = actual.dump_output().read_all();
+        if output != optimized_output {
+            return Err(mismatch(format!(
+                "output {:?}, optimized {:?}",
+                output, optimized_output
+            )));
+        }
+        let len = expected.memory.len().max(actual.memory.len());
+        for addr in 0..len {
+            let rewritten = original.get(addr).unwrap_or(&0) != optimized.get(addr).unwrap_or(&0);
+            let (a, b) = (
+                expected.memory.read(addr as u64),
+                actual.memory.read(addr as u64),
+            );
+            if !rewritten && a != b {
+                return Err(mismatch(format!("cell {} is {}, optimized {}", addr, a, b)));
+            }
+        }
+        compared += 1;
+    }
+    Ok(compared)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::asm::assemble;
+    use crate::int_code::fuzz::{mutate, random_input, random_program, Rng};
+    use crate::int_code::optimize::{optimize, verify, Blocker, Change};
+    use crate::util;
+
+    const FOLD: &str = "
+            add 2, 3, [x]
+            jt  [x], done
+            out 1
+        done:
+            out 2
+            hlt
+        x:  .data 0
+    ";
+
+    const THREAD: &str = "
+            in  [x]
+            jt  [x], hop
+            out 1
+            jt  1, end
+            out 2
+            out 3
+        hop:
+            jt  1, end
+        end:
+            out [x]
+            hlt
+        x:  .data 7
+    ";
+
+    /// Counts down from 3.
+    const LOOP: &str = "
+            add 0, 3, [n]
+        loop:
+            jf  [n], done
+            add [n], -1, [n]
+            jt  1, loop
+        done:
+            add [n], 3, [n]
+            eq  [n], 3, [t]
+            jt  [t], stop
+            out 99
+        stop:
+            out [n]
+            hlt
+        n:  .data 0
+        t:  .data 0
+    ";
+
+    /// Keeps a value in a cell it finds through the relative base.
+    const RELATIVE: &str = "
+            arb x
+            add 2, 3, [rb]
+            jt  [x], done
+            out 1
+        done:
+            out [rb]
+            hlt
+        x:  .data 0
+    ";
+
+    #[test]
+    fn test_fold_constant_jump() {
+        let program = assemble(FOLD).unwrap();
+        let optimized = optimize(&program);
+        assert_eq!(optimized.blocked, None);
+        assert_eq!(
+            optimized.changes,
+            vec![
+                Change::Folded { addr: 4, target: 9 },
+                Change::Cleared { start: 7, len: 2 },
+                Change::Trimmed { len: 1 },
+            ]
+        );
+        assert_eq!(
+            optimized.program,
+            vec![1101, 2, 3, 12, 1105, 1, 9, 0, 0, 104, 2, 99]
+        );
+        assert_eq!(verify(&program, &optimized.program, &[vec![]], 100), Ok(1));
+    }
+
+    #[test]
+    fn test_dead_code_and_threading() {
+        let program = assemble(THREAD).unwrap();
+        let optimized = optimize(&program);
+        assert_eq!(
+            optimized.changes,
+            vec![
+                Change::Threaded {
+                    addr: 2,
+                    target: 17
+                },
+                Change::Cleared { start: 10, len: 7 },
+            ]
+        );
+        let inputs = vec![vec![0], vec![1], vec![-5]];
+        assert_eq!(verify(&program, &optimized.program, &inputs, 100), Ok(3));
+    }
+
+    #[test]
+    fn test_known_values_merge_where_paths_meet() {
+        // 3 is stored just before the loop's first jump, but the jump is also reached from the end
+        // of the loop with other values, so it must stay conditional.
+        let program = assemble(LOOP).unwrap();
+        let optimized = optimize(&program);
+        assert!(!optimized
+            .changes
+            .iter()
+            .any(|c| matches!(c, Change::Folded { addr: 4, .. })));
+        assert_eq!(verify(&program, &optimized.program, &[vec![]], 1000), Ok(1));
+    }
+
+    #[test]
+    fn test_relative_base_and_own_code() {
+        let program = assemble(RELATIVE).unwrap();
+        let optimized = optimize(&program);
+        assert_eq!(
+            optimized.changes,
+            vec![
+                Change::Folded {
+                    addr: 6,
+                    target: 11
+                },
+                Change::Cleared { start: 9, len: 2 },
+                Change::Trimmed { len: 1 },
+            ]
+        );
+        assert_eq!(verify(&program, &optimized.program, &[vec![]], 100), Ok(1));
+
+        // The add at 5 writes an operand of the add at 14, but only on a path that never gets
+        // there.
+        let late_write = vec![
+            3, 20, 1005, 20, 14, 1, 1105, 1, 17, 104, 2, 104, 3, 16, 1, 17, 4, 20, 99, 7,
+        ];
+        let optimized = optimize(&late_write);
+        assert_eq!(optimized.blocked, None);
+        let inputs = vec![vec![0], vec![1]];
+        assert_eq!(verify(&late_write, &optimized.program, &inputs, 100), Ok(2));
+    }
+
+    #[test]
+    fn test_real_inputs() {
+        // Day 2 writes over operands of instructions that have already run, and nothing reads the
+        // cells after its hlt.
+        let program = util::read_int_code_memory("./input/day02.txt");
+        let optimized = optimize(&program);
+        assert_eq!(
+            optimized.changes,
+            vec![
+                Change::Cleared { start: 157, len: 4 },
+                Change::Trimmed { len: 4 },
+            ]
+        );
+        assert_eq!(optimized.program.len(), program.len() - 4);
+        assert_eq!(verify(&program, &optimized.program, &[vec![]], 1000), Ok(1));
+
+        // Day 5 adds its input to the opcode at 6, so it may run any instruction there. Later it
+        // stores constants in cell 0 and jumps through it, and its self-tests have known results,
+        // so the code that handles a failed test is never run.
+        let program = util::read_int_code_memory("./input/day05.txt");
+        let optimized = optimize(&program);
+        assert_eq!(optimized.blocked, None);
+        for (addr, target) in [(288, 294), (308, 314)] {
+            assert!(optimized.changes.contains(&Change::Folded { addr, target }));
+        }
+        let used = |program: &[i64]| program.iter().filter(|v| **v != 0).count();
+        assert!(used(&optimized.program) < used(&program));
+        let inputs = vec![vec![1], vec![5], vec![0]];
+        assert_eq!(
+            verify(&program, &optimized.program, &inputs, 100_000),
+            Ok(3)
+        );
+
+        let cases = vec![
+            // Day 7 jumps through a table, by writing the index into the jump's operand.
+            ("./input/day07.txt", 6),
+            // Day 9 recurses, so the relative base isn't known in the function it calls.
+            ("./input/day09.txt", 924),
+        ];
+        for (path, addr) in cases {
+            let program = util::read_int_code_memory(path);
+            let optimized = optimize(&program);
+            assert_eq!(optimized.program, program);
+            assert_eq!(
+                optimized.blocked,
+                Some(Blocker {
+                    addr,
+                    reason: "reads from an address that changes"
+                }),
+                "{}",
+                path
+            );
+        }
+        let jump_table = assemble("in [t]\njt 1, [t]\nhlt\nt: .data 0").unwrap();
+        assert_eq!(
+            optimize(&jump_table).blocked,
+            Some(Blocker {
+                addr: 2,
+                reason: "jumps to an address that changes"
+            })
+        );
+        assert_eq!(
+            Blocker {
+                addr: 6,
+                reason: "writes to an address that changes"
+            }
+            .to_string(),
+            "6: writes to an address that changes"
+        );
+    }
+
+    #[test]
+    fn test_verify_reports_differences() {
+        let program = assemble("in [9]\nout [9]\nout 5\nhlt").unwrap();
+        let mut wrong = program.clone();
+        wrong[5] = 6;
+        let mismatch = verify(&program, &wrong, &[vec![1]], 100).unwrap_err();
+        assert_eq!(
+            mismatch.to_string(),
+            "input [1]: output [1, 5], optimized [1, 6]"
+        );
+
+        // A run that doesn't finish within the budget isn't compared.
+        let looping = assemble("l: jt 1, l").unwrap();
+        assert_eq!(verify(&looping, &looping, &[vec![]], 10), Ok(0));
+    }
+
+    #[test]
+    fn test_verify_random_programs() {
+        let mut rng = Rng::new(34);
+        let mut optimized_count = 0;
+        let examples: Vec<Vec<i64>> = [FOLD, THREAD, LOOP]
+            .iter()
+            .map(|source| assemble(source).unwrap())
+            .collect();
+        for i in 0..2000 {
+            // Mutated examples have more structure than random programs, so more of them can be
+            // optimized.
+            let program = if i % 2 == 0 {
+                random_program(&mut rng, 40)
+            } else {
+                let example = rng.pick(&examples).clone();
+                mutate(&mut rng, &example)
+            };
+            let optimized = optimize(&program);
+            if optimized.blocked.is_some() {
+                continue;
+            }
+            optimized_count += 1;
+            let inputs: Vec<Vec<i64>> = (0..4).map(|_| random_input(&mut rng, 4)).collect();
+            if let Err(mismatch) = verify(&program, &optimized.program, &inputs, 1000) {
+                panic!("{:?}\n{}\n{:?}", program, mismatch, optimized.changes);
+            }
+        }
+        assert!(optimized_count > 500);
+    }
+}
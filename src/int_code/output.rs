@@ -0,0 +1,230 @@
+//! Where an IntCodeComputer's output goes.
+//!
+//! The computer hands each value the program outputs to its OutputSink as soon as it is written.
+//! A DataStream, the default, keeps them for the caller to read between runs; the other sinks
+//! here pass them on to a closure or stdout, or group them into frames, such as the `(x, y, id)`
+//! tiles of a screen or the `(color, turn)` moves of a robot, and hand each frame to a closure.
+use std::convert::TryInto;
+use std::mem;
+
+use crate::int_code::{Cell, DataStream};
+
+pub trait OutputSink<T: Cell = i64> {
+    /// Called with each value the program outputs.
+    fn write(&mut self, value: T);
+
+    /// Called when the computer is reset. Sinks holding output of the old run drop it.
+    fn reset(&mut self) {}
+}
+
+impl<T: Cell> OutputSink<T> for DataStream<T> {
+    fn write(&mut self, value: T) {
+        DataStream::write(self, value)
+    }
+
+    fn reset(&mut self) {
+        DataStream::reset(self)
+    }
+}
+
+impl<T: Cell> OutputSink<T> for Vec<T> {
+    fn write(&mut self, value: T) {
+        self.push(value)
+    }
+
+    fn reset(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T: Cell, S: OutputSink<T> + ?Sized> OutputSink<T> for Box<S> {
+    fn write(&mut self, value: T) {
+        (**self).write(value)
+    }
+
+    fn reset(&mut self) {
+        (**self).reset()
+    }
+}
+
+/// A pair writes every value to both sinks.
+impl<T: Cell, A: OutputSink<T>, B: OutputSink<T>> OutputSink<T> for (A, B) {
+    fn write(&mut self, value: T) {
+        self.0.write(value.clone());
+        self.1.write(value);
+    }
+
+    fn reset(&mut self) {
+        self.0.reset();
+        self.1.reset();
+    }
+}
+
+/// ForEach calls a closure with each value. See for_each.
+pub struct ForEach<F>(F);
+
+/// Returns a sink that calls `f` with each value the program outputs.
+pub fn for_each<T: Cell, F: FnMut(T)>(f: F) -> ForEach<F> {
+    ForEach(f)
+}
+
+impl<T: Cell, F: FnMut(T)> OutputSink<T> for ForEach<F> {
+    fn write(&mut self, value: T) {
+        (self.0)(value)
+    }
+}
+
+/// Stdout prints each value on a line of its own. Like println, it panics if stdout is closed.
+pub struct Stdout;
+
+impl<T: Cell> OutputSink<T> for Stdout {
+    fn write(&mut self, value: T) {
+        println!("{}", value);
+    }
+}
+
+/// Frames collects values into frames of N and calls a closure with each one. See frames.
+pub struct Frames<T, F, const N: usize> {
+    buffer: Vec<T>,
+    handler: F,
+}
+
+/// Returns a sink that calls `handler` with every N values the program outputs, for example
+/// `frames(|[x, y, id]: [i64; 3]| ...)`.
+pub fn frames<T: Cell, F: FnMut([T; N]), const N: usize>(handler: F) -> Frames<T, F, N> {
+    Frames {
+        buffer: Vec::with_capacity(N),
+        handler,
+    }
+}
+
+impl<T, F, const N: usize> Frames<T, F, N> {
+    /// Returns the values of the frame that hasn't been completed yet.
+    pub fn partial(&self) -> &[T] {
+        &self.buffer
+    }
+}
+
+impl<T: Cell, F: FnMut([T; N]), const N: usize> OutputSink<T> for Frames<T, F, N> {
+    fn write(&mut self, value: T) {
+        self.buffer.push(value);
+        if self.buffer.len() == N {
+            let frame = mem::replace(&mut self.buffer, Vec::with_capacity(N));
+            (self.handler)(frame.try_into().unwrap());
+        }
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use crate::int_code::input::from_fn;
+    use crate::int_code::lang::compile;
+    use crate::int_code::output::{for_each, frames};
+    use crate::int_code::IntCodeComputer;
+
+    #[test]
+    fn test_screen_frames() {
+        let program = compile(
+            "
+            let y = 0;
+            while y < 2 {
+                let x = 0;
+                while x < 3 {
+                    output(x);
+                    output(y);
+                    output(x * 10 + y);
+                    x = x + 1;
+                }
+                y = y + 1;
+            }
+            output(7);
+            ",
+        )
+        .unwrap();
+        let mut screen = HashMap::new();
+        let mut computer =
+            IntCodeComputer::new(program).with_output(frames(|[x, y, id]: [i64; 3]| {
+                screen.insert((x, y), id);
+            }));
+        computer.run();
+        assert_eq!(computer.output.partial(), &[7]);
+        drop(computer);
+        assert_eq!(screen.len(), 6);
+        assert_eq!(screen[&(2, 1)], 21);
+    }
+
+    #[test]
+    fn test_robot_driven_by_events() {
+        // Paints each panel the opposite of its color and turns right, eight times: around a
+        // square twice.
+        let program = compile(
+            "
+            let n = 0;
+            while n < 8 {
+                output(1 - input());
+                output(1);
+                n = n + 1;
+            }
+            ",
+        )
+        .unwrap();
+        #[derive(Default)]
+        struct Robot {
+            at: (i64, i64),
+            facing: (i64, i64),
+            panels: HashMap<(i64, i64), i64>,
+            seen: Vec<i64>,
+        }
+        let robot = Rc::new(RefCell::new(Robot {
+            facing: (0, -1),
+            ..Robot::default()
+        }));
+        let (eyes, arms) = (robot.clone(), robot.clone());
+        let mut computer = IntCodeComputer::new(program)
+            .with_input(from_fn(move || {
+                let mut robot = eyes.borrow_mut();
+                let color = *robot.panels.get(&robot.at).unwrap_or(&0);
+                robot.seen.push(color);
+                Some(color)
+            }))
+            .with_output(frames(move |[color, turn]: [i64; 2]| {
+                let mut robot = arms.borrow_mut();
+                let at = robot.at;
+                robot.panels.insert(at, color);
+                let (dx, dy) = robot.facing;
+                robot.facing = if turn == 1 { (-dy, dx) } else { (dy, -dx) };
+                robot.at = (at.0 + robot.facing.0, at.1 + robot.facing.1);
+            }));
+        computer.run();
+        assert!(computer.is_halted());
+        let robot = robot.borrow();
+        assert_eq!(robot.seen, vec![0, 0, 0, 0, 1, 1, 1, 1]);
+        assert_eq!(robot.panels.len(), 4);
+        assert!(robot.panels.values().all(|c| *c == 0));
+        assert_eq!(robot.at, (0, 0));
+    }
+
+    #[test]
+    fn test_sinks() {
+        let program = vec![104, 1, 104, 2, 104, 3, 99];
+        let mut computer = IntCodeComputer::new(program.clone()).with_output(vec![]);
+        computer.run();
+        assert_eq!(computer.output, vec![1, 2, 3]);
+
+        let mut sum = 0;
+        let mut computer =
+            IntCodeComputer::new(program).with_output((vec![], for_each(|v| sum += v)));
+        computer.run();
+        assert_eq!(computer.output.0, vec![1, 2, 3]);
+        drop(computer);
+        assert_eq!(sum, 6);
+    }
+}
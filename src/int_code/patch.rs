@@ -0,0 +1,298 @@
+//! Patches change a program before it runs.
+//!
+//! A patch file has one change on each line, and `#` starts a comment:
+//!
+//! ```text
+//! # The 1202 program alarm.
+//! 1 = 12
+//! 2 = 2
+//! # Writes three values from 10 on, then zeros 20 to 29.
+//! 10 = 4, 5, 6
+//! 20..30 = 0
+//! # Replaces the instruction at 4.
+//! 4: jt 1, 40
+//! ```
+//!
+//! `ADDR = VALUES` writes comma separated values to ADDR and the cells after it. `START..END =
+//! VALUES` writes to the cells from START up to but not including END: one value fills them all,
+//! otherwise there has to be a value for each cell. `ADDR: CODE` assembles a line of the
+//! assembler's syntax at ADDR, so any labels it defines are addresses in the patched program.
+//!
+//! The changes are made in order. Writing past the end of the program extends it with zeros.
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::int_code::asm::{self, column};
+use crate::int_code::loader::{LoadError, ParseError};
+use crate::int_code::MAX_ADDRESS;
+
+enum Edit {
+    Write { addr: usize, values: Vec<i64> },
+    Fill { range: Range<usize>, value: i64 },
+}
+
+/// Patch is a list of changes to make to a program. It can be parsed from the format described
+/// in the module docs, or built up in code, as in `Patch::new().set(1, 12).set(2, 2)`.
+#[derive(Default)]
+pub struct Patch {
+    edits: Vec<Edit>,
+}
+
+impl Patch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the cell at `addr` to `value`.
+    pub fn set(self, addr: usize, value: i64) -> Self {
+        self.write(addr, &[value])
+    }
+
+    /// Writes `values` to `addr` and the cells after it.
+    pub fn write(mut self, addr: usize, values: &[i64]) -> Self {
+        self.edits.push(Edit::Write {
+            addr,
+            values: values.to_vec(),
+        });
+        self
+    }
+
+    /// Sets every cell in `range` to `value`.
+    pub fn fill(mut self, range: Range<usize>, value: i64) -> Self {
+        self.edits.push(Edit::Fill { range, value });
+        self
+    }
+
+    /// Assembles `code` at `addr`, writing over whatever was there.
+    pub fn assemble(self, addr: usize, code: &str) -> Result<Self, ParseError> {
+        let values = asm::assemble_at(code, addr)?;
+        Ok(self.write(addr, &values))
+    }
+
+    /// Makes the changes to `program`. A program can't address the cells past MAX_ADDRESS, so
+    /// any part of a change that falls past it is left out. Patches that are parsed never have
+    /// such changes: parse rejects them.
+    pub fn apply(&self, program: &mut Vec<i64>) {
+        let limit = MAX_ADDRESS as usize + 1;
+        for edit in self.edits.iter() {
+            let (start, end) = match edit {
+                Edit::Write { addr, values } => (*addr, addr.saturating_add(values.len())),
+                Edit::Fill { range, .. } => (range.start, range.end),
+            };
+            let range = start.min(limit)..end.min(limit);
+            if range.is_empty() {
+                continue;
+            }
+            if program.len() < range.end {
+                program.resize(range.end, 0);
+            }
+            match edit {
+                Edit::Write { values, .. } => {
+                    program[range.clone()].copy_from_slice(&values[..range.len()])
+                }
+                Edit::Fill { value, .. } => {
+                    for cell in program[range].iter_mut() {
+                        *cell = *value;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parses an address at the start of `text`, returning it and the rest of the text.
+fn parse_addr<'a>(
+    text: &'a str,
+    error: &dyn Fn(&str, String) -> ParseError,
+) -> Result<(usize, &'a str), ParseError> {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    if end == 0 {
+        return Err(error(text, "expected an address".to_string()));
+    }
+    match text[..end].parse::<usize>() {
+        Ok(addr) if addr as i64 <= MAX_ADDRESS => Ok((addr, text[end..].trim_start())),
+        _ => Err(error(
+            text,
+            format!("address {} is out of range", &text[..end]),
+        )),
+    }
+}
+
+/// Parses the comma separated values that make up the rest of a line.
+fn parse_list(
+    text: &str,
+    error: &dyn Fn(&str, String) -> ParseError,
+) -> Result<Vec<i64>, ParseError> {
+    text.split(',')
+        .map(|value| {
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(error(value, "expected a value".to_string()));
+            }
+            value
+                .parse()
+                .map_err(|_| error(value, format!("invalid value '{}'", value)))
+        })
+        .collect()
+}
+
+/// Parses a patch in the format described in the module docs.
+pub fn parse(text: &str) -> Result<Patch, ParseError> {
+    let mut patch = Patch::new();
+    for (i, line) in text.lines().enumerate() {
+        let error =
+            |part: &str, message: String| ParseError::new(i + 1, column(line, part), message);
+        let rest = line.split('#').next().unwrap().trim();
+        if rest.is_empty() {
+            continue;
+        }
+        let (addr, rest) = parse_addr(rest, &error)?;
+        if let Some(code) = rest.strip_prefix(':') {
+            let code = code.trim_start();
+            // The assembler counts columns from the start of the code.
+            patch = patch.assemble(addr, code).map_err(|e| {
+                ParseError::new(i + 1, column(line, code) + e.column - 1, e.message)
+            })?;
+            continue;
+        }
+        let (range, rest) = match rest.strip_prefix("..") {
+            Some(end) => {
+                let (end, rest) = parse_addr(end.trim_start(), &error)?;
+                if end <= addr {
+                    return Err(error(line.trim_start(), "the range is empty".to_string()));
+                }
+                (Some(addr..end), rest)
+            }
+            None => (None, rest),
+        };
+        let values = match rest.strip_prefix('=') {
+            Some(values) => parse_list(values, &error)?,
+            None => return Err(error(rest, "expected '='".to_string())),
+        };
+        patch = match range {
+            None if addr + values.len() - 1 > MAX_ADDRESS as usize => {
+                return Err(error(rest, "values run past the end of memory".to_string()))
+            }
+            None => patch.write(addr, &values),
+            Some(range) if values.len() == 1 => patch.fill(range, values[0]),
+            Some(range) if values.len() == range.len() => patch.write(addr, &values),
+            Some(range) => {
+                return Err(error(
+                    rest,
+                    format!(
+                        "{}..{} takes {} values but {} were given",
+                        range.start,
+                        range.end,
+                        range.len(),
+                        values.len()
+                    ),
+                ))
+            }
+        };
+    }
+    Ok(patch)
+}
+
+/// Reads a patch file.
+pub fn load(path: impl AsRef<Path>) -> Result<Patch, LoadError> {
+    let bytes = fs::read(path)?;
+    let text = String::from_utf8(bytes).map_err(|_| LoadError::Encoding)?;
+    Ok(parse(&text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::patch::{load, parse, Patch};
+    use crate::int_code::{IntCodeComputer, MAX_ADDRESS};
+    use crate::util;
+
+    #[test]
+    fn test_alarm() {
+        let mut program = util::read_int_code_memory("./input/day02.txt");
+        load("./input/day02_alarm.patch")
+            .unwrap()
+            .apply(&mut program);
+        assert_eq!(&program[..3], &[1, 12, 2]);
+        let mut computer = IntCodeComputer::new(program);
+        computer.run();
+        assert_eq!(computer.memory().read(0), 4484226);
+    }
+
+    #[test]
+    fn test_changes() {
+        let patch = parse(
+            "
+            # Comments and blank lines are skipped.
+            1 = 7
+            3 = 1, 2, 3
+            5..8 = 9   # overwrites the 3
+            10..12 = -1, -2
+            ",
+        )
+        .unwrap();
+        let mut program = vec![0; 4];
+        patch.apply(&mut program);
+        assert_eq!(program, vec![0, 7, 0, 1, 2, 9, 9, 9, 0, 0, -1, -2]);
+    }
+
+    #[test]
+    fn test_assembled_code() {
+        // Outputs the input rather than its double, looping back through a label.
+        let mut program = vec![3, 9, 1002, 9, 2, 9, 4, 9, 99, 0];
+        parse("2: add [9], 0, [9]").unwrap().apply(&mut program);
+        assert_eq!(&program[..6], &[3, 9, 1001, 9, 0, 9]);
+        let mut computer = IntCodeComputer::new(program.clone());
+        computer.input.write(21);
+        computer.run();
+        assert_eq!(computer.drain_output(), &[21]);
+
+        // Labels are addresses in the patched program.
+        parse("12: loop: jt 1, loop").unwrap().apply(&mut program);
+        assert_eq!(&program[10..], &[0, 0, 1105, 1, 12]);
+
+        let mut built = vec![0; 3];
+        Patch::new()
+            .fill(0..3, 1)
+            .assemble(1, "out 5")
+            .unwrap()
+            .apply(&mut built);
+        assert_eq!(built, vec![1, 104, 5]);
+    }
+
+    #[test]
+    fn test_past_max_address() {
+        let max = MAX_ADDRESS as usize;
+        let mut program = vec![];
+        Patch::new()
+            .write(max - 1, &[1, 2, 3])
+            .fill(max..usize::MAX, 4)
+            .set(usize::MAX, 5)
+            .write(usize::MAX - 1, &[6, 7, 8])
+            .apply(&mut program);
+        assert_eq!(program.len(), max + 1);
+        assert_eq!(&program[max - 2..], &[0, 1, 4]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let cases = vec![
+            ("x = 1", "1:1: expected an address"),
+            ("1 2", "1:3: expected '='"),
+            ("1 = 2,", "1:7: expected a value"),
+            ("\n  1 = two", "2:7: invalid value 'two'"),
+            ("5..5 = 0", "1:1: the range is empty"),
+            ("0..3 = 1, 2", "1:6: 0..3 takes 3 values but 2 were given"),
+            ("2000000 = 1", "1:1: address 2000000 is out of range"),
+            ("1048576 = 1, 2", "1:9: values run past the end of memory"),
+            ("4: add 1, 2", "1:4: add takes 3 operands but 2 were given"),
+            ("4:  jt 1, nowhere", "1:11: undefined label 'nowhere'"),
+        ];
+        for (text, msg) in cases {
+            let error = parse(text).err().expect(text);
+            assert_eq!(error.to_string(), msg, "{:?}", text);
+        }
+    }
+}
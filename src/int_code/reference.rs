@@ -0,0 +1,491 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::int_code::{ErrorKind, IntCodeComputer, RunOutcome, MAX_ADDRESS};
+
+/// Step is what happened when the reference interpreter executed one instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Step {
+    /// The instruction ran. If it wrote to memory, `wrote` holds the address and the new value.
+    Ran {
+        wrote: Option<(u64, i64)>,
+    },
+    /// The instruction is an input and there is nothing to read. Nothing changed.
+    WaitingForInput,
+    Halted,
+    Crashed(ErrorKind),
+}
+
+/// ReferenceComputer is a second Intcode interpreter, written to be obviously correct rather than
+/// fast. It exists to check IntCodeComputer against: the two should agree on every step of every
+/// program. Keep it simple; any cleverness belongs in IntCodeComputer.
+///
+/// Where the puzzle leaves behaviour open, it does what IntCodeComputer does: memory is unbounded
+/// (up to the same address limit) and reads as 0 past the end, a write parameter in immediate mode
+/// is treated as an address, and arithmetic that overflows an i64 is an error.
+pub struct ReferenceComputer {
+    pub memory: Vec<i64>,
+    pub ptr: u64,
+    pub relative_base: i64,
+    pub input: VecDeque<i64>,
+    pub output: Vec<i64>,
+    pub halted: bool,
+}
+
+impl ReferenceComputer {
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            memory: program,
+            ptr: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: vec![],
+            halted: false,
+        }
+    }
+
+    pub fn read(&self, addr: u64) -> i64 {
+        if (addr as usize) < self.memory.len() {
+            self.memory[addr as usize]
+        } else {
+            0
+        }
+    }
+
+    fn write(&mut self, addr: u64, value: i64) {
+        if addr as usize >= self.memory.len() {
+            self.memory.resize(addr as usize + 1, 0);
+        }
+        self.memory[addr as usize] = value;
+    }
+
+    /// Returns the mode of the nth parameter (counting from 1) of the current instruction.
+    fn mode(&self, n: u32) -> Result<i64, ErrorKind> {
+        let mode = self.read(self.ptr) / 10i64.pow(n + 1) % 10;
+        if mode == 0 || mode == 1 || mode == 2 {
+            Ok(mode)
+        } else {
+            Err(ErrorKind::InvalidParamMode(mode))
+        }
+    }
+
+    /// Returns the address that the nth parameter refers to.
+    fn address(&self, n: u32) -> Result<u64, ErrorKind> {
+        let raw = self.read(self.ptr + n as u64);
+        let addr = if self.mode(n)? == 2 {
+            raw.checked_add(self.relative_base)
+                .ok_or(ErrorKind::Overflow)?
+        } else {
+            raw
+        };
+        if addr < 0 {
+            return Err(ErrorKind::NegativeAddress(addr));
+        }
+        if addr > MAX_ADDRESS {
+            return Err(ErrorKind::AddressOutOfRange(addr));
+        }
+        Ok(addr as u64)
+    }
+
+    /// Returns the value of the nth parameter.
+    fn value(&self, n: u32) -> Result<i64, ErrorKind> {
+        if self.mode(n)? == 1 {
+            return Ok(self.read(self.ptr + n as u64));
+        }
+        Ok(self.read(self.address(n)?))
+    }
+
+    /// Executes one instruction.
+    pub fn step(&mut self) -> Step {
+        if self.halted {
+            return Step::Halted;
+        }
+        match self.try_step() {
+            Ok(step) => step,
+            Err(kind) => Step::Crashed(kind),
+        }
+    }
+
+    fn try_step(&mut self) -> Result<Step, ErrorKind> {
+        let instruction = self.read(self.ptr);
+        let op_code = instruction % 100;
+        // The mode of every parameter must be valid before anything runs, even if the instruction
+        // stops early, as an input with nothing to read does.
+        let params = match op_code {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            3 | 4 | 9 => 1,
+            99 => 0,
+            _ => return Err(ErrorKind::InvalidOpcode(instruction)),
+        };
+        for n in 1..=params {
+            self.mode(n)?;
+        }
+        match op_code {
+            1 | 2 | 7 | 8 => {
+                let a = self.value(1)?;
+                let b = self.value(2)?;
+                let dest = self.address(3)?;
+                let result = match op_code {
+                    1 => a.checked_add(b).ok_or(ErrorKind::Overflow)?,
+                    2 => a.checked_mul(b).ok_or(ErrorKind::Overflow)?,
+                    7 => (a < b) as i64,
+                    _ => (a == b) as i64,
+                };
+                self.write(dest, result);
+                self.ptr += 4;
+                Ok(Step::Ran {
+                    wrote: Some((dest, result)),
+                })
+            }
+            3 => {
+                let value = match self.input.front() {
+                    Some(v) => *v,
+                    None => return Ok(Step::WaitingForInput),
+                };
+                let dest = self.address(1)?;
+                self.input.pop_front();
+                self.write(dest, value);
+                self.ptr += 2;
+                Ok(Step::Ran {
+                    wrote: Some((dest, value)),
+                })
+            }
+            4 => {
+                let value = self.value(1)?;
+                self.output.push(value);
+                self.ptr += 2;
+                Ok(Step::Ran { wrote: None })
+            }
+            5 | 6 => {
+                let condition = self.value(1)?;
+                let target = self.value(2)?;
+                let jump = if op_code == 5 {
+                    condition != 0
+                } else {
+                    condition == 0
+                };
+                if jump {
+                    if target < 0 {
+                        return Err(ErrorKind::NegativeAddress(target));
+                    }
+                    if target > MAX_ADDRESS {
+                        return Err(ErrorKind::AddressOutOfRange(target));
+                    }
+                    self.ptr = target as u64;
+                } else {
+                    self.ptr += 3;
+                }
+                Ok(Step::Ran { wrote: None })
+            }
+            9 => {
+                let offset = self.value(1)?;
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or(ErrorKind::Overflow)?;
+                self.ptr += 2;
+                Ok(Step::Ran { wrote: None })
+            }
+            _ => {
+                self.halted = true;
+                Ok(Step::Halted)
+            }
+        }
+    }
+}
+
+/// Agreement is the result of a differential run in which both computers did the same thing at
+/// every step.
+#[derive(Debug)]
+pub struct Agreement {
+    /// The number of instructions both computers executed.
+    pub steps: u64,
+    /// How the run ended. Step::Ran means the budget ran out with both computers still running.
+    pub end: Step,
+    pub output: Vec<i64>,
+}
+
+/// Divergence describes the first step at which IntCodeComputer and ReferenceComputer disagreed.
+#[derive(Debug)]
+pub struct Divergence {
+    pub step: u64,
+    /// The address of the instruction that was executed when they disagreed.
+    pub ptr: u64,
+    pub reason: String,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "step {} (instruction at {}): {}",
+            self.step, self.ptr, self.reason
+        )
+    }
+}
+
+/// Runs the program on IntCodeComputer and ReferenceComputer side by side, one instruction at a
+/// time, for at most `budget` instructions. After every instruction it checks that both computers
+/// are in the same state: the same instruction pointer and relative base, the same value in any
+/// cell that was written, the same outputs, and the same halting, waiting or error state. When the
+/// run ends it compares all of memory as well, to catch stray writes.
+pub fn compare(program: &[i64], input: &[i64], budget: u64) -> Result<Agreement, Divergence> {
+    let mut computer = IntCodeComputer::new(program.to_vec());
+    let mut reference = ReferenceComputer::new(program.to_vec());
+    for v in input {
+        computer.input.write(*v);
+        reference.input.push_back(*v);
+    }
+    let mut steps = 0;
+    let end = loop {
+        let ptr = reference.ptr;
+        let diverged = |reason: String| Divergence {
+            step: steps,
+            ptr,
+            reason,
+        };
+        if steps == budget {
+            break Step::Ran { wrote: None };
+        }
+        let expected = reference.step();
+        let actual = match computer.run_with_budget(1) {
+            Ok(RunOutcome::BudgetExhausted) => Step::Ran {
+                wrote: expected_write(&expected),
+            },
+            Ok(RunOutcome::WaitingForInput) => Step::WaitingForInput,
+            Ok(RunOutcome::Halted) => Step::Halted,
+            Err(e) => Step::Crashed(e.kind),
+        };
+        if actual != expected {
+            return Err(diverged(format!(
+                "expected {:?}, IntCodeComputer gave {:?}",
+                expected, actual
+            )));
+        }
+        if computer.ptr != reference.ptr {
+            return Err(diverged(format!(
+                "expected ptr {}, IntCodeComputer has {}",
+                reference.ptr, computer.ptr
+            )));
+        }
+        if computer.rel_pos != reference.relative_base {
+            return Err(diverged(format!(
+                "expected relative base {}, IntCodeComputer has {}",
+                reference.relative_base, computer.rel_pos
+            )));
+        }
+        if let Step::Ran {
+            wrote: Some((addr, value)),
+        } = expected
+        {
+            let got = computer.memory.read(addr);
+            if got != value {
+                return Err(diverged(format!(
+                    "expected {} at address {}, IntCodeComputer has {}",
+                    value, addr, got
+                )));
+            }
+        }
+        if computer.output.producer_ind != reference.output.len() as u64 {
+            return Err(diverged(format!(
+                "expected {} outputs, IntCodeComputer has {}",
+                reference.output.len(),
+                computer.output.producer_ind
+            )));
+        }
+        if let (Some(value), Some(last)) = (
+            reference.output.last(),
+            computer.output.producer_ind.checked_sub(1),
+        ) {
+            let got = computer.output.buffer.read(last);
+            if got != *value {
+                return Err(diverged(format!(
+                    "expected output {}, IntCodeComputer gave {}",
+                    value, got
+                )));
+            }
+        }
+        match expected {
+            Step::Ran { .. } => steps += 1,
+            end => break end,
+        }
+    };
+    let len = computer.memory.len().max(reference.memory.len()) as u64;
+    for addr in 0..len {
+        let (got, value) = (computer.memory.read(addr), reference.read(addr));
+        if got != value {
+            return Err(Divergence {
+                step: steps,
+                ptr: reference.ptr,
+                reason: format!(
+                    "at the end of the run, expected {} at address {}, IntCodeComputer has {}",
+                    value, addr, got
+                ),
+            });
+        }
+    }
+    Ok(Agreement {
+        steps,
+        end,
+        output: reference.output,
+    })
+}
+
+/// IntCodeComputer doesn't report which cell an instruction wrote, so the comparison takes the
+/// reference's word for it and then checks that cell separately.
+fn expected_write(step: &Step) -> Option<(u64, i64)> {
+    match step {
+        Step::Ran { wrote } => *wrote,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::int_code::fuzz::{self, Rng};
+    use crate::int_code::reference::{compare, Divergence, ReferenceComputer, Step};
+    use crate::int_code::ErrorKind;
+
+    fn run(program: Vec<i64>, input: &[i64]) -> ReferenceComputer {
+        let mut computer = ReferenceComputer::new(program);
+        computer.input.extend(input.iter());
+        while let Step::Ran { .. } = computer.step() {}
+        computer
+    }
+
+    #[test]
+    fn test_day02_example() {
+        let computer = run(vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50], &[]);
+        assert!(computer.halted);
+        assert_eq!(computer.memory[0], 3500);
+    }
+
+    #[test]
+    fn test_io_and_relative_base() {
+        let quine = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        assert_eq!(run(quine.clone(), &[]).output, quine);
+        assert_eq!(run(vec![3, 0, 4, 0, 99], &[7]).output, vec![7]);
+    }
+
+    #[test]
+    fn test_waits_and_crashes() {
+        let mut computer = ReferenceComputer::new(vec![3, 0, 99]);
+        assert_eq!(computer.step(), Step::WaitingForInput);
+        assert_eq!(computer.ptr, 0);
+        let mut computer = ReferenceComputer::new(vec![301, 0, 0, 0, 99]);
+        assert_eq!(
+            computer.step(),
+            Step::Crashed(ErrorKind::InvalidParamMode(3))
+        );
+    }
+
+    /// Returns the puzzle inputs under input/ that are Intcode programs.
+    fn puzzle_programs() -> Vec<(String, Vec<i64>)> {
+        let mut programs = vec![];
+        for entry in fs::read_dir("./input").expect("no input directory") {
+            let path = entry.unwrap().path();
+            let text = fs::read_to_string(&path).unwrap();
+            let line = text.lines().next().unwrap_or("");
+            if !line.contains(',') {
+                continue;
+            }
+            let program: Result<Vec<i64>, _> = line.split(',').map(|v| v.trim().parse()).collect();
+            if let Ok(program) = program {
+                programs.push((path.display().to_string(), program));
+            }
+        }
+        programs.sort();
+        programs
+    }
+
+    fn assert_agree(program: &[i64], input: &[i64], budget: u64) -> Step {
+        match compare(program, input, budget) {
+            Ok(agreement) => agreement.end,
+            Err(d) => panic!("{}\nprogram: {:?}\ninput: {:?}", d, program, input),
+        }
+    }
+
+    #[test]
+    fn test_compare_puzzle_inputs() {
+        let programs = puzzle_programs();
+        // Days 2, 5, 7 and 9.
+        assert_eq!(programs.len(), 4);
+        for (name, program) in programs.iter() {
+            // Covers the diagnostic modes of days 5 and 9 and a day 7 amplifier.
+            for input in &[vec![], vec![1], vec![2], vec![5], vec![3, 0]] {
+                let end = assert_agree(program, input, 1_000_000);
+                assert!(
+                    end != (Step::Ran { wrote: None }),
+                    "{} ran out of budget",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_unit_test_programs() {
+        let programs = vec![
+            vec![1, 0, 0, 0, 99],
+            vec![2, 3, 0, 3, 99],
+            vec![2, 4, 4, 5, 99, 0],
+            vec![1, 1, 1, 4, 99, 5, 6, 0, 99],
+            vec![1002, 4, 3, 4, 33],
+            vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8],
+            vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8],
+            vec![3, 3, 1108, -1, 8, 3, 4, 3, 99],
+            vec![3, 3, 1107, -1, 8, 3, 4, 3, 99],
+            vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9],
+            vec![3, 0, 99],
+            vec![
+                3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36,
+                98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000,
+                1, 20, 4, 20, 1105, 1, 46, 98, 99,
+            ],
+            vec![
+                109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+            ],
+            vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0],
+            vec![104, 1125899906842624, 99],
+        ];
+        for program in programs.iter() {
+            for input in &[vec![], vec![0], vec![7], vec![8], vec![9]] {
+                assert_agree(program, input, 10_000);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_generated_programs() {
+        let mut rng = Rng::new(29);
+        let seeds: Vec<Vec<i64>> = puzzle_programs().into_iter().map(|(_, p)| p).collect();
+        for _ in 0..2000 {
+            let program = if rng.chance(25) {
+                let seed = rng.pick(&seeds).clone();
+                fuzz::mutate(&mut rng, &seed)
+            } else {
+                fuzz::random_program(&mut rng, 64)
+            };
+            let input = fuzz::random_input(&mut rng, 4);
+            assert_agree(&program, &input, 10_000);
+        }
+    }
+
+    #[test]
+    fn test_divergence_display() {
+        let d = Divergence {
+            step: 3,
+            ptr: 4,
+            reason: "expected Halted, IntCodeComputer gave WaitingForInput".to_string(),
+        };
+        assert_eq!(
+            d.to_string(),
+            "step 3 (instruction at 4): expected Halted, IntCodeComputer gave WaitingForInput"
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Searches over the parameters of an Intcode program, run across threads.
+//!
+//! A search takes a program, the parameters to try and a function that runs the program for one
+//! of them: the noun and verb to patch in on day 2, say, or the phase settings of the day 7
+//! amplifiers. Each thread takes the next parameters to try until there are none left, running
+//! them on computers of its own that are reset rather than made anew for every evaluation.
+//! Searching for the first match stops taking parameters as soon as one is found.
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::int_code::IntCodeComputer;
+
+/// Workbench holds the computers one thread runs the program on.
+pub struct Workbench<'a> {
+    program: &'a [i64],
+    computers: Vec<IntCodeComputer>,
+    /// The program with the last patches applied, which the first computer is loaded with.
+    scratch: Vec<i64>,
+    patched: bool,
+}
+
+impl<'a> Workbench<'a> {
+    fn new(program: &'a [i64]) -> Self {
+        Self {
+            program,
+            computers: vec![],
+            scratch: vec![],
+            patched: false,
+        }
+    }
+
+    /// Returns `n` computers loaded with the program, as if they had just been made.
+    pub fn computers(&mut self, n: usize) -> &mut [IntCodeComputer] {
+        while self.computers.len() < n {
+            self.computers
+                .push(IntCodeComputer::new(self.program.to_vec()));
+        }
+        for computer in self.computers[..n].iter_mut() {
+            computer.reset();
+        }
+        if self.patched && n > 0 {
+            self.computers[0].reset_with(self.program);
+            self.patched = false;
+        }
+        &mut self.computers[..n]
+    }
+
+    /// Returns a computer loaded with the program.
+    pub fn computer(&mut self) -> &mut IntCodeComputer {
+        &mut self.computers(1)[0]
+    }
+
+    /// Returns a computer loaded with the program after writing each `(addr, value)` of
+    /// `patches` to it.
+    pub fn patched(&mut self, patches: &[(usize, i64)]) -> &mut IntCodeComputer {
+        // reset_with below resets the computer anyway, so it is taken as it is.
+        if self.computers.is_empty() {
+            self.computers
+                .push(IntCodeComputer::new(self.program.to_vec()));
+        }
+        self.scratch.clear();
+        self.scratch.extend_from_slice(self.program);
+        for &(addr, value) in patches {
+            if addr >= self.scratch.len() {
+                self.scratch.resize(addr + 1, 0);
+            }
+            self.scratch[addr] = value;
+        }
+        self.patched = true;
+        let computer = &mut self.computers[0];
+        computer.reset_with(&self.scratch);
+        computer
+    }
+}
+
+/// Search runs a program for each of a set of parameters across a number of threads.
+pub struct Search<'a> {
+    program: &'a [i64],
+    threads: usize,
+}
+
+impl<'a> Search<'a> {
+    /// Returns a search over `program` that uses a thread for each CPU.
+    pub fn new(program: &'a [i64]) -> Self {
+        Self {
+            program,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    /// Sets the number of threads to run the program on.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Returns the first of `params`, in the order they come, for which `accept` returns a value,
+    /// together with that value. Once one has been found, the threads stop taking parameters that
+    /// come after it, but finish the ones before it that they have started.
+    pub fn find_first<P, R, I, F>(&self, params: I, accept: F) -> Option<(P, R)>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send,
+        P: Send,
+        R: Send,
+        F: Fn(&mut Workbench, &P) -> Option<R> + Sync,
+    {
+        self.evaluate(params.into_iter(), accept, true)
+            .into_iter()
+            .min_by_key(|(i, _, _)| *i)
+            .map(|(_, p, r)| (p, r))
+    }
+
+    /// Returns the parameters for which `objective` returns the largest value, together with the
+    /// value. Of parameters with the same value, the one that comes first wins.
+    pub fn max_by_key<P, K, I, F>(&self, params: I, objective: F) -> Option<(P, K)>
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send,
+        P: Send,
+        K: Ord + Send,
+        F: Fn(&mut Workbench, &P) -> K + Sync,
+    {
+        self.evaluate(
+            params.into_iter(),
+            |bench, p| Some(objective(bench, p)),
+            false,
+        )
+        .into_iter()
+        .max_by(|a, b| a.2.cmp(&b.2).then(b.0.cmp(&a.0)))
+        .map(|(_, p, k)| (p, k))
+    }
+
+    /// Runs `eval` for the parameters across the threads, returning those it returned a value
+    /// for with their position. With `stop_at_match`, no parameters after the first match found
+    /// are started.
+    fn evaluate<P, R, I, F>(&self, params: I, eval: F, stop_at_match: bool) -> Vec<(usize, P, R)>
+    where
+        I: Iterator<Item = P> + Send,
+        P: Send,
+        R: Send,
+        F: Fn(&mut Workbench, &P) -> Option<R> + Sync,
+    {
+        let params = Mutex::new(params.enumerate());
+        let first_match = AtomicUsize::new(usize::MAX);
+        thread::scope(|s| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut bench = Workbench::new(self.program);
+                        let mut found = vec![];
+                        loop {
+                            let next = params.lock().unwrap().next();
+                            // Parameters are taken in order, so once one comes after a match,
+                            // all the rest do too.
+                            let (i, p) = match next {
+                                Some((i, _)) if i > first_match.load(Ordering::Relaxed) => break,
+                                Some(next) => next,
+                                None => break,
+                            };
+                            if let Some(r) = eval(&mut bench, &p) {
+                                if stop_at_match {
+                                    first_match.fetch_min(i, Ordering::Relaxed);
+                                }
+                                found.push((i, p, r));
+                            }
+                        }
+                        found
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|w| w.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::int_code::search::{Search, Workbench};
+    use crate::util;
+
+    /// Outputs its input plus the value in cell 10.
+    const ADDER: [i64; 12] = [3, 9, 1, 9, 10, 11, 4, 11, 99, 0, 0, 0];
+
+    #[test]
+    fn test_find_first() {
+        let program = ADDER;
+        let evaluated = AtomicUsize::new(0);
+        for threads in 1..5 {
+            evaluated.store(0, Ordering::Relaxed);
+            let found =
+                Search::new(&program)
+                    .threads(threads)
+                    .find_first(0..1_000_000i64, |bench, p| {
+                        evaluated.fetch_add(1, Ordering::Relaxed);
+                        let computer = bench.patched(&[(10, *p)]);
+                        computer.input.write(*p);
+                        computer.run();
+                        let sum = *computer.last_output().unwrap();
+                        if sum % 7 == 3 && sum > 100 {
+                            Some(sum)
+                        } else {
+                            None
+                        }
+                    });
+            assert_eq!(found, Some((54, 108)));
+            // The search stops soon after the match rather than trying every parameter.
+            assert!(evaluated.load(Ordering::Relaxed) < 1000);
+        }
+        assert_eq!(
+            Search::new(&program).find_first(0..100, |_, _| None::<()>),
+            None
+        );
+    }
+
+    #[test]
+    fn test_max_by_key() {
+        // Every parameter scores the same, so the first one wins.
+        let program = ADDER;
+        let best = Search::new(&program)
+            .threads(4)
+            .max_by_key(0..50, |bench, _| {
+                let computer = bench.computer();
+                computer.input.write(1);
+                computer.run();
+                *computer.last_output().unwrap()
+            });
+        assert_eq!(best, Some((0, 1)));
+    }
+
+    #[test]
+    fn test_patches_do_not_leak() {
+        let mut bench = Workbench::new(&ADDER);
+        assert_eq!(bench.patched(&[(10, 100), (20, 1)]).memory().read(10), 100);
+        let computer = bench.computer();
+        assert_eq!(computer.memory().read(10), 0);
+        assert_eq!(computer.memory().read(20), 0);
+        assert_eq!(bench.computers(3).len(), 3);
+    }
+
+    #[test]
+    fn test_day02() {
+        let program = util::read_int_code_memory("./input/day02.txt");
+        let params = (0..100).flat_map(|noun| (0..100).map(move |verb| (noun, verb)));
+        let found = Search::new(&program).find_first(params, |bench, &(noun, verb)| {
+            let computer = bench.patched(&[(1, noun), (2, verb)]);
+            computer.run();
+            Some(computer.memory().read(0)).filter(|v| *v == 19690720)
+        });
+        assert_eq!(found, Some(((56, 96), 19690720)));
+    }
+
+    #[test]
+    fn test_day07() {
+        let program = util::read_int_code_memory("./input/day07.txt");
+        let best = Search::new(&program).max_by_key(util::Permutations::new(5), |bench, phases| {
+            let amps = bench.computers(5);
+            for (amp, phase) in amps.iter_mut().zip(phases) {
+                amp.input.write(5 + *phase as i64);
+            }
+            let mut signal = 0;
+            while !amps.iter().all(|amp| amp.is_halted()) {
+                for amp in amps.iter_mut() {
+                    amp.input.write(signal);
+                    amp.run();
+                    signal = amp.drain_output()[0];
+                }
+            }
+            signal
+        });
+        assert_eq!(best.unwrap().1, 17519904);
+    }
+}
@@ -0,0 +1,869 @@
+//! Symbolic (concolic) execution of Intcode programs.
+//!
+//! Chosen memory cells and input values are treated as symbols. Running the program builds an
+//! expression for every value it computes, and every jump whose condition depends on a symbol
+//! records which way it went as a path constraint. The run itself follows the concrete values of
+//! a model, so it never has to fork. Afterwards a small linear solver looks for symbol values that
+//! keep to the same path and make a goal hold, and if none exist it flips recorded branches to
+//! explore other paths.
+//!
+//! Non-linear values (the product of two symbolic values, a comparison used as a number, or a read
+//! through a symbolic address) are concretized when a constraint needs them: the solver is told to
+//! keep them at the value they had in the run. This keeps every constraint linear at the cost of
+//! only finding solutions close to the explored path.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::mem;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use crate::int_code::{
+    parse_instruction, ErrorKind, Instruction, IntCodeError, ParamMode, MAX_ADDRESS,
+};
+
+mod solver;
+
+pub use solver::{Constraint, Domain, Linear, Relation};
+
+/// The most instructions a single symbolic run will execute.
+const MAX_STEPS_PER_RUN: u64 = 1_000_000;
+
+/// Symbol identifies an unknown value. Its value in a Solution is read out with Solution::value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Symbol(usize);
+
+/// Expr is the symbolic value of a memory cell, input or output.
+#[derive(Clone, Debug)]
+pub enum Expr {
+    Const(i64),
+    Symbol(Symbol),
+    Add(Rc<Expr>, Rc<Expr>),
+    Mul(Rc<Expr>, Rc<Expr>),
+    LessThan(Rc<Expr>, Rc<Expr>),
+    Equals(Rc<Expr>, Rc<Expr>),
+    /// A read through a symbolic address. `at` is the address it resolved to during the run and
+    /// `value` is what was stored there.
+    Load {
+        addr: Rc<Expr>,
+        at: i64,
+        value: Rc<Expr>,
+    },
+}
+
+impl Expr {
+    /// Folds constants unless the sum overflows, which the machine reports before it gets here.
+    fn add(a: &Expr, b: &Expr) -> Expr {
+        match (a, b) {
+            (Expr::Const(x), Expr::Const(y)) if x.checked_add(*y).is_some() => Expr::Const(x + y),
+            (Expr::Const(0), e) | (e, Expr::Const(0)) => e.clone(),
+            _ => Expr::Add(Rc::new(a.clone()), Rc::new(b.clone())),
+        }
+    }
+
+    fn mul(a: &Expr, b: &Expr) -> Expr {
+        match (a, b) {
+            (Expr::Const(x), Expr::Const(y)) if x.checked_mul(*y).is_some() => Expr::Const(x * y),
+            (Expr::Const(0), _) | (_, Expr::Const(0)) => Expr::Const(0),
+            (Expr::Const(1), e) | (e, Expr::Const(1)) => e.clone(),
+            _ => Expr::Mul(Rc::new(a.clone()), Rc::new(b.clone())),
+        }
+    }
+
+    fn less_than(a: &Expr, b: &Expr) -> Expr {
+        match (a, b) {
+            (Expr::Const(x), Expr::Const(y)) => Expr::Const((x < y) as i64),
+            _ => Expr::LessThan(Rc::new(a.clone()), Rc::new(b.clone())),
+        }
+    }
+
+    fn equals(a: &Expr, b: &Expr) -> Expr {
+        match (a, b) {
+            (Expr::Const(x), Expr::Const(y)) => Expr::Const((x == y) as i64),
+            _ => Expr::Equals(Rc::new(a.clone()), Rc::new(b.clone())),
+        }
+    }
+
+    pub fn as_const(&self) -> Option<i64> {
+        match self {
+            Expr::Const(c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the expression when every symbol takes its value from the model, or
+    /// None if working it out overflows.
+    pub fn eval(&self, model: &[i64]) -> Option<i64> {
+        // A loop that keeps adding to a cell builds a chain of Adds far deeper than the call stack
+        // allows, so the expression is walked with a stack of its own.
+        let mut work = vec![Walk::Visit(self)];
+        let mut values = vec![];
+        while let Some(step) = work.pop() {
+            match step {
+                Walk::Visit(e) => match e {
+                    Expr::Const(c) => values.push(*c),
+                    Expr::Symbol(s) => values.push(model[s.0]),
+                    Expr::Load { value, .. } => work.push(Walk::Visit(value)),
+                    Expr::Add(a, b)
+                    | Expr::Mul(a, b)
+                    | Expr::LessThan(a, b)
+                    | Expr::Equals(a, b) => {
+                        work.extend([Walk::Apply(e), Walk::Visit(b), Walk::Visit(a)]);
+                    }
+                },
+                Walk::Apply(e) => {
+                    let (b, a) = (values.pop()?, values.pop()?);
+                    values.push(match e {
+                        Expr::Add(..) => a.checked_add(b)?,
+                        Expr::Mul(..) => a.checked_mul(b)?,
+                        Expr::LessThan(..) => (a < b) as i64,
+                        _ => (a == b) as i64,
+                    });
+                }
+                Walk::Pin(_) => unreachable!("eval doesn't pin"),
+            }
+        }
+        values.pop()
+    }
+
+    /// Returns the value of the expression in the model it was built from, where every Add and Mul
+    /// was checked for overflow when the program ran it.
+    fn value(&self, model: &[i64]) -> i64 {
+        self.eval(model)
+            .expect("the value was checked for overflow when it was computed")
+    }
+
+    /// Converts the expression into a linear form. Any non-linear part is pinned to its value in
+    /// the model, and the constraint that pins it is pushed onto `side`. So is any part whose
+    /// coefficients would no longer fit in an i64, so that they can't overflow the solver.
+    fn linearize(&self, model: &[i64], side: &mut Vec<Constraint>) -> Linear {
+        let pin = |side: &mut Vec<Constraint>, l: &Linear, e: &Expr| {
+            side.push(Constraint::eq(l, &Linear::constant(e.value(model) as i128)));
+        };
+        // Walked with a stack of its own, like eval.
+        let mut work = vec![Walk::Visit(self)];
+        let mut linears: Vec<Linear> = vec![];
+        while let Some(step) = work.pop() {
+            match step {
+                Walk::Visit(e) => match e {
+                    Expr::Const(c) => linears.push(Linear::constant(*c as i128)),
+                    Expr::Symbol(s) => linears.push(Linear::symbol(s.0)),
+                    Expr::Load { addr, value, .. } => {
+                        work.extend([Walk::Visit(value), Walk::Pin(e), Walk::Visit(addr)]);
+                    }
+                    Expr::Add(a, b)
+                    | Expr::Mul(a, b)
+                    | Expr::LessThan(a, b)
+                    | Expr::Equals(a, b) => {
+                        work.extend([Walk::Apply(e), Walk::Visit(b), Walk::Visit(a)]);
+                    }
+                },
+                Walk::Pin(e) => {
+                    let la = linears.pop().expect("the address was linearized");
+                    if let Expr::Load { at, .. } = e {
+                        side.push(Constraint::eq(&la, &Linear::constant(*at as i128)));
+                    }
+                }
+                Walk::Apply(e) => {
+                    let lb = linears.pop().expect("both operands were linearized");
+                    let la = linears.pop().expect("both operands were linearized");
+                    let linear = match e {
+                        Expr::Add(..) => la.checked_add(&lb),
+                        Expr::Mul(_, b) => match (la.as_constant(), lb.as_constant()) {
+                            (Some(k), _) => lb.checked_scale(k),
+                            (_, Some(k)) => la.checked_scale(k),
+                            (None, None) => {
+                                pin(side, &lb, b);
+                                la.checked_scale(b.value(model) as i128)
+                            }
+                        },
+                        Expr::LessThan(..) | Expr::Equals(..) => {
+                            let holds = e.value(model) != 0;
+                            side.push(e.branch(&la, &lb, holds));
+                            Some(Linear::constant(holds as i128))
+                        }
+                        _ => unreachable!("only operators are applied"),
+                    };
+                    linears.push(match linear.filter(Linear::fits_i64) {
+                        Some(linear) => linear,
+                        None => {
+                            // Past this point the expression is only known by its value.
+                            if let Expr::Add(a, b) | Expr::Mul(a, b) = e {
+                                pin(side, &la, a);
+                                pin(side, &lb, b);
+                            }
+                            Linear::constant(e.value(model) as i128)
+                        }
+                    });
+                }
+            }
+        }
+        linears.pop().expect("the expression was linearized")
+    }
+
+    /// Returns the constraint that a comparison of `la` and `lb` holds, or doesn't.
+    fn branch(&self, la: &Linear, lb: &Linear, holds: bool) -> Constraint {
+        match (self, holds) {
+            (Expr::LessThan(..), true) => Constraint::lt(la, lb),
+            (Expr::LessThan(..), false) => Constraint::ge(la, lb),
+            (_, true) => Constraint::eq(la, lb),
+            (_, false) => Constraint::ne(la, lb),
+        }
+    }
+
+    /// Returns the constraints that make the expression non-zero (if `truthy`) or zero. The last
+    /// constraint is the branch itself; any before it pin non-linear parts of the condition.
+    fn condition(&self, model: &[i64], truthy: bool) -> Vec<Constraint> {
+        let mut side = vec![];
+        let branch = match self {
+            Expr::LessThan(a, b) | Expr::Equals(a, b) => {
+                let (la, lb) = (a.linearize(model, &mut side), b.linearize(model, &mut side));
+                self.branch(&la, &lb, truthy)
+            }
+            _ => {
+                let l = self.linearize(model, &mut side);
+                if truthy {
+                    Constraint::ne(&l, &Linear::constant(0))
+                } else {
+                    Constraint::eq(&l, &Linear::constant(0))
+                }
+            }
+        };
+        side.push(branch);
+        side
+    }
+}
+
+impl Drop for Expr {
+    /// Drops the subexpressions that nothing else holds one at a time, since dropping them
+    /// recursively overflows the stack on a long chain.
+    fn drop(&mut self) {
+        let mut orphans = vec![];
+        self.take_children(&mut orphans);
+        while let Some(mut e) = orphans.pop() {
+            e.take_children(&mut orphans);
+        }
+    }
+}
+
+impl Expr {
+    /// Moves the subexpressions that only this expression holds into `orphans`.
+    fn take_children(&mut self, orphans: &mut Vec<Expr>) {
+        let children = match self {
+            Expr::Add(a, b) | Expr::Mul(a, b) | Expr::LessThan(a, b) | Expr::Equals(a, b) => [a, b],
+            Expr::Load { addr, value, .. } => [addr, value],
+            Expr::Const(_) | Expr::Symbol(_) => return,
+        };
+        for child in children {
+            if let Some(e) = Rc::get_mut(child) {
+                orphans.push(mem::replace(e, Expr::Const(0)));
+            }
+        }
+    }
+}
+
+/// Walk is a step of walking an expression without recursing.
+enum Walk<'a> {
+    /// Works out a subexpression, leaving its result on the stack.
+    Visit(&'a Expr),
+    /// Combines the results of an operator's operands.
+    Apply(&'a Expr),
+    /// Pins the address of a Load, which is on the stack, to the one it resolved to.
+    Pin(&'a Expr),
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Const(c) => write!(f, "{}", c),
+            Expr::Symbol(s) => write!(f, "s{}", s.0),
+            Expr::Add(a, b) => write!(f, "({} + {})", a, b),
+            Expr::Mul(a, b) => write!(f, "({} * {})", a, b),
+            Expr::LessThan(a, b) => write!(f, "({} < {})", a, b),
+            Expr::Equals(a, b) => write!(f, "({} == {})", a, b),
+            Expr::Load { addr, .. } => write!(f, "mem[{}]", addr),
+        }
+    }
+}
+
+/// Goal is what the solver should make happen.
+#[derive(Clone, Debug)]
+pub enum Goal {
+    /// The program halts with `value` stored at `addr`.
+    Memory { addr: u64, value: i64 },
+    /// The program's `index`th output is `value`.
+    Output { index: usize, value: i64 },
+}
+
+/// SymbolicError describes why a symbolic run could not continue.
+#[derive(Debug, PartialEq)]
+pub enum SymbolicError {
+    /// The program did something the computer can't execute along the explored path.
+    Crashed(IntCodeError),
+    StepLimit {
+        steps: u64,
+    },
+}
+
+impl fmt::Display for SymbolicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymbolicError::Crashed(e) => write!(f, "{}", e),
+            SymbolicError::StepLimit { steps } => {
+                write!(f, "program has run more than {} operations", steps)
+            }
+        }
+    }
+}
+
+/// Path is the result of one concolic run: the constraints that describe the path it took and the
+/// symbolic state at the end.
+pub struct Path {
+    /// Each entry is a group of constraints added at one point in the run. Branch groups end with
+    /// the flippable branch constraint.
+    constraints: Vec<(Vec<Constraint>, bool)>,
+    pub memory: Vec<Expr>,
+    pub outputs: Vec<Expr>,
+    pub halted: bool,
+}
+
+impl Path {
+    fn all_constraints(&self) -> Vec<Constraint> {
+        self.constraints
+            .iter()
+            .flat_map(|(group, _)| group.iter().cloned())
+            .collect()
+    }
+
+    /// Returns the number of symbolic branches the run went through.
+    pub fn branches(&self) -> usize {
+        self.constraints.iter().filter(|(_, b)| *b).count()
+    }
+}
+
+/// Solution holds a value for every symbol.
+#[derive(Debug, PartialEq)]
+pub struct Solution {
+    values: Vec<i64>,
+    /// How many concolic runs it took to find the solution.
+    pub runs: usize,
+}
+
+impl Solution {
+    pub fn value(&self, symbol: Symbol) -> i64 {
+        self.values[symbol.0]
+    }
+}
+
+enum InputValue {
+    Concrete(i64),
+    Symbolic(Symbol),
+}
+
+/// SymbolicProgram is an Intcode program together with a description of which of its values are
+/// unknown.
+pub struct SymbolicProgram {
+    program: Vec<i64>,
+    cells: Vec<(u64, Symbol)>,
+    inputs: Vec<InputValue>,
+    domains: Vec<RangeInclusive<i64>>,
+}
+
+impl SymbolicProgram {
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            program,
+            cells: vec![],
+            inputs: vec![],
+            domains: vec![],
+        }
+    }
+
+    fn new_symbol(&mut self, domain: RangeInclusive<i64>) -> Symbol {
+        self.domains.push(domain);
+        Symbol(self.domains.len() - 1)
+    }
+
+    /// Treats the value stored at `addr` as an unknown in the given range.
+    pub fn symbolic_cell(&mut self, addr: u64, domain: RangeInclusive<i64>) -> Symbol {
+        let s = self.new_symbol(domain);
+        self.cells.push((addr, s));
+        s
+    }
+
+    /// Queues an unknown input in the given range.
+    pub fn symbolic_input(&mut self, domain: RangeInclusive<i64>) -> Symbol {
+        let s = self.new_symbol(domain);
+        self.inputs.push(InputValue::Symbolic(s));
+        s
+    }
+
+    /// Queues a known input.
+    pub fn concrete_input(&mut self, val: i64) {
+        self.inputs.push(InputValue::Concrete(val));
+    }
+
+    fn domains(&self) -> Vec<Domain> {
+        self.domains
+            .iter()
+            .map(|d| Domain {
+                lo: *d.start() as i128,
+                hi: *d.end() as i128,
+            })
+            .collect()
+    }
+
+    /// Runs the program once, following the concrete values in `model` at every branch.
+    pub fn run(&self, model: &[i64]) -> Result<Path, SymbolicError> {
+        let mut memory: Vec<Value> = self.program.iter().map(|v| Value::constant(*v)).collect();
+        memory.resize(10000, Value::constant(0));
+        for (addr, s) in self.cells.iter() {
+            if *addr as usize >= memory.len() {
+                memory.resize(*addr as usize + 1, Value::constant(0));
+            }
+            memory[*addr as usize] = Value::symbol(*s, model);
+        }
+        let mut inputs: VecDeque<Value> = self
+            .inputs
+            .iter()
+            .map(|i| match i {
+                InputValue::Concrete(v) => Value::constant(*v),
+                InputValue::Symbolic(s) => Value::symbol(*s, model),
+            })
+            .collect();
+        let mut machine = SymbolicMachine {
+            memory,
+            ptr: 0,
+            rel_pos: 0,
+            model,
+            path: vec![],
+        };
+        let mut outputs = vec![];
+        let mut halted = false;
+        let mut steps = 0u64;
+        loop {
+            steps += 1;
+            if steps > MAX_STEPS_PER_RUN {
+                return Err(SymbolicError::StepLimit {
+                    steps: MAX_STEPS_PER_RUN,
+                });
+            }
+            let op_code = machine.concrete(&machine.cell(machine.ptr));
+            let instruction = parse_instruction(op_code).map_err(|kind| machine.crash(kind))?;
+            match instruction {
+                Instruction::Add { modes } => {
+                    let a = machine.operand(1, &modes[0])?;
+                    let b = machine.operand(2, &modes[1])?;
+                    let val = machine.arith(&a, &b, Expr::add, i64::checked_add)?;
+                    machine.store(3, &modes[2], val)?;
+                    machine.ptr += 4;
+                }
+                Instruction::Mult { modes } => {
+                    let a = machine.operand(1, &modes[0])?;
+                    let b = machine.operand(2, &modes[1])?;
+                    let val = machine.arith(&a, &b, Expr::mul, i64::checked_mul)?;
+                    machine.store(3, &modes[2], val)?;
+                    machine.ptr += 4;
+                }
+                Instruction::LessThan { modes } => {
+                    let a = machine.operand(1, &modes[0])?;
+                    let b = machine.operand(2, &modes[1])?;
+                    let val = Value {
+                        expr: Expr::less_than(&a.expr, &b.expr),
+                        concrete: (a.concrete < b.concrete) as i64,
+                    };
+                    machine.store(3, &modes[2], val)?;
+                    machine.ptr += 4;
+                }
+                Instruction::Equals { modes } => {
+                    let a = machine.operand(1, &modes[0])?;
+                    let b = machine.operand(2, &modes[1])?;
+                    let val = Value {
+                        expr: Expr::equals(&a.expr, &b.expr),
+                        concrete: (a.concrete == b.concrete) as i64,
+                    };
+                    machine.store(3, &modes[2], val)?;
+                    machine.ptr += 4;
+                }
+                Instruction::ReadInput { modes } => match inputs.pop_front() {
+                    Some(val) => {
+                        machine.store(1, &modes, val)?;
+                        machine.ptr += 2;
+                    }
+                    None => break,
+                },
+                Instruction::WriteOutput { modes } => {
+                    outputs.push(machine.operand(1, &modes)?.expr);
+                    machine.ptr += 2;
+                }
+                Instruction::JumpIfTrue { modes } => {
+                    let cond = machine.operand(1, &modes[0])?;
+                    let target = machine.operand(2, &modes[1])?;
+                    machine.jump(&cond, &target, true);
+                }
+                Instruction::JumpIfFalse { modes } => {
+                    let cond = machine.operand(1, &modes[0])?;
+                    let target = machine.operand(2, &modes[1])?;
+                    machine.jump(&cond, &target, false);
+                }
+                Instruction::AdjustRelativePosition { modes } => {
+                    let val = machine.operand(1, &modes)?;
+                    let val = machine.concrete(&val);
+                    machine.rel_pos = machine
+                        .rel_pos
+                        .checked_add(val)
+                        .ok_or_else(|| machine.crash(ErrorKind::Overflow))?;
+                    machine.ptr += 2;
+                }
+                Instruction::End => {
+                    halted = true;
+                    break;
+                }
+            }
+        }
+        Ok(Path {
+            constraints: machine.path,
+            memory: machine.memory.into_iter().map(|v| v.expr).collect(),
+            outputs,
+            halted,
+        })
+    }
+
+    fn goal_constraints(&self, path: &Path, goal: &Goal, model: &[i64]) -> Option<Vec<Constraint>> {
+        let expr = match goal {
+            Goal::Memory { addr, value } => {
+                if !path.halted {
+                    return None;
+                }
+                (path.memory.get(*addr as usize)?, value)
+            }
+            Goal::Output { index, value } => (path.outputs.get(*index)?, value),
+        };
+        let mut side = vec![];
+        let l = expr.0.linearize(model, &mut side);
+        side.push(Constraint::eq(&l, &Linear::constant(*expr.1 as i128)));
+        Some(side)
+    }
+
+    /// Searches for values of every symbol that make the goal hold. The first run uses the lower
+    /// bound of every symbol's range. Each run either finds a solution on its own path or queues
+    /// new models that flip one of its branches, up to `max_runs` runs in total.
+    pub fn solve(&self, goal: &Goal, max_runs: usize) -> Result<Option<Solution>, SymbolicError> {
+        let domains = self.domains();
+        let initial: Vec<i64> = self.domains.iter().map(|d| *d.start()).collect();
+        // Each entry is a model and how many of its path's branches are already covered by
+        // earlier runs, so they are not flipped again.
+        let mut worklist = VecDeque::new();
+        worklist.push_back((initial, 0usize));
+        let mut runs = 0;
+        while let Some((model, bound)) = worklist.pop_front() {
+            if runs >= max_runs {
+                break;
+            }
+            runs += 1;
+            let path = self.run(&model)?;
+            if let Some(goal) = self.goal_constraints(&path, goal, &model) {
+                let mut constraints = path.all_constraints();
+                constraints.extend(goal);
+                if let Some(values) = solver::solve(&constraints, &domains) {
+                    return Ok(Some(Solution {
+                        values: values.iter().map(|v| *v as i64).collect(),
+                        runs,
+                    }));
+                }
+            }
+            // Flip every branch past the bound, keeping the prefix of the path before it.
+            let mut prefix: Vec<Constraint> = vec![];
+            let mut branch_index = 0;
+            for (group, is_branch) in path.constraints.iter() {
+                let (last, pins) = group.split_last().unwrap();
+                if *is_branch {
+                    if branch_index >= bound {
+                        let mut flipped = prefix.clone();
+                        flipped.extend(pins.iter().cloned());
+                        flipped.push(last.negate());
+                        if let Some(values) = solver::solve(&flipped, &domains) {
+                            let next: Vec<i64> = values.iter().map(|v| *v as i64).collect();
+                            worklist.push_back((next, branch_index + 1));
+                        }
+                    }
+                    branch_index += 1;
+                }
+                prefix.extend(group.iter().cloned());
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Value is what the machine works with: an expression, and what it comes to in the model the
+/// machine runs with, which is kept so that it needn't be worked out again.
+#[derive(Clone, Debug)]
+struct Value {
+    expr: Expr,
+    concrete: i64,
+}
+
+impl Value {
+    fn constant(c: i64) -> Self {
+        Value {
+            expr: Expr::Const(c),
+            concrete: c,
+        }
+    }
+
+    fn symbol(s: Symbol, model: &[i64]) -> Self {
+        Value {
+            expr: Expr::Symbol(s),
+            concrete: model[s.0],
+        }
+    }
+}
+
+struct SymbolicMachine<'a> {
+    memory: Vec<Value>,
+    ptr: u64,
+    rel_pos: i64,
+    model: &'a [i64],
+    path: Vec<(Vec<Constraint>, bool)>,
+}
+
+impl<'a> SymbolicMachine<'a> {
+    /// Returns the concrete value, pinning it to that value if it is symbolic.
+    fn concrete(&mut self, v: &Value) -> i64 {
+        if v.expr.as_const().is_none() {
+            let mut side = vec![];
+            let l = v.expr.linearize(self.model, &mut side);
+            side.push(Constraint::eq(&l, &Linear::constant(v.concrete as i128)));
+            self.path.push((side, false));
+        }
+        v.concrete
+    }
+
+    fn crash(&self, kind: ErrorKind) -> SymbolicError {
+        SymbolicError::Crashed(IntCodeError {
+            ptr: self.ptr,
+            kind,
+        })
+    }
+
+    /// Returns the value stored at addr. Addresses past the end of memory hold 0.
+    fn cell(&self, addr: u64) -> Value {
+        match self.memory.get(addr as usize) {
+            Some(v) => v.clone(),
+            None => Value::constant(0),
+        }
+    }
+
+    fn check_addr(&self, at: i64) -> Result<usize, SymbolicError> {
+        if at < 0 {
+            return Err(self.crash(ErrorKind::NegativeAddress(at)));
+        }
+        if at > MAX_ADDRESS {
+            return Err(self.crash(ErrorKind::AddressOutOfRange(at)));
+        }
+        Ok(at as usize)
+    }
+
+    /// Adds or multiplies `a` and `b`, crashing the way the computer would if that overflows in
+    /// this run. Whether it would for other values of the symbols isn't recorded as a constraint:
+    /// every Add and Mul would need one.
+    fn arith(
+        &self,
+        a: &Value,
+        b: &Value,
+        expr: fn(&Expr, &Expr) -> Expr,
+        op: fn(i64, i64) -> Option<i64>,
+    ) -> Result<Value, SymbolicError> {
+        match op(a.concrete, b.concrete) {
+            Some(concrete) => Ok(Value {
+                expr: expr(&a.expr, &b.expr),
+                concrete,
+            }),
+            None => Err(self.crash(ErrorKind::Overflow)),
+        }
+    }
+
+    fn address(&self, offset: u64, mode: &ParamMode) -> Result<Value, SymbolicError> {
+        let param = self.cell(self.ptr + offset);
+        match mode {
+            ParamMode::RelativeMode => self.arith(
+                &param,
+                &Value::constant(self.rel_pos),
+                Expr::add,
+                i64::checked_add,
+            ),
+            _ => Ok(param),
+        }
+    }
+
+    fn operand(&mut self, offset: u64, mode: &ParamMode) -> Result<Value, SymbolicError> {
+        if let ParamMode::Immediate = mode {
+            return Ok(self.cell(self.ptr + offset));
+        }
+        let addr = self.address(offset, mode)?;
+        let at = self.check_addr(addr.concrete)?;
+        let value = self.cell(at as u64);
+        // Reading through a symbolic address only pins the address if the value ends up in a
+        // constraint, which is often never: day 2's first instruction is overwritten right away.
+        Ok(match addr.expr {
+            Expr::Const(_) => value,
+            addr => Value {
+                concrete: value.concrete,
+                expr: Expr::Load {
+                    addr: Rc::new(addr),
+                    at: at as i64,
+                    value: Rc::new(value.expr),
+                },
+            },
+        })
+    }
+
+    fn store(&mut self, offset: u64, mode: &ParamMode, val: Value) -> Result<(), SymbolicError> {
+        let addr = self.address(offset, mode)?;
+        // Which cell changes decides everything that follows, so a symbolic write address is
+        // pinned straight away.
+        let at = self.concrete(&addr);
+        let at = self.check_addr(at)?;
+        if at >= self.memory.len() {
+            self.memory.resize(at + 1, Value::constant(0));
+        }
+        self.memory[at] = val;
+        Ok(())
+    }
+
+    fn jump(&mut self, cond: &Value, target: &Value, if_true: bool) {
+        let truthy = cond.concrete != 0;
+        if cond.expr.as_const().is_none() {
+            self.path
+                .push((cond.expr.condition(self.model, truthy), true));
+        }
+        if truthy == if_true {
+            self.ptr = self.concrete(target) as u64;
+        } else {
+            self.ptr += 3;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::symbolic::{Goal, SymbolicError, SymbolicProgram};
+    use crate::int_code::{ErrorKind, IntCodeError};
+    use crate::util;
+
+    #[test]
+    fn test_day02_in_one_run() {
+        let memory = util::read_int_code_memory("./input/day02.txt");
+        let mut program = SymbolicProgram::new(memory);
+        let noun = program.symbolic_cell(1, 0..=99);
+        let verb = program.symbolic_cell(2, 0..=99);
+        let goal = Goal::Memory {
+            addr: 0,
+            value: 19690720,
+        };
+        let solution = program.solve(&goal, 1).unwrap().unwrap();
+        assert_eq!(solution.runs, 1);
+        assert_eq!(100 * solution.value(noun) + solution.value(verb), 5696);
+    }
+
+    #[test]
+    fn test_branching_input() {
+        // Outputs 999 if the input is below 8, 1000 if it is 8 and 1001 above 8.
+        let program = vec![
+            3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0,
+            0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4,
+            20, 1105, 1, 46, 98, 99,
+        ];
+        for (target, check) in &[(999, 0), (1000, 1), (1001, 2)] {
+            let mut p = SymbolicProgram::new(program.clone());
+            let input = p.symbolic_input(0..=100);
+            let goal = Goal::Output {
+                index: 0,
+                value: *target,
+            };
+            let solution = p.solve(&goal, 10).unwrap().unwrap();
+            let v = solution.value(input);
+            assert_eq!((v >= 8) as i32 + (v > 8) as i32, *check);
+        }
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        // Outputs the input plus one, which can never be 0 when the input is in 0..=10.
+        let program = vec![3, 9, 1001, 9, 1, 9, 4, 9, 99, 0];
+        let mut p = SymbolicProgram::new(program);
+        p.symbolic_input(0..=10);
+        let goal = Goal::Output { index: 0, value: 0 };
+        assert_eq!(p.solve(&goal, 10).unwrap(), None);
+    }
+
+    #[test]
+    fn test_path_records_branches() {
+        let program = vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+        let mut p = SymbolicProgram::new(program);
+        p.symbolic_input(0..=1);
+        let path = p.run(&[0]).unwrap();
+        assert!(path.halted);
+        assert_eq!(path.branches(), 1);
+        assert_eq!(path.outputs[0].eval(&[0]), Some(0));
+    }
+
+    #[test]
+    fn test_overflow_crashes() {
+        let overflow = |ptr| {
+            Some(SymbolicError::Crashed(IntCodeError {
+                ptr,
+                kind: ErrorKind::Overflow,
+            }))
+        };
+        // Adds the input to i64::MAX, which only overflows if the input is positive.
+        let program = vec![3, 9, 1, 9, 10, 11, 4, 11, 99, 0, i64::MAX, 0];
+        let mut p = SymbolicProgram::new(program);
+        p.symbolic_input(-5..=5);
+        assert_eq!(
+            p.run(&[-1]).unwrap().outputs[0].eval(&[-1]),
+            Some(i64::MAX - 1)
+        );
+        assert_eq!(p.run(&[1]).err(), overflow(2));
+        let program = vec![1102, i64::MAX, 2, 0, 99];
+        assert_eq!(SymbolicProgram::new(program).run(&[]).err(), overflow(0));
+        let program = vec![109, i64::MAX, 109, 1, 99];
+        assert_eq!(SymbolicProgram::new(program).run(&[]).err(), overflow(2));
+        let program = vec![109, i64::MAX, 204, 1, 99];
+        assert_eq!(SymbolicProgram::new(program).run(&[]).err(), overflow(2));
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // Adds the input to a total 100000 times and outputs the total, which makes a chain of
+        // 100000 Adds.
+        let program = vec![
+            3, 100, 1, 101, 100, 101, 1001, 102, 1, 102, 1007, 102, 100000, 103, 1005, 103, 2, 4,
+            101, 99,
+        ];
+        let mut p = SymbolicProgram::new(program);
+        let input = p.symbolic_input(0..=10);
+        let goal = Goal::Output {
+            index: 0,
+            value: 300000,
+        };
+        let solution = p.solve(&goal, 1).unwrap().unwrap();
+        assert_eq!(solution.value(input), 3);
+    }
+
+    #[test]
+    fn test_huge_coefficients() {
+        // Multiplies the input by 2^62 twice and compares it with 0. The coefficient of the
+        // product doesn't fit in an i64, so the product is pinned to its value in the run.
+        let m = 1 << 62;
+        let program = vec![
+            3, 100, 1002, 100, m, 101, 1002, 101, m, 101, 1008, 101, 0, 102, 4, 102, 99,
+        ];
+        let mut p = SymbolicProgram::new(program);
+        let input = p.symbolic_input(0..=10);
+        let goal = Goal::Output { index: 0, value: 1 };
+        let solution = p.solve(&goal, 1).unwrap().unwrap();
+        assert_eq!(solution.value(input), 0);
+    }
+}
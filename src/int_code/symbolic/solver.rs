@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The solver gives up after exploring this many search nodes.
+const MAX_SEARCH_NODES: usize = 100_000;
+/// Domains at most this big are enumerated value by value instead of being split in half.
+const ENUMERATE_DOMAIN_SIZE: i128 = 16;
+
+/// Linear is an expression of the form `c + a1*x1 + a2*x2 + ...` over integer symbols. The
+/// arithmetic is done in i128, so that adding or scaling expressions whose constant and
+/// coefficients fit in an i64 can't overflow. Expressions built from program values are kept that
+/// small; the checked operations say when they wouldn't be.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Linear {
+    constant: i128,
+    terms: BTreeMap<usize, i128>,
+}
+
+impl Linear {
+    pub fn constant(c: i128) -> Self {
+        Self {
+            constant: c,
+            terms: BTreeMap::new(),
+        }
+    }
+
+    pub fn symbol(id: usize) -> Self {
+        let mut terms = BTreeMap::new();
+        terms.insert(id, 1);
+        Self { constant: 0, terms }
+    }
+
+    /// Returns the constant value if the expression does not depend on any symbol.
+    pub fn as_constant(&self) -> Option<i128> {
+        if self.terms.is_empty() {
+            Some(self.constant)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the constant and every coefficient fit in an i64.
+    pub fn fits_i64(&self) -> bool {
+        let fits = |v: i128| i64::try_from(v).is_ok();
+        fits(self.constant) && self.terms.values().all(|c| fits(*c))
+    }
+
+    /// Returns the sum, or None if it overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let mut out = self.clone();
+        out.constant = out.constant.checked_add(other.constant)?;
+        for (id, coef) in other.terms.iter() {
+            let sum = out.terms.entry(*id).or_insert(0);
+            *sum = sum.checked_add(*coef)?;
+        }
+        out.terms.retain(|_, coef| *coef != 0);
+        Some(out)
+    }
+
+    /// Returns the expression multiplied by `k`, or None if it overflows.
+    pub fn checked_scale(&self, k: i128) -> Option<Self> {
+        if k == 0 {
+            return Some(Self::constant(0));
+        }
+        let mut terms = BTreeMap::new();
+        for (id, c) in self.terms.iter() {
+            terms.insert(*id, c.checked_mul(k)?);
+        }
+        Some(Self {
+            constant: self.constant.checked_mul(k)?,
+            terms,
+        })
+    }
+
+    /// Panics if the sum overflows, which it can't if both expressions fit in an i64.
+    pub fn add(&self, other: &Self) -> Self {
+        self.checked_add(other)
+            .expect("linear expression overflowed")
+    }
+
+    /// Panics if the product overflows, which it can't if the expression and `k` fit in an i64.
+    pub fn scale(&self, k: i128) -> Self {
+        self.checked_scale(k).expect("linear expression overflowed")
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.scale(-1))
+    }
+
+    fn eval(&self, values: &[i128]) -> i128 {
+        self.constant
+            + self
+                .terms
+                .iter()
+                .map(|(id, coef)| coef * values[*id])
+                .sum::<i128>()
+    }
+
+    /// Returns the smallest and largest value the expression can take over the given domains,
+    /// ignoring the term for `skip`.
+    fn range_without(&self, domains: &[Domain], skip: usize) -> (i128, i128) {
+        let mut lo = self.constant;
+        let mut hi = self.constant;
+        for (id, coef) in self.terms.iter() {
+            if *id == skip {
+                continue;
+            }
+            let d = &domains[*id];
+            if *coef > 0 {
+                lo += coef * d.lo;
+                hi += coef * d.hi;
+            } else {
+                lo += coef * d.hi;
+                hi += coef * d.lo;
+            }
+        }
+        (lo, hi)
+    }
+}
+
+impl fmt::Display for Linear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.constant)?;
+        for (id, coef) in self.terms.iter() {
+            write!(f, " + {}*s{}", coef, id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Relation says how a constraint's linear expression compares to zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relation {
+    EqZero,
+    NeZero,
+    LeZero,
+}
+
+/// Constraint is a linear expression together with how it must compare to zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    pub lhs: Linear,
+    pub relation: Relation,
+}
+
+impl Constraint {
+    pub fn eq(a: &Linear, b: &Linear) -> Self {
+        Self {
+            lhs: a.sub(b),
+            relation: Relation::EqZero,
+        }
+    }
+
+    pub fn ne(a: &Linear, b: &Linear) -> Self {
+        Self {
+            lhs: a.sub(b),
+            relation: Relation::NeZero,
+        }
+    }
+
+    /// a < b, stored as a - b + 1 <= 0.
+    pub fn lt(a: &Linear, b: &Linear) -> Self {
+        Self {
+            lhs: a.sub(b).add(&Linear::constant(1)),
+            relation: Relation::LeZero,
+        }
+    }
+
+    /// a >= b, stored as b - a <= 0.
+    pub fn ge(a: &Linear, b: &Linear) -> Self {
+        Self {
+            lhs: b.sub(a),
+            relation: Relation::LeZero,
+        }
+    }
+
+    /// Returns the constraint that holds exactly when this one does not.
+    pub fn negate(&self) -> Self {
+        match self.relation {
+            Relation::EqZero => Self {
+                lhs: self.lhs.clone(),
+                relation: Relation::NeZero,
+            },
+            Relation::NeZero => Self {
+                lhs: self.lhs.clone(),
+                relation: Relation::EqZero,
+            },
+            // not (e <= 0)  <=>  e >= 1  <=>  1 - e <= 0
+            Relation::LeZero => Self {
+                lhs: Linear::constant(1).sub(&self.lhs),
+                relation: Relation::LeZero,
+            },
+        }
+    }
+
+    pub fn holds(&self, values: &[i128]) -> bool {
+        let v = self.lhs.eval(values);
+        match self.relation {
+            Relation::EqZero => v == 0,
+            Relation::NeZero => v != 0,
+            Relation::LeZero => v <= 0,
+        }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.relation {
+            Relation::EqZero => "==",
+            Relation::NeZero => "!=",
+            Relation::LeZero => "<=",
+        };
+        write!(f, "{} {} 0", self.lhs, op)
+    }
+}
+
+/// Domain is the inclusive range of values a symbol may still take.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Domain {
+    pub lo: i128,
+    pub hi: i128,
+}
+
+impl Domain {
+    fn is_empty(&self) -> bool {
+        self.lo > self.hi
+    }
+
+    fn is_fixed(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    fn size(&self) -> i128 {
+        self.hi - self.lo + 1
+    }
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if (a % b != 0) && ((a < 0) != (b < 0)) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -div_floor(-a, b)
+}
+
+/// Narrows the domains until no constraint can narrow them further. Returns false if some domain
+/// became empty, meaning the constraints can't be satisfied.
+fn propagate(constraints: &[Constraint], domains: &mut [Domain]) -> bool {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for c in constraints {
+            for (id, coef) in c.lhs.terms.iter() {
+                let (rest_lo, rest_hi) = c.lhs.range_without(domains, *id);
+                let d = domains[*id];
+                let mut next = d;
+                match c.relation {
+                    // coef*x ∈ [-rest_hi, -rest_lo]
+                    Relation::EqZero => {
+                        let (lo, hi) = if *coef > 0 {
+                            (div_ceil(-rest_hi, *coef), div_floor(-rest_lo, *coef))
+                        } else {
+                            (div_ceil(-rest_lo, *coef), div_floor(-rest_hi, *coef))
+                        };
+                        next.lo = next.lo.max(lo);
+                        next.hi = next.hi.min(hi);
+                    }
+                    // coef*x <= -rest_lo
+                    Relation::LeZero => {
+                        if *coef > 0 {
+                            next.hi = next.hi.min(div_floor(-rest_lo, *coef));
+                        } else {
+                            next.lo = next.lo.max(div_ceil(-rest_lo, *coef));
+                        }
+                    }
+                    // Only useful once everything else is fixed and the forbidden value sits on
+                    // the edge of the domain.
+                    Relation::NeZero => {
+                        if rest_lo == rest_hi && (-rest_lo) % coef == 0 {
+                            let forbidden = -rest_lo / coef;
+                            if next.lo == forbidden {
+                                next.lo += 1;
+                            } else if next.hi == forbidden {
+                                next.hi -= 1;
+                            }
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    return false;
+                }
+                if next != d {
+                    domains[*id] = next;
+                    changed = true;
+                }
+            }
+            if c.lhs.terms.is_empty() && !c.holds(&[]) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Finds integer values for every symbol such that each value lies in its domain and every
+/// constraint holds. Returns None if there is no such assignment, or if the search gave up.
+pub fn solve(constraints: &[Constraint], domains: &[Domain]) -> Option<Vec<i128>> {
+    let mut budget = MAX_SEARCH_NODES;
+    search(constraints, domains.to_vec(), &mut budget)
+}
+
+fn search(
+    constraints: &[Constraint],
+    mut domains: Vec<Domain>,
+    budget: &mut usize,
+) -> Option<Vec<i128>> {
+    if *budget == 0 {
+        return None;
+    }
+    *budget -= 1;
+    if !propagate(constraints, &mut domains) {
+        return None;
+    }
+    // Branch on the unfixed symbol with the smallest domain.
+    let branch = domains
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| !d.is_fixed())
+        .min_by_key(|(_, d)| d.size())
+        .map(|(id, d)| (id, *d));
+    let (id, d) = match branch {
+        Some(b) => b,
+        None => {
+            let values: Vec<i128> = domains.iter().map(|d| d.lo).collect();
+            return if constraints.iter().all(|c| c.holds(&values)) {
+                Some(values)
+            } else {
+                None
+            };
+        }
+    };
+    let halves: Vec<Domain> = if d.size() <= ENUMERATE_DOMAIN_SIZE {
+        (d.lo..=d.hi).map(|v| Domain { lo: v, hi: v }).collect()
+    } else {
+        let mid = d.lo + (d.hi - d.lo) / 2;
+        vec![
+            Domain { lo: d.lo, hi: mid },
+            Domain {
+                lo: mid + 1,
+                hi: d.hi,
+            },
+        ]
+    };
+    for half in halves {
+        let mut next = domains.clone();
+        next[id] = half;
+        if let Some(values) = search(constraints, next, budget) {
+            return Some(values);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int_code::symbolic::solver::{solve, Constraint, Domain, Linear};
+
+    fn x(id: usize, coef: i128) -> Linear {
+        Linear::symbol(id).scale(coef)
+    }
+
+    #[test]
+    fn test_solve_equation() {
+        // 250000 * a + b + 1 == 14000097 with 0 <= a, b <= 99
+        let lhs = x(0, 250000).add(&x(1, 1)).add(&Linear::constant(1));
+        let c = Constraint::eq(&lhs, &Linear::constant(14000097));
+        let domains = vec![Domain { lo: 0, hi: 99 }; 2];
+        assert_eq!(solve(&[c], &domains), Some(vec![56, 96]));
+    }
+
+    #[test]
+    fn test_solve_inequalities() {
+        // a < b, b < 3, a != 0 over [0, 10]
+        let constraints = vec![
+            Constraint::lt(&x(0, 1), &x(1, 1)),
+            Constraint::lt(&x(1, 1), &Linear::constant(3)),
+            Constraint::ne(&x(0, 1), &Linear::constant(0)),
+        ];
+        let domains = vec![Domain { lo: 0, hi: 10 }; 2];
+        assert_eq!(solve(&constraints, &domains), Some(vec![1, 2]));
+        let mut constraints = constraints;
+        constraints.push(Constraint::ge(&x(0, 1), &Linear::constant(2)));
+        assert_eq!(solve(&constraints, &domains), None);
+    }
+
+    #[test]
+    fn test_checked() {
+        let big = x(0, i64::MAX as i128).add(&Linear::constant(i64::MIN as i128));
+        assert!(big.fits_i64());
+        let squared = big.checked_scale(i64::MAX as i128).unwrap();
+        assert!(!squared.fits_i64());
+        assert_eq!(squared.checked_scale(4), None);
+        let tripled = squared.checked_scale(2).unwrap();
+        assert_eq!(squared.checked_add(&tripled), None);
+        assert_eq!(big.checked_scale(0), Some(Linear::constant(0)));
+    }
+
+    #[test]
+    fn test_negate() {
+        let c = Constraint::lt(&x(0, 1), &Linear::constant(5));
+        assert!(c.holds(&[4]));
+        assert!(!c.negate().holds(&[4]));
+        assert!(c.negate().holds(&[5]));
+    }
+}
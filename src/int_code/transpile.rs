@@ -0,0 +1,792 @@
+//! An ahead-of-time compiler from Intcode to Rust.
+//!
+//! `transpile` finds the instructions reachable from address 0, splits them into basic blocks and
+//! emits a struct that runs them as a state machine: one match arm per block, with operands and
+//! modes decoded in advance. The struct has the same `input` and `output` DataStreams as an
+//! IntCodeComputer and its `run` returns the same RunOutcome, so it can stand in for one.
+//!
+//! Operands the program writes to at a fixed address, such as the index of a jump table, are read
+//! from memory when the instruction runs instead of being compiled in. Anything else can't be
+//! compiled ahead of time: when the program writes into a cell that holds a compiled opcode or
+//! operand, or jumps somewhere the compiler didn't find code, the struct hands its memory, pointer
+//! and relative base to an IntCodeComputer and interprets the rest of the run.
+//!
+//! The tests run the Intcode puzzle inputs compiled ahead of time, from src/int_code/transpiled.
+//! test_fixtures_are_current fails when they no longer match what transpile generates, and
+//! running the tests with AOC_UPDATE_FIXTURES=1 writes them again.
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// How the generated code is named and where it finds this crate.
+pub struct Options {
+    pub struct_name: String,
+    /// The path of this crate from the generated code: `aoc2019` from another crate, or `crate`
+    /// from inside this one.
+    pub crate_path: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            struct_name: "Machine".to_string(),
+            crate_path: "aoc2019".to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Param {
+    Position(i64),
+    Immediate(i64),
+    Relative(i64),
+}
+
+pub(crate) struct Op {
+    pub(crate) op_code: i64,
+    pub(crate) params: Vec<Param>,
+}
+
+impl Op {
+    pub(crate) fn len(&self) -> usize {
+        self.params.len() + 1
+    }
+}
+
+/// Decodes the instruction at the address, or returns None if it isn't one. Instructions whose
+/// operands run past the end of the program are left to the interpreter.
+pub(crate) fn decode(program: &[i64], addr: usize) -> Option<Op> {
+    let instruction = *program.get(addr)?;
+    let count = match instruction % 100 {
+        1 | 2 | 7 | 8 => 3,
+        5 | 6 => 2,
+        3 | 4 | 9 => 1,
+        99 => 0,
+        _ => return None,
+    };
+    let mut params = vec![];
+    let mut modes = instruction / 100;
+    for i in 0..count {
+        let value = *program.get(addr + 1 + i)?;
+        params.push(match modes % 10 {
+            0 => Param::Position(value),
+            1 => Param::Immediate(value),
+            2 => Param::Relative(value),
+            _ => return None,
+        });
+        modes /= 10;
+    }
+    Some(Op {
+        op_code: instruction % 100,
+        params,
+    })
+}
+
+fn literal(v: i64) -> String {
+    if v == i64::MIN {
+        "i64::MIN".to_string()
+    } else {
+        v.to_string()
+    }
+}
+
+struct Compiler {
+    ops: BTreeMap<usize, Op>,
+    leaders: BTreeSet<usize>,
+    /// Cells that belong to an instruction that has been found.
+    covered: Vec<bool>,
+    /// Cells the program writes to through a position parameter.
+    written: BTreeSet<usize>,
+    /// Cells whose value is compiled into the code: opcodes, and operands that aren't written.
+    code: Vec<bool>,
+}
+
+impl Compiler {
+    /// Finds the program's instructions and basic blocks. The first instruction of each block is
+    /// a leader: the start of the program, jump targets, the instruction after a jump, and
+    /// inputs, which may have to wait.
+    ///
+    /// Jumps through memory, like the jump tables of day 7, have targets that aren't known until
+    /// the program runs. Values in cells that aren't code are tried as extra entry points, as long
+    /// as what they point at decodes without overlapping the code already found or a cell the
+    /// program writes to. A wrong guess only costs some unused code.
+    fn new(program: &[i64]) -> Self {
+        let mut compiler = Self {
+            ops: BTreeMap::new(),
+            leaders: BTreeSet::new(),
+            covered: vec![false; program.len()],
+            written: BTreeSet::new(),
+            code: vec![false; program.len()],
+        };
+        compiler.explore(program, 0, false);
+        compiler.written = compiler.writes(program.len());
+        let candidates: Vec<usize> = (0..program.len())
+            .filter(|a| !compiler.covered[*a])
+            .map(|a| program[a])
+            .filter(|v| *v >= 0 && (*v as usize) < program.len())
+            .map(|v| v as usize)
+            .collect();
+        for root in candidates {
+            compiler.explore(program, root, true);
+        }
+        compiler.written = compiler.writes(program.len());
+        for (addr, op) in compiler.ops.iter() {
+            compiler.code[*addr] = true;
+            for cell in addr + 1..addr + op.len() {
+                compiler.code[cell] = !compiler.written.contains(&cell);
+            }
+        }
+        compiler
+    }
+
+    /// Finds every instruction reachable from `root` through fallthrough and jumps with an
+    /// immediate target. A speculative search gives up on instructions that overlap code or
+    /// written cells.
+    fn explore(&mut self, program: &[i64], root: usize, speculative: bool) {
+        let mut work = vec![root];
+        while let Some(addr) = work.pop() {
+            if self.ops.contains_key(&addr) {
+                continue;
+            }
+            let op = match decode(program, addr) {
+                Some(op) => op,
+                None => continue,
+            };
+            let next = addr + op.len();
+            if speculative && (addr..next).any(|a| self.covered[a] || self.written.contains(&a)) {
+                continue;
+            }
+            match op.op_code {
+                99 => (),
+                5 | 6 => {
+                    if let Param::Immediate(target) = op.params[1] {
+                        if target >= 0 && (target as usize) < program.len() {
+                            self.leaders.insert(target as usize);
+                            work.push(target as usize);
+                        }
+                    }
+                    self.leaders.insert(next);
+                    work.push(next);
+                }
+                3 => {
+                    self.leaders.insert(addr);
+                    work.push(next);
+                }
+                _ => work.push(next),
+            }
+            for cell in self.covered[addr..next].iter_mut() {
+                *cell = true;
+            }
+            self.ops.insert(addr, op);
+        }
+        if self.ops.contains_key(&root) {
+            self.leaders.insert(root);
+        }
+    }
+
+    /// Returns the cells inside the program that instructions found so far write to through a
+    /// position parameter.
+    fn writes(&self, len: usize) -> BTreeSet<usize> {
+        let mut written = BTreeSet::new();
+        for op in self.ops.values() {
+            let param = match op.op_code {
+                1 | 2 | 7 | 8 => op.params[2],
+                3 => op.params[0],
+                _ => continue,
+            };
+            if let Param::Immediate(a) | Param::Position(a) = param {
+                if a >= 0 && (a as usize) < len {
+                    written.insert(a as usize);
+                }
+            }
+        }
+        written
+    }
+
+    /// Returns whether operand `i` of the instruction at `ptr` is read from memory when the
+    /// instruction runs, because the program may have changed it.
+    fn live(&self, ptr: usize, i: usize) -> bool {
+        self.written.contains(&(ptr + 1 + i))
+    }
+
+    /// Returns an expression for operand `i` of the instruction at `ptr`, before its mode is
+    /// applied.
+    fn raw(&self, ptr: usize, i: usize) -> String {
+        if self.live(ptr, i) {
+            return format!("self.load({})", ptr + 1 + i);
+        }
+        match self.ops[&ptr].params[i] {
+            Param::Immediate(v) | Param::Position(v) | Param::Relative(v) => literal(v),
+        }
+    }
+
+    /// Returns an expression for the value of parameter `i` of the instruction at `ptr`.
+    fn value(&self, ptr: usize, i: usize) -> String {
+        let raw = self.raw(ptr, i);
+        match self.ops[&ptr].params[i] {
+            Param::Immediate(_) => raw,
+            Param::Position(_) => format!("self.load(Self::addr({}, {})?)", ptr, raw),
+            Param::Relative(_) => format!("self.load(self.rel({}, {})?)", ptr, raw),
+        }
+    }
+
+    /// Returns an expression for the address parameter `i` of the instruction at `ptr` writes
+    /// to. Writes never use immediate mode, so like IntCodeComputer it treats an immediate as a
+    /// position.
+    fn dest(&self, ptr: usize, i: usize) -> String {
+        let raw = self.raw(ptr, i);
+        match self.ops[&ptr].params[i] {
+            Param::Immediate(_) | Param::Position(_) => format!("Self::addr({}, {})?", ptr, raw),
+            Param::Relative(_) => format!("self.rel({}, {})?", ptr, raw),
+        }
+    }
+
+    /// Returns the ranges of code cells as a pattern for matches!.
+    fn code_pattern(&self) -> String {
+        let mut ranges = vec![];
+        let mut addr = 0;
+        while addr < self.code.len() {
+            if !self.code[addr] {
+                addr += 1;
+                continue;
+            }
+            let start = addr;
+            while addr < self.code.len() && self.code[addr] {
+                addr += 1;
+            }
+            ranges.push(format!("{}..={}", start, addr - 1));
+        }
+        if ranges.is_empty() {
+            // An empty range, which matches nothing.
+            return "1..=0".to_string();
+        }
+        ranges.join(" | ")
+    }
+
+    /// Emits a write of `v` to the destination parameter. See code_write for the return value.
+    fn store(&self, out: &mut String, ptr: usize, i: usize) -> bool {
+        writeln!(out, "                    let d = {};", self.dest(ptr, i)).unwrap();
+        writeln!(out, "                    self.store(d, v);").unwrap();
+        self.code_write(out, ptr, i)
+    }
+
+    /// Emits the instruction at `ptr`, and returns whether it ends the block.
+    fn instruction(&self, out: &mut String, ptr: usize) -> bool {
+        let op = &self.ops[&ptr];
+        writeln!(out, "                {{").unwrap();
+        let ends_block = match op.op_code {
+            1 | 2 | 7 | 8 => {
+                writeln!(
+                    out,
+                    "                    let a: i64 = {};",
+                    self.value(ptr, 0)
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "                    let b: i64 = {};",
+                    self.value(ptr, 1)
+                )
+                .unwrap();
+                let result = match op.op_code {
+                    1 => format!("a.checked_add(b).ok_or(Self::overflow({}))?", ptr),
+                    2 => format!("a.checked_mul(b).ok_or(Self::overflow({}))?", ptr),
+                    7 => "(a < b) as i64".to_string(),
+                    _ => "(a == b) as i64".to_string(),
+                };
+                // The destination is checked before the arithmetic, as IntCodeComputer does.
+                writeln!(out, "                    let d = {};", self.dest(ptr, 2)).unwrap();
+                writeln!(out, "                    let v = {};", result).unwrap();
+                writeln!(out, "                    self.store(d, v);").unwrap();
+                self.code_write(out, ptr, 2)
+            }
+            3 => {
+                writeln!(
+                    out,
+                    "                    let v = match self.input.try_read() {{
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError {{ ptr: {}, kind }}),
+                    }};",
+                    ptr
+                )
+                .unwrap();
+                self.store(out, ptr, 0)
+            }
+            4 => {
+                writeln!(
+                    out,
+                    "                    let v: i64 = {};",
+                    self.value(ptr, 0)
+                )
+                .unwrap();
+                writeln!(out, "                    self.output.write(v);").unwrap();
+                false
+            }
+            5 | 6 => {
+                writeln!(
+                    out,
+                    "                    let c: i64 = {};",
+                    self.value(ptr, 0)
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "                    let t: i64 = {};",
+                    self.value(ptr, 1)
+                )
+                .unwrap();
+                let test = if op.op_code == 5 { "!=" } else { "==" };
+                writeln!(out, "                    if c {} 0 {{", test).unwrap();
+                writeln!(
+                    out,
+                    "                        self.pc = Self::addr({}, t)? as u64;",
+                    ptr
+                )
+                .unwrap();
+                writeln!(out, "                        continue;").unwrap();
+                writeln!(out, "                    }}").unwrap();
+                false
+            }
+            9 => {
+                writeln!(
+                    out,
+                    "                    let v: i64 = {};",
+                    self.value(ptr, 0)
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow({}))?;",
+                    ptr
+                )
+                .unwrap();
+                false
+            }
+            _ => {
+                writeln!(out, "                    self.pc = {};", ptr).unwrap();
+                writeln!(out, "                    return Ok(RunOutcome::Halted);").unwrap();
+                true
+            }
+        };
+        writeln!(out, "                }}").unwrap();
+        ends_block || op.op_code == 5 || op.op_code == 6
+    }
+
+    /// Emits the check that follows a write to `d` through parameter `i`. Writes into code switch
+    /// to the interpreter before the next instruction; this returns true if that happens for
+    /// certain, which ends the block.
+    fn code_write(&self, out: &mut String, ptr: usize, i: usize) -> bool {
+        let next = ptr + self.ops[&ptr].len();
+        match self.ops[&ptr].params[i] {
+            Param::Immediate(a) | Param::Position(a) if !self.live(ptr, i) => {
+                if a < 0 || a as usize >= self.code.len() || !self.code[a as usize] {
+                    return false;
+                }
+                writeln!(out, "                    self.pc = {};", next).unwrap();
+                writeln!(out, "                    return self.interpret();").unwrap();
+                true
+            }
+            _ => {
+                writeln!(out, "                    if is_code(d) {{").unwrap();
+                writeln!(out, "                        self.pc = {};", next).unwrap();
+                writeln!(out, "                        return self.interpret();").unwrap();
+                writeln!(out, "                    }}").unwrap();
+                false
+            }
+        }
+    }
+
+    fn blocks(&self, out: &mut String) {
+        for leader in self.leaders.iter() {
+            if !self.ops.contains_key(leader) {
+                continue;
+            }
+            writeln!(out, "            {} => {{", leader).unwrap();
+            let mut ptr = *leader;
+            loop {
+                if self.instruction(out, ptr) {
+                    if matches!(self.ops[&ptr].op_code, 5 | 6) {
+                        writeln!(out, "                self.pc = {};", ptr + 3).unwrap();
+                    }
+                    break;
+                }
+                ptr += self.ops[&ptr].len();
+                if self.leaders.contains(&ptr) || !self.ops.contains_key(&ptr) {
+                    writeln!(out, "                self.pc = {};", ptr).unwrap();
+                    break;
+                }
+            }
+            writeln!(out, "            }}").unwrap();
+        }
+    }
+}
+
+/// Returns Rust source for a struct that runs the program. See the module docs.
+pub fn transpile(program: &[i64], options: &Options) -> String {
+    let compiler = Compiler::new(program);
+    let mut out = String::new();
+    let name = &options.struct_name;
+    let krate = &options.crate_path;
+    write!(
+        out,
+        "// Generated from a {len} cell Intcode program by {krate}::int_code::transpile.
+
+use {krate}::int_code::{{
+    DataStream, ErrorKind, IntCodeComputer, IntCodeError, RunOutcome, MAX_ADDRESS,
+}};
+
+const PROGRAM: [i64; {len}] = [",
+        len = program.len(),
+        krate = krate,
+    )
+    .unwrap();
+    for (i, v) in program.iter().enumerate() {
+        if i % 12 == 0 {
+            out += "\n   ";
+        }
+        write!(out, " {},", literal(*v)).unwrap();
+    }
+    write!(
+        out,
+        "
+];
+
+/// Returns whether the cell holds a compiled instruction.
+fn is_code(addr: usize) -> bool {{
+    matches!(addr, {code})
+}}
+
+pub struct {name} {{
+    pub input: DataStream,
+    pub output: DataStream,
+    memory: Vec<i64>,
+    pc: u64,
+    rb: i64,
+    interpreter: Option<IntCodeComputer>,
+    crashed: Option<IntCodeError>,
+}}
+
+impl Default for {name} {{
+    fn default() -> Self {{
+        Self::with_memory(PROGRAM.to_vec())
+    }}
+}}
+
+impl {name} {{
+    pub fn new() -> Self {{
+        Self::default()
+    }}
+
+    /// Returns a machine for the program with some cells changed. If any of them hold compiled
+    /// instructions the whole run is interpreted.
+    pub fn with_memory(memory: Vec<i64>) -> Self {{
+        let patched = (0..PROGRAM.len()).any(|a| is_code(a) && memory.get(a) != Some(&PROGRAM[a]));
+        let mut machine = Self {{
+            input: DataStream::new(),
+            output: DataStream::new(),
+            memory,
+            pc: 0,
+            rb: 0,
+            interpreter: None,
+            crashed: None,
+        }};
+        if patched {{
+            machine.start_interpreter();
+        }}
+        machine
+    }}
+
+    /// Returns whether the machine has handed over to IntCodeComputer.
+    pub fn is_interpreted(&self) -> bool {{
+        self.interpreter.is_some()
+    }}
+
+    /// Returns the value at the address. Addresses past the end of memory read as 0.
+    pub fn read(&self, addr: u64) -> i64 {{
+        match &self.interpreter {{
+            Some(computer) => computer.memory().read(addr),
+            None => self.load(addr as usize),
+        }}
+    }}
+
+    /// Runs until the program halts or needs input, like IntCodeComputer::run_with_budget without
+    /// a budget. After an error every call returns the same error.
+    pub fn run(&mut self) -> Result<RunOutcome, IntCodeError> {{
+        if let Some(e) = self.crashed {{
+            return Err(e);
+        }}
+        let result = self.run_compiled();
+        if let Err(e) = result {{
+            self.crashed = Some(e);
+        }}
+        result
+    }}
+
+    fn start_interpreter(&mut self) {{
+        let memory = std::mem::take(&mut self.memory);
+        self.interpreter = Some(IntCodeComputer::from_state(memory, self.pc, self.rb));
+    }}
+
+    fn interpret(&mut self) -> Result<RunOutcome, IntCodeError> {{
+        if self.interpreter.is_none() {{
+            self.start_interpreter();
+        }}
+        let computer = self.interpreter.as_mut().unwrap();
+        std::mem::swap(&mut self.input, &mut computer.input);
+        let outcome = computer.run_with_budget(u64::MAX);
+        std::mem::swap(&mut self.input, &mut computer.input);
+        for v in computer.dump_output().read_all() {{
+            self.output.write(v);
+        }}
+        computer.clear_output();
+        outcome
+    }}
+
+    fn overflow(ptr: u64) -> IntCodeError {{
+        IntCodeError {{
+            ptr,
+            kind: ErrorKind::Overflow,
+        }}
+    }}
+
+    #[inline]
+    fn addr(ptr: u64, v: i64) -> Result<usize, IntCodeError> {{
+        let kind = if v < 0 {{
+            ErrorKind::NegativeAddress(v)
+        }} else if v > MAX_ADDRESS {{
+            ErrorKind::AddressOutOfRange(v)
+        }} else {{
+            return Ok(v as usize);
+        }};
+        Err(IntCodeError {{ ptr, kind }})
+    }}
+
+    #[inline]
+    fn rel(&self, ptr: u64, offset: i64) -> Result<usize, IntCodeError> {{
+        let v = offset.checked_add(self.rb).ok_or(Self::overflow(ptr))?;
+        Self::addr(ptr, v)
+    }}
+
+    #[inline]
+    fn load(&self, addr: usize) -> i64 {{
+        self.memory.get(addr).copied().unwrap_or(0)
+    }}
+
+    #[inline]
+    fn store(&mut self, addr: usize, v: i64) {{
+        if addr >= self.memory.len() {{
+            self.memory.resize(addr + 1, 0);
+        }}
+        self.memory[addr] = v;
+    }}
+
+    // A program without backward jumps never goes round the loop.
+    #[allow(clippy::never_loop)]
+    fn run_compiled(&mut self) -> Result<RunOutcome, IntCodeError> {{
+        if self.interpreter.is_some() {{
+            return self.interpret();
+        }}
+        loop {{
+            match self.pc {{
+",
+        code = compiler.code_pattern(),
+        name = name,
+    )
+    .unwrap();
+    compiler.blocks(&mut out);
+    out += "            _ => return self.interpret(),
+            }
+        }
+    }
+}
+";
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::int_code::transpile::{transpile, Options};
+    use crate::int_code::{IntCodeComputer, RunOutcome};
+    use crate::util;
+
+    // Each Intcode puzzle input compiled with transpile. Not every program needs every helper.
+    #[allow(dead_code)]
+    mod day02 {
+        include!("transpiled/day02.rs");
+    }
+    #[allow(dead_code)]
+    mod day05 {
+        include!("transpiled/day05.rs");
+    }
+    #[allow(dead_code)]
+    mod day07 {
+        include!("transpiled/day07.rs");
+    }
+    #[allow(dead_code)]
+    mod day09 {
+        include!("transpiled/day09.rs");
+    }
+
+    #[test]
+    fn test_fixtures_are_current() {
+        let options = Options {
+            struct_name: "Machine".to_string(),
+            crate_path: "crate".to_string(),
+        };
+        let update = env::var_os("AOC_UPDATE_FIXTURES").is_some();
+        for name in ["day02", "day05", "day07", "day09"].iter() {
+            let program = util::read_int_code_memory(format!("./input/{}.txt", name));
+            let source = transpile(&program, &options);
+            let path = format!("./src/int_code/transpiled/{}.rs", name);
+            if update {
+                fs::write(&path, &source).unwrap();
+            } else {
+                let fixture = fs::read_to_string(&path).unwrap();
+                assert!(
+                    fixture == source,
+                    "{} is out of date; run the tests with AOC_UPDATE_FIXTURES=1",
+                    path
+                );
+            }
+        }
+    }
+
+    fn interpret(program: Vec<i64>, input: &[i64]) -> (IntCodeComputer, Vec<i64>) {
+        let mut computer = IntCodeComputer::new(program);
+        for v in input {
+            computer.input.write(*v);
+        }
+        computer.run();
+        let output = computer.dump_output().read_all();
+        (computer, output)
+    }
+
+    #[test]
+    fn test_day02() {
+        let program = util::read_int_code_memory("./input/day02.txt");
+        let (computer, _) = interpret(program.clone(), &[]);
+        let mut machine = day02::Machine::new();
+        assert_eq!(machine.run(), Ok(RunOutcome::Halted));
+        assert_eq!(machine.read(0), computer.memory().read(0));
+
+        // Patching the noun and verb changes compiled instructions, so this one is interpreted.
+        let mut patched = program;
+        patched[1] = 12;
+        patched[2] = 2;
+        let (computer, _) = interpret(patched.clone(), &[]);
+        let mut machine = day02::Machine::with_memory(patched);
+        assert!(machine.is_interpreted());
+        assert_eq!(machine.run(), Ok(RunOutcome::Halted));
+        assert_eq!(machine.read(0), computer.memory().read(0));
+        assert_eq!(machine.read(0), 4484226);
+    }
+
+    #[test]
+    fn test_day05() {
+        let program = util::read_int_code_memory("./input/day05.txt");
+        for input in &[1, 5] {
+            let (_, expected) = interpret(program.clone(), &[*input]);
+            let mut machine = day05::Machine::new();
+            machine.input.write(*input);
+            assert_eq!(machine.run(), Ok(RunOutcome::Halted));
+            assert_eq!(machine.output.read_all(), expected);
+        }
+    }
+
+    /// Runs the day 7 amplifiers in a feedback loop, each either compiled or interpreted.
+    fn feedback_loop(phases: &[i64], compiled: bool) -> i64 {
+        let program = util::read_int_code_memory("./input/day07.txt");
+        let mut compiled_amps: Vec<day07::Machine> = vec![];
+        let mut interpreted_amps: Vec<IntCodeComputer> = vec![];
+        for phase in phases {
+            let mut machine = day07::Machine::new();
+            machine.input.write(*phase);
+            compiled_amps.push(machine);
+            let mut computer = IntCodeComputer::new(program.clone());
+            computer.input.write(*phase);
+            interpreted_amps.push(computer);
+        }
+        let mut signal = 0;
+        loop {
+            for i in 0..phases.len() {
+                let (outcome, mut output) = if compiled {
+                    let amp = &mut compiled_amps[i];
+                    amp.input.write(signal);
+                    (amp.run().unwrap(), amp.output.read_all())
+                } else {
+                    let amp = &mut interpreted_amps[i];
+                    amp.input.write(signal);
+                    let outcome = amp.run_with_budget(u64::MAX).unwrap();
+                    let output = amp.dump_output().read_all();
+                    amp.clear_output();
+                    (outcome, output)
+                };
+                signal = output.pop().unwrap_or(signal);
+                if i == phases.len() - 1 && outcome == RunOutcome::Halted {
+                    assert!(compiled_amps.iter().all(|m| !m.is_interpreted()));
+                    return signal;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_day07() {
+        let mut best = (0, 0);
+        for perm in util::Permutations::new(5) {
+            let phases: Vec<i64> = perm.iter().map(|p| *p as i64).collect();
+            let linear = |compiled: bool| {
+                phases.iter().fold(0, |signal, phase| {
+                    let output = if compiled {
+                        let mut machine = day07::Machine::new();
+                        machine.input.write(*phase);
+                        machine.input.write(signal);
+                        machine.run().unwrap();
+                        machine.output.read_all()
+                    } else {
+                        interpret(
+                            util::read_int_code_memory("./input/day07.txt"),
+                            &[*phase, signal],
+                        )
+                        .1
+                    };
+                    output[0]
+                })
+            };
+            let thrust = linear(true);
+            assert_eq!(thrust, linear(false));
+            best.0 = best.0.max(thrust);
+
+            let phases: Vec<i64> = phases.iter().map(|p| p + 5).collect();
+            let thrust = feedback_loop(&phases, true);
+            assert_eq!(thrust, feedback_loop(&phases, false));
+            best.1 = best.1.max(thrust);
+        }
+        assert_eq!(best, (199988, 17519904));
+    }
+
+    #[test]
+    fn test_day09() {
+        let program = util::read_int_code_memory("./input/day09.txt");
+        for input in &[1, 2] {
+            let (_, expected) = interpret(program.clone(), &[*input]);
+            let mut machine = day09::Machine::new();
+            machine.input.write(*input);
+            assert_eq!(machine.run(), Ok(RunOutcome::Halted));
+            assert!(!machine.is_interpreted());
+            assert_eq!(machine.output.read_all(), expected);
+        }
+    }
+
+    #[test]
+    fn test_transpile_text() {
+        // out 7; jt [5], 0; hlt, with a jump that only the interpreter can follow.
+        let source = transpile(&[104, 7, 1006, 6, 0, 99, 0], &Options::default());
+        assert!(source.contains("use aoc2019::int_code::{"));
+        assert!(source.contains("pub struct Machine {"));
+        assert!(source.contains("matches!(addr, 0..=5)"));
+    }
+}
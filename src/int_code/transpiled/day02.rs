@@ -0,0 +1,582 @@
+// Generated from a 161 cell Intcode program by crate::int_code::transpile.
+
+use crate::int_code::{
+    DataStream, ErrorKind, IntCodeComputer, IntCodeError, RunOutcome, MAX_ADDRESS,
+};
+
+const PROGRAM: [i64; 161] = [
+    1, 0, 0, 3, 1, 1, 2, 3, 1, 3, 4, 3,
+    1, 5, 0, 3, 2, 6, 1, 19, 1, 5, 19, 23,
+    2, 6, 23, 27, 1, 27, 5, 31, 2, 9, 31, 35,
+    1, 5, 35, 39, 2, 6, 39, 43, 2, 6, 43, 47,
+    1, 5, 47, 51, 2, 9, 51, 55, 1, 5, 55, 59,
+    1, 10, 59, 63, 1, 63, 6, 67, 1, 9, 67, 71,
+    1, 71, 6, 75, 1, 75, 13, 79, 2, 79, 13, 83,
+    2, 9, 83, 87, 1, 87, 5, 91, 1, 9, 91, 95,
+    2, 10, 95, 99, 1, 5, 99, 103, 1, 103, 9, 107,
+    1, 13, 107, 111, 2, 111, 10, 115, 1, 115, 5, 119,
+    2, 13, 119, 123, 1, 9, 123, 127, 1, 5, 127, 131,
+    2, 131, 6, 135, 1, 135, 5, 139, 1, 139, 6, 143,
+    1, 143, 6, 147, 1, 2, 147, 151, 1, 151, 5, 0,
+    99, 2, 14, 0, 0,
+];
+
+/// Returns whether the cell holds a compiled instruction.
+fn is_code(addr: usize) -> bool {
+    matches!(addr, 0..=2 | 4..=18 | 20..=22 | 24..=26 | 28..=30 | 32..=34 | 36..=38 | 40..=42 | 44..=46 | 48..=50 | 52..=54 | 56..=58 | 60..=62 | 64..=66 | 68..=70 | 72..=74 | 76..=78 | 80..=82 | 84..=86 | 88..=90 | 92..=94 | 96..=98 | 100..=102 | 104..=106 | 108..=110 | 112..=114 | 116..=118 | 120..=122 | 124..=126 | 128..=130 | 132..=134 | 136..=138 | 140..=142 | 144..=146 | 148..=150 | 152..=156)
+}
+
+pub struct Machine {
+    pub input: DataStream,
+    pub output: DataStream,
+    memory: Vec<i64>,
+    pc: u64,
+    rb: i64,
+    interpreter: Option<IntCodeComputer>,
+    crashed: Option<IntCodeError>,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::with_memory(PROGRAM.to_vec())
+    }
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a machine for the program with some cells changed. If any of them hold compiled
+    /// instructions the whole run is interpreted.
+    pub fn with_memory(memory: Vec<i64>) -> Self {
+        let patched = (0..PROGRAM.len()).any(|a| is_code(a) && memory.get(a) != Some(&PROGRAM[a]));
+        let mut machine = Self {
+            input: DataStream::new(),
+            output: DataStream::new(),
+            memory,
+            pc: 0,
+            rb: 0,
+            interpreter: None,
+            crashed: None,
+        };
+        if patched {
+            machine.start_interpreter();
+        }
+        machine
+    }
+
+    /// Returns whether the machine has handed over to IntCodeComputer.
+    pub fn is_interpreted(&self) -> bool {
+        self.interpreter.is_some()
+    }
+
+    /// Returns the value at the address. Addresses past the end of memory read as 0.
+    pub fn read(&self, addr: u64) -> i64 {
+        match &self.interpreter {
+            Some(computer) => computer.memory().read(addr),
+            None => self.load(addr as usize),
+        }
+    }
+
+    /// Runs until the program halts or needs input, like IntCodeComputer::run_with_budget without
+    /// a budget. After an error every call returns the same error.
+    pub fn run(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if let Some(e) = self.crashed {
+            return Err(e);
+        }
+        let result = self.run_compiled();
+        if let Err(e) = result {
+            self.crashed = Some(e);
+        }
+        result
+    }
+
+    fn start_interpreter(&mut self) {
+        let memory = std::mem::take(&mut self.memory);
+        self.interpreter = Some(IntCodeComputer::from_state(memory, self.pc, self.rb));
+    }
+
+    fn interpret(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_none() {
+            self.start_interpreter();
+        }
+        let computer = self.interpreter.as_mut().unwrap();
+        std::mem::swap(&mut self.input, &mut computer.input);
+        let outcome = computer.run_with_budget(u64::MAX);
+        std::mem::swap(&mut self.input, &mut computer.input);
+        for v in computer.dump_output().read_all() {
+            self.output.write(v);
+        }
+        computer.clear_output();
+        outcome
+    }
+
+    fn overflow(ptr: u64) -> IntCodeError {
+        IntCodeError {
+            ptr,
+            kind: ErrorKind::Overflow,
+        }
+    }
+
+    #[inline]
+    fn addr(ptr: u64, v: i64) -> Result<usize, IntCodeError> {
+        let kind = if v < 0 {
+            ErrorKind::NegativeAddress(v)
+        } else if v > MAX_ADDRESS {
+            ErrorKind::AddressOutOfRange(v)
+        } else {
+            return Ok(v as usize);
+        };
+        Err(IntCodeError { ptr, kind })
+    }
+
+    #[inline]
+    fn rel(&self, ptr: u64, offset: i64) -> Result<usize, IntCodeError> {
+        let v = offset.checked_add(self.rb).ok_or(Self::overflow(ptr))?;
+        Self::addr(ptr, v)
+    }
+
+    #[inline]
+    fn load(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    fn store(&mut self, addr: usize, v: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+        self.memory[addr] = v;
+    }
+
+    // A program without backward jumps never goes round the loop.
+    #[allow(clippy::never_loop)]
+    fn run_compiled(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_some() {
+            return self.interpret();
+        }
+        loop {
+            match self.pc {
+            0 => {
+                {
+                    let a: i64 = self.load(Self::addr(0, 0)?);
+                    let b: i64 = self.load(Self::addr(0, 0)?);
+                    let d = Self::addr(0, self.load(3))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(0))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 4;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(4, 1)?);
+                    let b: i64 = self.load(Self::addr(4, 2)?);
+                    let d = Self::addr(4, 3)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(4))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(8, 3)?);
+                    let b: i64 = self.load(Self::addr(8, 4)?);
+                    let d = Self::addr(8, 3)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(8))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(12, 5)?);
+                    let b: i64 = self.load(Self::addr(12, 0)?);
+                    let d = Self::addr(12, 3)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(12))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(16, 6)?);
+                    let b: i64 = self.load(Self::addr(16, 1)?);
+                    let d = Self::addr(16, self.load(19))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(16))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 20;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(20, 5)?);
+                    let b: i64 = self.load(Self::addr(20, 19)?);
+                    let d = Self::addr(20, self.load(23))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(20))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 24;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(24, 6)?);
+                    let b: i64 = self.load(Self::addr(24, 23)?);
+                    let d = Self::addr(24, self.load(27))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(24))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 28;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(28, 27)?);
+                    let b: i64 = self.load(Self::addr(28, 5)?);
+                    let d = Self::addr(28, self.load(31))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(28))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 32;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(32, 9)?);
+                    let b: i64 = self.load(Self::addr(32, 31)?);
+                    let d = Self::addr(32, self.load(35))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(32))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 36;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(36, 5)?);
+                    let b: i64 = self.load(Self::addr(36, 35)?);
+                    let d = Self::addr(36, self.load(39))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(36))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 40;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(40, 6)?);
+                    let b: i64 = self.load(Self::addr(40, 39)?);
+                    let d = Self::addr(40, self.load(43))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(40))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 44;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(44, 6)?);
+                    let b: i64 = self.load(Self::addr(44, 43)?);
+                    let d = Self::addr(44, self.load(47))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(44))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 48;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(48, 5)?);
+                    let b: i64 = self.load(Self::addr(48, 47)?);
+                    let d = Self::addr(48, self.load(51))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(48))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 52;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(52, 9)?);
+                    let b: i64 = self.load(Self::addr(52, 51)?);
+                    let d = Self::addr(52, self.load(55))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(52))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 56;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(56, 5)?);
+                    let b: i64 = self.load(Self::addr(56, 55)?);
+                    let d = Self::addr(56, self.load(59))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(56))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 60;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(60, 10)?);
+                    let b: i64 = self.load(Self::addr(60, 59)?);
+                    let d = Self::addr(60, self.load(63))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(60))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 64;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(64, 63)?);
+                    let b: i64 = self.load(Self::addr(64, 6)?);
+                    let d = Self::addr(64, self.load(67))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(64))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 68;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(68, 9)?);
+                    let b: i64 = self.load(Self::addr(68, 67)?);
+                    let d = Self::addr(68, self.load(71))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(68))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 72;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(72, 71)?);
+                    let b: i64 = self.load(Self::addr(72, 6)?);
+                    let d = Self::addr(72, self.load(75))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(72))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 76;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(76, 75)?);
+                    let b: i64 = self.load(Self::addr(76, 13)?);
+                    let d = Self::addr(76, self.load(79))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(76))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 80;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(80, 79)?);
+                    let b: i64 = self.load(Self::addr(80, 13)?);
+                    let d = Self::addr(80, self.load(83))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(80))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 84;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(84, 9)?);
+                    let b: i64 = self.load(Self::addr(84, 83)?);
+                    let d = Self::addr(84, self.load(87))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(84))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 88;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(88, 87)?);
+                    let b: i64 = self.load(Self::addr(88, 5)?);
+                    let d = Self::addr(88, self.load(91))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(88))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 92;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(92, 9)?);
+                    let b: i64 = self.load(Self::addr(92, 91)?);
+                    let d = Self::addr(92, self.load(95))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(92))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 96;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(96, 10)?);
+                    let b: i64 = self.load(Self::addr(96, 95)?);
+                    let d = Self::addr(96, self.load(99))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(96))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 100;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(100, 5)?);
+                    let b: i64 = self.load(Self::addr(100, 99)?);
+                    let d = Self::addr(100, self.load(103))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(100))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 104;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(104, 103)?);
+                    let b: i64 = self.load(Self::addr(104, 9)?);
+                    let d = Self::addr(104, self.load(107))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(104))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 108;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(108, 13)?);
+                    let b: i64 = self.load(Self::addr(108, 107)?);
+                    let d = Self::addr(108, self.load(111))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(108))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 112;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(112, 111)?);
+                    let b: i64 = self.load(Self::addr(112, 10)?);
+                    let d = Self::addr(112, self.load(115))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(112))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 116;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(116, 115)?);
+                    let b: i64 = self.load(Self::addr(116, 5)?);
+                    let d = Self::addr(116, self.load(119))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(116))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 120;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(120, 13)?);
+                    let b: i64 = self.load(Self::addr(120, 119)?);
+                    let d = Self::addr(120, self.load(123))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(120))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 124;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(124, 9)?);
+                    let b: i64 = self.load(Self::addr(124, 123)?);
+                    let d = Self::addr(124, self.load(127))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(124))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 128;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(128, 5)?);
+                    let b: i64 = self.load(Self::addr(128, 127)?);
+                    let d = Self::addr(128, self.load(131))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(128))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 132;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(132, 131)?);
+                    let b: i64 = self.load(Self::addr(132, 6)?);
+                    let d = Self::addr(132, self.load(135))?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(132))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 136;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(136, 135)?);
+                    let b: i64 = self.load(Self::addr(136, 5)?);
+                    let d = Self::addr(136, self.load(139))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(136))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 140;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(140, 139)?);
+                    let b: i64 = self.load(Self::addr(140, 6)?);
+                    let d = Self::addr(140, self.load(143))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(140))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 144;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(144, 143)?);
+                    let b: i64 = self.load(Self::addr(144, 6)?);
+                    let d = Self::addr(144, self.load(147))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(144))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 148;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(148, 2)?);
+                    let b: i64 = self.load(Self::addr(148, 147)?);
+                    let d = Self::addr(148, self.load(151))?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(148))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 152;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(152, 151)?);
+                    let b: i64 = self.load(Self::addr(152, 5)?);
+                    let d = Self::addr(152, 0)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(152))?;
+                    self.store(d, v);
+                    self.pc = 156;
+                    return self.interpret();
+                }
+            }
+            _ => return self.interpret(),
+            }
+        }
+    }
+}
@@ -0,0 +1,1721 @@
+// Generated from a 678 cell Intcode program by crate::int_code::transpile.
+
+use crate::int_code::{
+    DataStream, ErrorKind, IntCodeComputer, IntCodeError, RunOutcome, MAX_ADDRESS,
+};
+
+const PROGRAM: [i64; 678] = [
+    3, 225, 1, 225, 6, 6, 1100, 1, 238, 225, 104, 0,
+    1101, 65, 39, 225, 2, 14, 169, 224, 101, -2340, 224, 224,
+    4, 224, 1002, 223, 8, 223, 101, 7, 224, 224, 1, 224,
+    223, 223, 1001, 144, 70, 224, 101, -96, 224, 224, 4, 224,
+    1002, 223, 8, 223, 1001, 224, 2, 224, 1, 223, 224, 223,
+    1101, 92, 65, 225, 1102, 42, 8, 225, 1002, 61, 84, 224,
+    101, -7728, 224, 224, 4, 224, 102, 8, 223, 223, 1001, 224,
+    5, 224, 1, 223, 224, 223, 1102, 67, 73, 224, 1001, 224,
+    -4891, 224, 4, 224, 102, 8, 223, 223, 101, 4, 224, 224,
+    1, 224, 223, 223, 1102, 54, 12, 225, 102, 67, 114, 224,
+    101, -804, 224, 224, 4, 224, 102, 8, 223, 223, 1001, 224,
+    3, 224, 1, 224, 223, 223, 1101, 19, 79, 225, 1101, 62,
+    26, 225, 101, 57, 139, 224, 1001, 224, -76, 224, 4, 224,
+    1002, 223, 8, 223, 1001, 224, 2, 224, 1, 224, 223, 223,
+    1102, 60, 47, 225, 1101, 20, 62, 225, 1101, 47, 44, 224,
+    1001, 224, -91, 224, 4, 224, 1002, 223, 8, 223, 101, 2,
+    224, 224, 1, 224, 223, 223, 1, 66, 174, 224, 101, -70,
+    224, 224, 4, 224, 102, 8, 223, 223, 1001, 224, 6, 224,
+    1, 223, 224, 223, 4, 223, 99, 0, 0, 0, 677, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1105, 0,
+    99999, 1105, 227, 247, 1105, 1, 99999, 1005, 227, 99999, 1005, 0,
+    256, 1105, 1, 99999, 1106, 227, 99999, 1106, 0, 265, 1105, 1,
+    99999, 1006, 0, 99999, 1006, 227, 274, 1105, 1, 99999, 1105, 1,
+    280, 1105, 1, 99999, 1, 225, 225, 225, 1101, 294, 0, 0,
+    105, 1, 0, 1105, 1, 99999, 1106, 0, 300, 1105, 1, 99999,
+    1, 225, 225, 225, 1101, 314, 0, 0, 106, 0, 0, 1105,
+    1, 99999, 108, 226, 226, 224, 102, 2, 223, 223, 1005, 224,
+    329, 101, 1, 223, 223, 1107, 226, 677, 224, 1002, 223, 2,
+    223, 1005, 224, 344, 101, 1, 223, 223, 8, 226, 677, 224,
+    102, 2, 223, 223, 1006, 224, 359, 101, 1, 223, 223, 108,
+    677, 677, 224, 1002, 223, 2, 223, 1005, 224, 374, 1001, 223,
+    1, 223, 1108, 226, 677, 224, 1002, 223, 2, 223, 1005, 224,
+    389, 101, 1, 223, 223, 1007, 677, 677, 224, 1002, 223, 2,
+    223, 1006, 224, 404, 1001, 223, 1, 223, 1108, 677, 677, 224,
+    102, 2, 223, 223, 1006, 224, 419, 1001, 223, 1, 223, 1008,
+    226, 677, 224, 102, 2, 223, 223, 1005, 224, 434, 101, 1,
+    223, 223, 107, 677, 677, 224, 102, 2, 223, 223, 1006, 224,
+    449, 1001, 223, 1, 223, 1007, 226, 677, 224, 102, 2, 223,
+    223, 1005, 224, 464, 101, 1, 223, 223, 7, 677, 226, 224,
+    102, 2, 223, 223, 1005, 224, 479, 101, 1, 223, 223, 1007,
+    226, 226, 224, 102, 2, 223, 223, 1005, 224, 494, 101, 1,
+    223, 223, 7, 677, 677, 224, 102, 2, 223, 223, 1006, 224,
+    509, 101, 1, 223, 223, 1008, 677, 677, 224, 1002, 223, 2,
+    223, 1006, 224, 524, 1001, 223, 1, 223, 108, 226, 677, 224,
+    1002, 223, 2, 223, 1006, 224, 539, 101, 1, 223, 223, 8,
+    226, 226, 224, 102, 2, 223, 223, 1006, 224, 554, 101, 1,
+    223, 223, 8, 677, 226, 224, 102, 2, 223, 223, 1005, 224,
+    569, 1001, 223, 1, 223, 1108, 677, 226, 224, 1002, 223, 2,
+    223, 1006, 224, 584, 101, 1, 223, 223, 1107, 677, 226, 224,
+    1002, 223, 2, 223, 1005, 224, 599, 101, 1, 223, 223, 107,
+    226, 226, 224, 102, 2, 223, 223, 1006, 224, 614, 1001, 223,
+    1, 223, 7, 226, 677, 224, 102, 2, 223, 223, 1005, 224,
+    629, 1001, 223, 1, 223, 107, 677, 226, 224, 1002, 223, 2,
+    223, 1005, 224, 644, 1001, 223, 1, 223, 1107, 677, 677, 224,
+    102, 2, 223, 223, 1006, 224, 659, 101, 1, 223, 223, 1008,
+    226, 226, 224, 1002, 223, 2, 223, 1006, 224, 674, 1001, 223,
+    1, 223, 4, 223, 99, 226,
+];
+
+/// Returns whether the cell holds a compiled instruction.
+fn is_code(addr: usize) -> bool {
+    matches!(addr, 0..=5 | 7..=10 | 12..=222 | 238..=676)
+}
+
+pub struct Machine {
+    pub input: DataStream,
+    pub output: DataStream,
+    memory: Vec<i64>,
+    pc: u64,
+    rb: i64,
+    interpreter: Option<IntCodeComputer>,
+    crashed: Option<IntCodeError>,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::with_memory(PROGRAM.to_vec())
+    }
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a machine for the program with some cells changed. If any of them hold compiled
+    /// instructions the whole run is interpreted.
+    pub fn with_memory(memory: Vec<i64>) -> Self {
+        let patched = (0..PROGRAM.len()).any(|a| is_code(a) && memory.get(a) != Some(&PROGRAM[a]));
+        let mut machine = Self {
+            input: DataStream::new(),
+            output: DataStream::new(),
+            memory,
+            pc: 0,
+            rb: 0,
+            interpreter: None,
+            crashed: None,
+        };
+        if patched {
+            machine.start_interpreter();
+        }
+        machine
+    }
+
+    /// Returns whether the machine has handed over to IntCodeComputer.
+    pub fn is_interpreted(&self) -> bool {
+        self.interpreter.is_some()
+    }
+
+    /// Returns the value at the address. Addresses past the end of memory read as 0.
+    pub fn read(&self, addr: u64) -> i64 {
+        match &self.interpreter {
+            Some(computer) => computer.memory().read(addr),
+            None => self.load(addr as usize),
+        }
+    }
+
+    /// Runs until the program halts or needs input, like IntCodeComputer::run_with_budget without
+    /// a budget. After an error every call returns the same error.
+    pub fn run(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if let Some(e) = self.crashed {
+            return Err(e);
+        }
+        let result = self.run_compiled();
+        if let Err(e) = result {
+            self.crashed = Some(e);
+        }
+        result
+    }
+
+    fn start_interpreter(&mut self) {
+        let memory = std::mem::take(&mut self.memory);
+        self.interpreter = Some(IntCodeComputer::from_state(memory, self.pc, self.rb));
+    }
+
+    fn interpret(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_none() {
+            self.start_interpreter();
+        }
+        let computer = self.interpreter.as_mut().unwrap();
+        std::mem::swap(&mut self.input, &mut computer.input);
+        let outcome = computer.run_with_budget(u64::MAX);
+        std::mem::swap(&mut self.input, &mut computer.input);
+        for v in computer.dump_output().read_all() {
+            self.output.write(v);
+        }
+        computer.clear_output();
+        outcome
+    }
+
+    fn overflow(ptr: u64) -> IntCodeError {
+        IntCodeError {
+            ptr,
+            kind: ErrorKind::Overflow,
+        }
+    }
+
+    #[inline]
+    fn addr(ptr: u64, v: i64) -> Result<usize, IntCodeError> {
+        let kind = if v < 0 {
+            ErrorKind::NegativeAddress(v)
+        } else if v > MAX_ADDRESS {
+            ErrorKind::AddressOutOfRange(v)
+        } else {
+            return Ok(v as usize);
+        };
+        Err(IntCodeError { ptr, kind })
+    }
+
+    #[inline]
+    fn rel(&self, ptr: u64, offset: i64) -> Result<usize, IntCodeError> {
+        let v = offset.checked_add(self.rb).ok_or(Self::overflow(ptr))?;
+        Self::addr(ptr, v)
+    }
+
+    #[inline]
+    fn load(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    fn store(&mut self, addr: usize, v: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+        self.memory[addr] = v;
+    }
+
+    // A program without backward jumps never goes round the loop.
+    #[allow(clippy::never_loop)]
+    fn run_compiled(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_some() {
+            return self.interpret();
+        }
+        loop {
+            match self.pc {
+            0 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 0, kind }),
+                    };
+                    let d = Self::addr(0, 225)?;
+                    self.store(d, v);
+                }
+                self.pc = 2;
+            }
+            2 => {
+                {
+                    let a: i64 = self.load(Self::addr(2, 225)?);
+                    let b: i64 = self.load(Self::addr(2, 6)?);
+                    let d = Self::addr(2, 6)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(2))?;
+                    self.store(d, v);
+                }
+                self.pc = 6;
+            }
+            7 => {
+                {
+                    let a: i64 = self.load(Self::addr(7, 238)?);
+                    let b: i64 = self.load(Self::addr(7, 225)?);
+                    let d = Self::addr(7, 104)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(7))?;
+                    self.store(d, v);
+                    self.pc = 11;
+                    return self.interpret();
+                }
+            }
+            12 => {
+                {
+                    let a: i64 = 65;
+                    let b: i64 = 39;
+                    let d = Self::addr(12, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(12))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(16, 14)?);
+                    let b: i64 = self.load(Self::addr(16, 169)?);
+                    let d = Self::addr(16, 224)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(16))?;
+                    self.store(d, v);
+                }
+                self.pc = 20;
+            }
+            20 => {
+                {
+                    let a: i64 = -2340;
+                    let b: i64 = self.load(Self::addr(20, 224)?);
+                    let d = Self::addr(20, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(20))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(24, 224)?);
+                    self.output.write(v);
+                }
+                self.pc = 26;
+            }
+            26 => {
+                {
+                    let a: i64 = self.load(Self::addr(26, 223)?);
+                    let b: i64 = 8;
+                    let d = Self::addr(26, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(26))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 7;
+                    let b: i64 = self.load(Self::addr(30, 224)?);
+                    let d = Self::addr(30, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(30))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(34, 224)?);
+                    let b: i64 = self.load(Self::addr(34, 223)?);
+                    let d = Self::addr(34, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(34))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(38, 144)?);
+                    let b: i64 = 70;
+                    let d = Self::addr(38, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(38))?;
+                    self.store(d, v);
+                }
+                self.pc = 42;
+            }
+            42 => {
+                {
+                    let a: i64 = -96;
+                    let b: i64 = self.load(Self::addr(42, 224)?);
+                    let d = Self::addr(42, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(42))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(46, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(48, 223)?);
+                    let b: i64 = 8;
+                    let d = Self::addr(48, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(48))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(52, 224)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(52, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(52))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(56, 223)?);
+                    let b: i64 = self.load(Self::addr(56, 224)?);
+                    let d = Self::addr(56, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(56))?;
+                    self.store(d, v);
+                }
+                self.pc = 60;
+            }
+            60 => {
+                {
+                    let a: i64 = 92;
+                    let b: i64 = 65;
+                    let d = Self::addr(60, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(60))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 42;
+                    let b: i64 = 8;
+                    let d = Self::addr(64, 225)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(64))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(68, 61)?);
+                    let b: i64 = 84;
+                    let d = Self::addr(68, 224)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(68))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = -7728;
+                    let b: i64 = self.load(Self::addr(72, 224)?);
+                    let d = Self::addr(72, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(72))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(76, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = 8;
+                    let b: i64 = self.load(Self::addr(78, 223)?);
+                    let d = Self::addr(78, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(78))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(82, 224)?);
+                    let b: i64 = 5;
+                    let d = Self::addr(82, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(82))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(86, 223)?);
+                    let b: i64 = self.load(Self::addr(86, 224)?);
+                    let d = Self::addr(86, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(86))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 67;
+                    let b: i64 = 73;
+                    let d = Self::addr(90, 224)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(90))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(94, 224)?);
+                    let b: i64 = -4891;
+                    let d = Self::addr(94, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(94))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(98, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = 8;
+                    let b: i64 = self.load(Self::addr(100, 223)?);
+                    let d = Self::addr(100, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(100))?;
+                    self.store(d, v);
+                }
+                self.pc = 104;
+            }
+            104 => {
+                {
+                    let a: i64 = 4;
+                    let b: i64 = self.load(Self::addr(104, 224)?);
+                    let d = Self::addr(104, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(104))?;
+                    self.store(d, v);
+                }
+                self.pc = 108;
+            }
+            108 => {
+                {
+                    let a: i64 = self.load(Self::addr(108, 224)?);
+                    let b: i64 = self.load(Self::addr(108, 223)?);
+                    let d = Self::addr(108, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(108))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 54;
+                    let b: i64 = 12;
+                    let d = Self::addr(112, 225)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(112))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 67;
+                    let b: i64 = self.load(Self::addr(116, 114)?);
+                    let d = Self::addr(116, 224)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(116))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = -804;
+                    let b: i64 = self.load(Self::addr(120, 224)?);
+                    let d = Self::addr(120, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(120))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(124, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = 8;
+                    let b: i64 = self.load(Self::addr(126, 223)?);
+                    let d = Self::addr(126, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(126))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(130, 224)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(130, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(130))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(134, 224)?);
+                    let b: i64 = self.load(Self::addr(134, 223)?);
+                    let d = Self::addr(134, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(134))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 19;
+                    let b: i64 = 79;
+                    let d = Self::addr(138, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(138))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 62;
+                    let b: i64 = 26;
+                    let d = Self::addr(142, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(142))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 57;
+                    let b: i64 = self.load(Self::addr(146, 139)?);
+                    let d = Self::addr(146, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(146))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(150, 224)?);
+                    let b: i64 = -76;
+                    let d = Self::addr(150, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(150))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(154, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(156, 223)?);
+                    let b: i64 = 8;
+                    let d = Self::addr(156, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(156))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(160, 224)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(160, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(160))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(164, 224)?);
+                    let b: i64 = self.load(Self::addr(164, 223)?);
+                    let d = Self::addr(164, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(164))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 60;
+                    let b: i64 = 47;
+                    let d = Self::addr(168, 225)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(168))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 20;
+                    let b: i64 = 62;
+                    let d = Self::addr(172, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(172))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 47;
+                    let b: i64 = 44;
+                    let d = Self::addr(176, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(176))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(180, 224)?);
+                    let b: i64 = -91;
+                    let d = Self::addr(180, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(180))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(184, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(186, 223)?);
+                    let b: i64 = 8;
+                    let d = Self::addr(186, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(186))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(190, 224)?);
+                    let d = Self::addr(190, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(190))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(194, 224)?);
+                    let b: i64 = self.load(Self::addr(194, 223)?);
+                    let d = Self::addr(194, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(194))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(198, 66)?);
+                    let b: i64 = self.load(Self::addr(198, 174)?);
+                    let d = Self::addr(198, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(198))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = -70;
+                    let b: i64 = self.load(Self::addr(202, 224)?);
+                    let d = Self::addr(202, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(202))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(206, 224)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = 8;
+                    let b: i64 = self.load(Self::addr(208, 223)?);
+                    let d = Self::addr(208, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(208))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(212, 224)?);
+                    let b: i64 = 6;
+                    let d = Self::addr(212, 224)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(212))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(216, 223)?);
+                    let b: i64 = self.load(Self::addr(216, 224)?);
+                    let d = Self::addr(216, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(216))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(220, 223)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 222;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            238 => {
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(238, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 241;
+            }
+            241 => {
+                {
+                    let c: i64 = 227;
+                    let t: i64 = 247;
+                    if c != 0 {
+                        self.pc = Self::addr(241, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 244;
+            }
+            244 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(244, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 247;
+            }
+            247 => {
+                {
+                    let c: i64 = self.load(Self::addr(247, 227)?);
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(247, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 250;
+            }
+            250 => {
+                {
+                    let c: i64 = self.load(Self::addr(250, 0)?);
+                    let t: i64 = 256;
+                    if c != 0 {
+                        self.pc = Self::addr(250, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 253;
+            }
+            253 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(253, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 256;
+            }
+            256 => {
+                {
+                    let c: i64 = 227;
+                    let t: i64 = 99999;
+                    if c == 0 {
+                        self.pc = Self::addr(256, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 259;
+            }
+            259 => {
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 265;
+                    if c == 0 {
+                        self.pc = Self::addr(259, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 262;
+            }
+            262 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(262, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 265;
+            }
+            265 => {
+                {
+                    let c: i64 = self.load(Self::addr(265, 0)?);
+                    let t: i64 = 99999;
+                    if c == 0 {
+                        self.pc = Self::addr(265, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 268;
+            }
+            268 => {
+                {
+                    let c: i64 = self.load(Self::addr(268, 227)?);
+                    let t: i64 = 274;
+                    if c == 0 {
+                        self.pc = Self::addr(268, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 271;
+            }
+            271 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(271, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 274;
+            }
+            274 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 280;
+                    if c != 0 {
+                        self.pc = Self::addr(274, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 277;
+            }
+            277 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(277, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 280;
+            }
+            280 => {
+                {
+                    let a: i64 = self.load(Self::addr(280, 225)?);
+                    let b: i64 = self.load(Self::addr(280, 225)?);
+                    let d = Self::addr(280, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(280))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 294;
+                    let b: i64 = 0;
+                    let d = Self::addr(284, 0)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(284))?;
+                    self.store(d, v);
+                    self.pc = 288;
+                    return self.interpret();
+                }
+            }
+            291 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(291, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 294;
+            }
+            294 => {
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 300;
+                    if c == 0 {
+                        self.pc = Self::addr(294, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 297;
+            }
+            297 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(297, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 300;
+            }
+            300 => {
+                {
+                    let a: i64 = self.load(Self::addr(300, 225)?);
+                    let b: i64 = self.load(Self::addr(300, 225)?);
+                    let d = Self::addr(300, 225)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(300))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 314;
+                    let b: i64 = 0;
+                    let d = Self::addr(304, 0)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(304))?;
+                    self.store(d, v);
+                    self.pc = 308;
+                    return self.interpret();
+                }
+            }
+            311 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 99999;
+                    if c != 0 {
+                        self.pc = Self::addr(311, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 314;
+            }
+            314 => {
+                {
+                    let a: i64 = 226;
+                    let b: i64 = self.load(Self::addr(314, 226)?);
+                    let d = Self::addr(314, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(318, 223)?);
+                    let d = Self::addr(318, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(318))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(322, 224)?);
+                    let t: i64 = 329;
+                    if c != 0 {
+                        self.pc = Self::addr(322, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 325;
+            }
+            325 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(325, 223)?);
+                    let d = Self::addr(325, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(325))?;
+                    self.store(d, v);
+                }
+                self.pc = 329;
+            }
+            329 => {
+                {
+                    let a: i64 = 226;
+                    let b: i64 = 677;
+                    let d = Self::addr(329, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(333, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(333, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(333))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(337, 224)?);
+                    let t: i64 = 344;
+                    if c != 0 {
+                        self.pc = Self::addr(337, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 340;
+            }
+            340 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(340, 223)?);
+                    let d = Self::addr(340, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(340))?;
+                    self.store(d, v);
+                }
+                self.pc = 344;
+            }
+            344 => {
+                {
+                    let a: i64 = self.load(Self::addr(344, 226)?);
+                    let b: i64 = self.load(Self::addr(344, 677)?);
+                    let d = Self::addr(344, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(348, 223)?);
+                    let d = Self::addr(348, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(348))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(352, 224)?);
+                    let t: i64 = 359;
+                    if c == 0 {
+                        self.pc = Self::addr(352, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 355;
+            }
+            355 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(355, 223)?);
+                    let d = Self::addr(355, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(355))?;
+                    self.store(d, v);
+                }
+                self.pc = 359;
+            }
+            359 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = self.load(Self::addr(359, 677)?);
+                    let d = Self::addr(359, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(363, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(363, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(363))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(367, 224)?);
+                    let t: i64 = 374;
+                    if c != 0 {
+                        self.pc = Self::addr(367, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 370;
+            }
+            370 => {
+                {
+                    let a: i64 = self.load(Self::addr(370, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(370, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(370))?;
+                    self.store(d, v);
+                }
+                self.pc = 374;
+            }
+            374 => {
+                {
+                    let a: i64 = 226;
+                    let b: i64 = 677;
+                    let d = Self::addr(374, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(378, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(378, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(378))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(382, 224)?);
+                    let t: i64 = 389;
+                    if c != 0 {
+                        self.pc = Self::addr(382, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 385;
+            }
+            385 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(385, 223)?);
+                    let d = Self::addr(385, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(385))?;
+                    self.store(d, v);
+                }
+                self.pc = 389;
+            }
+            389 => {
+                {
+                    let a: i64 = self.load(Self::addr(389, 677)?);
+                    let b: i64 = 677;
+                    let d = Self::addr(389, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(393, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(393, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(393))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(397, 224)?);
+                    let t: i64 = 404;
+                    if c == 0 {
+                        self.pc = Self::addr(397, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 400;
+            }
+            400 => {
+                {
+                    let a: i64 = self.load(Self::addr(400, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(400, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(400))?;
+                    self.store(d, v);
+                }
+                self.pc = 404;
+            }
+            404 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = 677;
+                    let d = Self::addr(404, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(408, 223)?);
+                    let d = Self::addr(408, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(408))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(412, 224)?);
+                    let t: i64 = 419;
+                    if c == 0 {
+                        self.pc = Self::addr(412, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 415;
+            }
+            415 => {
+                {
+                    let a: i64 = self.load(Self::addr(415, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(415, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(415))?;
+                    self.store(d, v);
+                }
+                self.pc = 419;
+            }
+            419 => {
+                {
+                    let a: i64 = self.load(Self::addr(419, 226)?);
+                    let b: i64 = 677;
+                    let d = Self::addr(419, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(423, 223)?);
+                    let d = Self::addr(423, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(423))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(427, 224)?);
+                    let t: i64 = 434;
+                    if c != 0 {
+                        self.pc = Self::addr(427, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 430;
+            }
+            430 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(430, 223)?);
+                    let d = Self::addr(430, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(430))?;
+                    self.store(d, v);
+                }
+                self.pc = 434;
+            }
+            434 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = self.load(Self::addr(434, 677)?);
+                    let d = Self::addr(434, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(438, 223)?);
+                    let d = Self::addr(438, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(438))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(442, 224)?);
+                    let t: i64 = 449;
+                    if c == 0 {
+                        self.pc = Self::addr(442, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 445;
+            }
+            445 => {
+                {
+                    let a: i64 = self.load(Self::addr(445, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(445, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(445))?;
+                    self.store(d, v);
+                }
+                self.pc = 449;
+            }
+            449 => {
+                {
+                    let a: i64 = self.load(Self::addr(449, 226)?);
+                    let b: i64 = 677;
+                    let d = Self::addr(449, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(453, 223)?);
+                    let d = Self::addr(453, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(453))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(457, 224)?);
+                    let t: i64 = 464;
+                    if c != 0 {
+                        self.pc = Self::addr(457, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 460;
+            }
+            460 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(460, 223)?);
+                    let d = Self::addr(460, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(460))?;
+                    self.store(d, v);
+                }
+                self.pc = 464;
+            }
+            464 => {
+                {
+                    let a: i64 = self.load(Self::addr(464, 677)?);
+                    let b: i64 = self.load(Self::addr(464, 226)?);
+                    let d = Self::addr(464, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(468, 223)?);
+                    let d = Self::addr(468, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(468))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(472, 224)?);
+                    let t: i64 = 479;
+                    if c != 0 {
+                        self.pc = Self::addr(472, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 475;
+            }
+            475 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(475, 223)?);
+                    let d = Self::addr(475, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(475))?;
+                    self.store(d, v);
+                }
+                self.pc = 479;
+            }
+            479 => {
+                {
+                    let a: i64 = self.load(Self::addr(479, 226)?);
+                    let b: i64 = 226;
+                    let d = Self::addr(479, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(483, 223)?);
+                    let d = Self::addr(483, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(483))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(487, 224)?);
+                    let t: i64 = 494;
+                    if c != 0 {
+                        self.pc = Self::addr(487, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 490;
+            }
+            490 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(490, 223)?);
+                    let d = Self::addr(490, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(490))?;
+                    self.store(d, v);
+                }
+                self.pc = 494;
+            }
+            494 => {
+                {
+                    let a: i64 = self.load(Self::addr(494, 677)?);
+                    let b: i64 = self.load(Self::addr(494, 677)?);
+                    let d = Self::addr(494, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(498, 223)?);
+                    let d = Self::addr(498, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(498))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(502, 224)?);
+                    let t: i64 = 509;
+                    if c == 0 {
+                        self.pc = Self::addr(502, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 505;
+            }
+            505 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(505, 223)?);
+                    let d = Self::addr(505, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(505))?;
+                    self.store(d, v);
+                }
+                self.pc = 509;
+            }
+            509 => {
+                {
+                    let a: i64 = self.load(Self::addr(509, 677)?);
+                    let b: i64 = 677;
+                    let d = Self::addr(509, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(513, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(513, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(513))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(517, 224)?);
+                    let t: i64 = 524;
+                    if c == 0 {
+                        self.pc = Self::addr(517, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 520;
+            }
+            520 => {
+                {
+                    let a: i64 = self.load(Self::addr(520, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(520, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(520))?;
+                    self.store(d, v);
+                }
+                self.pc = 524;
+            }
+            524 => {
+                {
+                    let a: i64 = 226;
+                    let b: i64 = self.load(Self::addr(524, 677)?);
+                    let d = Self::addr(524, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(528, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(528, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(528))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(532, 224)?);
+                    let t: i64 = 539;
+                    if c == 0 {
+                        self.pc = Self::addr(532, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 535;
+            }
+            535 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(535, 223)?);
+                    let d = Self::addr(535, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(535))?;
+                    self.store(d, v);
+                }
+                self.pc = 539;
+            }
+            539 => {
+                {
+                    let a: i64 = self.load(Self::addr(539, 226)?);
+                    let b: i64 = self.load(Self::addr(539, 226)?);
+                    let d = Self::addr(539, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(543, 223)?);
+                    let d = Self::addr(543, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(543))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(547, 224)?);
+                    let t: i64 = 554;
+                    if c == 0 {
+                        self.pc = Self::addr(547, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 550;
+            }
+            550 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(550, 223)?);
+                    let d = Self::addr(550, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(550))?;
+                    self.store(d, v);
+                }
+                self.pc = 554;
+            }
+            554 => {
+                {
+                    let a: i64 = self.load(Self::addr(554, 677)?);
+                    let b: i64 = self.load(Self::addr(554, 226)?);
+                    let d = Self::addr(554, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(558, 223)?);
+                    let d = Self::addr(558, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(558))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(562, 224)?);
+                    let t: i64 = 569;
+                    if c != 0 {
+                        self.pc = Self::addr(562, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 565;
+            }
+            565 => {
+                {
+                    let a: i64 = self.load(Self::addr(565, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(565, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(565))?;
+                    self.store(d, v);
+                }
+                self.pc = 569;
+            }
+            569 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = 226;
+                    let d = Self::addr(569, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(573, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(573, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(573))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(577, 224)?);
+                    let t: i64 = 584;
+                    if c == 0 {
+                        self.pc = Self::addr(577, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 580;
+            }
+            580 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(580, 223)?);
+                    let d = Self::addr(580, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(580))?;
+                    self.store(d, v);
+                }
+                self.pc = 584;
+            }
+            584 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = 226;
+                    let d = Self::addr(584, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(588, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(588, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(588))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(592, 224)?);
+                    let t: i64 = 599;
+                    if c != 0 {
+                        self.pc = Self::addr(592, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 595;
+            }
+            595 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(595, 223)?);
+                    let d = Self::addr(595, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(595))?;
+                    self.store(d, v);
+                }
+                self.pc = 599;
+            }
+            599 => {
+                {
+                    let a: i64 = 226;
+                    let b: i64 = self.load(Self::addr(599, 226)?);
+                    let d = Self::addr(599, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(603, 223)?);
+                    let d = Self::addr(603, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(603))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(607, 224)?);
+                    let t: i64 = 614;
+                    if c == 0 {
+                        self.pc = Self::addr(607, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 610;
+            }
+            610 => {
+                {
+                    let a: i64 = self.load(Self::addr(610, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(610, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(610))?;
+                    self.store(d, v);
+                }
+                self.pc = 614;
+            }
+            614 => {
+                {
+                    let a: i64 = self.load(Self::addr(614, 226)?);
+                    let b: i64 = self.load(Self::addr(614, 677)?);
+                    let d = Self::addr(614, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(618, 223)?);
+                    let d = Self::addr(618, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(618))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(622, 224)?);
+                    let t: i64 = 629;
+                    if c != 0 {
+                        self.pc = Self::addr(622, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 625;
+            }
+            625 => {
+                {
+                    let a: i64 = self.load(Self::addr(625, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(625, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(625))?;
+                    self.store(d, v);
+                }
+                self.pc = 629;
+            }
+            629 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = self.load(Self::addr(629, 226)?);
+                    let d = Self::addr(629, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(633, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(633, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(633))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(637, 224)?);
+                    let t: i64 = 644;
+                    if c != 0 {
+                        self.pc = Self::addr(637, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 640;
+            }
+            640 => {
+                {
+                    let a: i64 = self.load(Self::addr(640, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(640, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(640))?;
+                    self.store(d, v);
+                }
+                self.pc = 644;
+            }
+            644 => {
+                {
+                    let a: i64 = 677;
+                    let b: i64 = 677;
+                    let d = Self::addr(644, 224)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(648, 223)?);
+                    let d = Self::addr(648, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(648))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(652, 224)?);
+                    let t: i64 = 659;
+                    if c == 0 {
+                        self.pc = Self::addr(652, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 655;
+            }
+            655 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(655, 223)?);
+                    let d = Self::addr(655, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(655))?;
+                    self.store(d, v);
+                }
+                self.pc = 659;
+            }
+            659 => {
+                {
+                    let a: i64 = self.load(Self::addr(659, 226)?);
+                    let b: i64 = 226;
+                    let d = Self::addr(659, 224)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(663, 223)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(663, 223)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(663))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(667, 224)?);
+                    let t: i64 = 674;
+                    if c == 0 {
+                        self.pc = Self::addr(667, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 670;
+            }
+            670 => {
+                {
+                    let a: i64 = self.load(Self::addr(670, 223)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(670, 223)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(670))?;
+                    self.store(d, v);
+                }
+                self.pc = 674;
+            }
+            674 => {
+                {
+                    let v: i64 = self.load(Self::addr(674, 223)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 676;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            _ => return self.interpret(),
+            }
+        }
+    }
+}
@@ -0,0 +1,1592 @@
+// Generated from a 511 cell Intcode program by crate::int_code::transpile.
+
+use crate::int_code::{
+    DataStream, ErrorKind, IntCodeComputer, IntCodeError, RunOutcome, MAX_ADDRESS,
+};
+
+const PROGRAM: [i64; 511] = [
+    3, 8, 1001, 8, 10, 8, 105, 1, 0, 0, 21, 38,
+    59, 76, 89, 106, 187, 268, 349, 430, 99999, 3, 9, 1002,
+    9, 3, 9, 101, 2, 9, 9, 1002, 9, 4, 9, 4,
+    9, 99, 3, 9, 1001, 9, 5, 9, 1002, 9, 5, 9,
+    1001, 9, 2, 9, 1002, 9, 3, 9, 4, 9, 99, 3,
+    9, 1001, 9, 4, 9, 102, 4, 9, 9, 1001, 9, 3,
+    9, 4, 9, 99, 3, 9, 101, 4, 9, 9, 1002, 9,
+    5, 9, 4, 9, 99, 3, 9, 1002, 9, 3, 9, 101,
+    5, 9, 9, 1002, 9, 3, 9, 4, 9, 99, 3, 9,
+    102, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9,
+    4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9,
+    101, 2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9,
+    4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9,
+    101, 1, 9, 9, 4, 9, 3, 9, 1001, 9, 1, 9,
+    4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9,
+    101, 2, 9, 9, 4, 9, 99, 3, 9, 1002, 9, 2,
+    9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3,
+    9, 1002, 9, 2, 9, 4, 9, 3, 9, 101, 1, 9,
+    9, 4, 9, 3, 9, 102, 2, 9, 9, 4, 9, 3,
+    9, 102, 2, 9, 9, 4, 9, 3, 9, 101, 2, 9,
+    9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9, 3,
+    9, 102, 2, 9, 9, 4, 9, 3, 9, 1001, 9, 2,
+    9, 4, 9, 99, 3, 9, 1002, 9, 2, 9, 4, 9,
+    3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 101, 1,
+    9, 9, 4, 9, 3, 9, 101, 2, 9, 9, 4, 9,
+    3, 9, 101, 2, 9, 9, 4, 9, 3, 9, 102, 2,
+    9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9,
+    3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 1001, 9,
+    1, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4, 9,
+    99, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9, 102,
+    2, 9, 9, 4, 9, 3, 9, 1001, 9, 2, 9, 4,
+    9, 3, 9, 102, 2, 9, 9, 4, 9, 3, 9, 101,
+    2, 9, 9, 4, 9, 3, 9, 1002, 9, 2, 9, 4,
+    9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9, 1002,
+    9, 2, 9, 4, 9, 3, 9, 101, 1, 9, 9, 4,
+    9, 3, 9, 101, 1, 9, 9, 4, 9, 99, 3, 9,
+    101, 2, 9, 9, 4, 9, 3, 9, 102, 2, 9, 9,
+    4, 9, 3, 9, 1002, 9, 2, 9, 4, 9, 3, 9,
+    1001, 9, 2, 9, 4, 9, 3, 9, 1001, 9, 2, 9,
+    4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9,
+    1001, 9, 1, 9, 4, 9, 3, 9, 1001, 9, 2, 9,
+    4, 9, 3, 9, 1001, 9, 2, 9, 4, 9, 3, 9,
+    102, 2, 9, 9, 4, 9, 99,
+];
+
+/// Returns whether the cell holds a compiled instruction.
+fn is_code(addr: usize) -> bool {
+    matches!(addr, 0..=7 | 21..=510)
+}
+
+pub struct Machine {
+    pub input: DataStream,
+    pub output: DataStream,
+    memory: Vec<i64>,
+    pc: u64,
+    rb: i64,
+    interpreter: Option<IntCodeComputer>,
+    crashed: Option<IntCodeError>,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::with_memory(PROGRAM.to_vec())
+    }
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a machine for the program with some cells changed. If any of them hold compiled
+    /// instructions the whole run is interpreted.
+    pub fn with_memory(memory: Vec<i64>) -> Self {
+        let patched = (0..PROGRAM.len()).any(|a| is_code(a) && memory.get(a) != Some(&PROGRAM[a]));
+        let mut machine = Self {
+            input: DataStream::new(),
+            output: DataStream::new(),
+            memory,
+            pc: 0,
+            rb: 0,
+            interpreter: None,
+            crashed: None,
+        };
+        if patched {
+            machine.start_interpreter();
+        }
+        machine
+    }
+
+    /// Returns whether the machine has handed over to IntCodeComputer.
+    pub fn is_interpreted(&self) -> bool {
+        self.interpreter.is_some()
+    }
+
+    /// Returns the value at the address. Addresses past the end of memory read as 0.
+    pub fn read(&self, addr: u64) -> i64 {
+        match &self.interpreter {
+            Some(computer) => computer.memory().read(addr),
+            None => self.load(addr as usize),
+        }
+    }
+
+    /// Runs until the program halts or needs input, like IntCodeComputer::run_with_budget without
+    /// a budget. After an error every call returns the same error.
+    pub fn run(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if let Some(e) = self.crashed {
+            return Err(e);
+        }
+        let result = self.run_compiled();
+        if let Err(e) = result {
+            self.crashed = Some(e);
+        }
+        result
+    }
+
+    fn start_interpreter(&mut self) {
+        let memory = std::mem::take(&mut self.memory);
+        self.interpreter = Some(IntCodeComputer::from_state(memory, self.pc, self.rb));
+    }
+
+    fn interpret(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_none() {
+            self.start_interpreter();
+        }
+        let computer = self.interpreter.as_mut().unwrap();
+        std::mem::swap(&mut self.input, &mut computer.input);
+        let outcome = computer.run_with_budget(u64::MAX);
+        std::mem::swap(&mut self.input, &mut computer.input);
+        for v in computer.dump_output().read_all() {
+            self.output.write(v);
+        }
+        computer.clear_output();
+        outcome
+    }
+
+    fn overflow(ptr: u64) -> IntCodeError {
+        IntCodeError {
+            ptr,
+            kind: ErrorKind::Overflow,
+        }
+    }
+
+    #[inline]
+    fn addr(ptr: u64, v: i64) -> Result<usize, IntCodeError> {
+        let kind = if v < 0 {
+            ErrorKind::NegativeAddress(v)
+        } else if v > MAX_ADDRESS {
+            ErrorKind::AddressOutOfRange(v)
+        } else {
+            return Ok(v as usize);
+        };
+        Err(IntCodeError { ptr, kind })
+    }
+
+    #[inline]
+    fn rel(&self, ptr: u64, offset: i64) -> Result<usize, IntCodeError> {
+        let v = offset.checked_add(self.rb).ok_or(Self::overflow(ptr))?;
+        Self::addr(ptr, v)
+    }
+
+    #[inline]
+    fn load(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    fn store(&mut self, addr: usize, v: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+        self.memory[addr] = v;
+    }
+
+    // A program without backward jumps never goes round the loop.
+    #[allow(clippy::never_loop)]
+    fn run_compiled(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_some() {
+            return self.interpret();
+        }
+        loop {
+            match self.pc {
+            0 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 0, kind }),
+                    };
+                    let d = Self::addr(0, 8)?;
+                    self.store(d, v);
+                }
+                self.pc = 2;
+            }
+            2 => {
+                {
+                    let a: i64 = self.load(Self::addr(2, 8)?);
+                    let b: i64 = 10;
+                    let d = Self::addr(2, 8)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(2))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = self.load(Self::addr(6, self.load(8))?);
+                    if c != 0 {
+                        self.pc = Self::addr(6, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 9;
+            }
+            21 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 21, kind }),
+                    };
+                    let d = Self::addr(21, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(23, 9)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(23, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(23))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(27, 9)?);
+                    let d = Self::addr(27, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(27))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(31, 9)?);
+                    let b: i64 = 4;
+                    let d = Self::addr(31, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(31))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(35, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 37;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            38 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 38, kind }),
+                    };
+                    let d = Self::addr(38, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(40, 9)?);
+                    let b: i64 = 5;
+                    let d = Self::addr(40, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(40))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(44, 9)?);
+                    let b: i64 = 5;
+                    let d = Self::addr(44, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(44))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(48, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(48, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(48))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(52, 9)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(52, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(52))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(56, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 58;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            59 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 59, kind }),
+                    };
+                    let d = Self::addr(59, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(61, 9)?);
+                    let b: i64 = 4;
+                    let d = Self::addr(61, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(61))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 4;
+                    let b: i64 = self.load(Self::addr(65, 9)?);
+                    let d = Self::addr(65, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(65))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(69, 9)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(69, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(69))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(73, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 75;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            76 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 76, kind }),
+                    };
+                    let d = Self::addr(76, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 4;
+                    let b: i64 = self.load(Self::addr(78, 9)?);
+                    let d = Self::addr(78, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(78))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(82, 9)?);
+                    let b: i64 = 5;
+                    let d = Self::addr(82, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(82))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(86, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 88;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            89 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 89, kind }),
+                    };
+                    let d = Self::addr(89, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(91, 9)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(91, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(91))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 5;
+                    let b: i64 = self.load(Self::addr(95, 9)?);
+                    let d = Self::addr(95, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(95))?;
+                    self.store(d, v);
+                }
+                self.pc = 99;
+            }
+            99 => {
+                {
+                    let a: i64 = self.load(Self::addr(99, 9)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(99, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(99))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(103, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 105;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            106 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 106, kind }),
+                    };
+                    let d = Self::addr(106, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(108, 9)?);
+                    let d = Self::addr(108, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(108))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(112, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 114;
+            }
+            114 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 114, kind }),
+                    };
+                    let d = Self::addr(114, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(116, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(116, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(116))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(120, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 122;
+            }
+            122 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 122, kind }),
+                    };
+                    let d = Self::addr(122, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(124, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(124, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(124))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(128, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 130;
+            }
+            130 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 130, kind }),
+                    };
+                    let d = Self::addr(130, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(132, 9)?);
+                    let d = Self::addr(132, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(132))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(136, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 138;
+            }
+            138 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 138, kind }),
+                    };
+                    let d = Self::addr(138, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(140, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(140, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(140))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(144, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 146;
+            }
+            146 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 146, kind }),
+                    };
+                    let d = Self::addr(146, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(148, 9)?);
+                    let d = Self::addr(148, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(148))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(152, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 154;
+            }
+            154 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 154, kind }),
+                    };
+                    let d = Self::addr(154, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(156, 9)?);
+                    let d = Self::addr(156, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(156))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(160, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 162;
+            }
+            162 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 162, kind }),
+                    };
+                    let d = Self::addr(162, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(164, 9)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(164, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(164))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(168, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 170;
+            }
+            170 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 170, kind }),
+                    };
+                    let d = Self::addr(170, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(172, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(172, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(172))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(176, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 178;
+            }
+            178 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 178, kind }),
+                    };
+                    let d = Self::addr(178, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(180, 9)?);
+                    let d = Self::addr(180, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(180))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(184, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 186;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            187 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 187, kind }),
+                    };
+                    let d = Self::addr(187, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(189, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(189, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(189))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(193, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 195;
+            }
+            195 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 195, kind }),
+                    };
+                    let d = Self::addr(195, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(197, 9)?);
+                    let d = Self::addr(197, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(197))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(201, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 203;
+            }
+            203 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 203, kind }),
+                    };
+                    let d = Self::addr(203, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(205, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(205, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(205))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(209, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 211;
+            }
+            211 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 211, kind }),
+                    };
+                    let d = Self::addr(211, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(213, 9)?);
+                    let d = Self::addr(213, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(213))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(217, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 219;
+            }
+            219 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 219, kind }),
+                    };
+                    let d = Self::addr(219, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(221, 9)?);
+                    let d = Self::addr(221, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(221))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(225, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 227;
+            }
+            227 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 227, kind }),
+                    };
+                    let d = Self::addr(227, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(229, 9)?);
+                    let d = Self::addr(229, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(229))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(233, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 235;
+            }
+            235 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 235, kind }),
+                    };
+                    let d = Self::addr(235, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(237, 9)?);
+                    let d = Self::addr(237, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(237))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(241, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 243;
+            }
+            243 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 243, kind }),
+                    };
+                    let d = Self::addr(243, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(245, 9)?);
+                    let d = Self::addr(245, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(245))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(249, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 251;
+            }
+            251 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 251, kind }),
+                    };
+                    let d = Self::addr(251, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(253, 9)?);
+                    let d = Self::addr(253, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(253))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(257, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 259;
+            }
+            259 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 259, kind }),
+                    };
+                    let d = Self::addr(259, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(261, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(261, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(261))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(265, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 267;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            268 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 268, kind }),
+                    };
+                    let d = Self::addr(268, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(270, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(270, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(270))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(274, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 276;
+            }
+            276 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 276, kind }),
+                    };
+                    let d = Self::addr(276, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(278, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(278, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(278))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(282, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 284;
+            }
+            284 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 284, kind }),
+                    };
+                    let d = Self::addr(284, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(286, 9)?);
+                    let d = Self::addr(286, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(286))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(290, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 292;
+            }
+            292 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 292, kind }),
+                    };
+                    let d = Self::addr(292, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(294, 9)?);
+                    let d = Self::addr(294, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(294))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(298, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 300;
+            }
+            300 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 300, kind }),
+                    };
+                    let d = Self::addr(300, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(302, 9)?);
+                    let d = Self::addr(302, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(302))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(306, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 308;
+            }
+            308 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 308, kind }),
+                    };
+                    let d = Self::addr(308, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(310, 9)?);
+                    let d = Self::addr(310, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(310))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(314, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 316;
+            }
+            316 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 316, kind }),
+                    };
+                    let d = Self::addr(316, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(318, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(318, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(318))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(322, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 324;
+            }
+            324 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 324, kind }),
+                    };
+                    let d = Self::addr(324, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(326, 9)?);
+                    let d = Self::addr(326, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(326))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(330, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 332;
+            }
+            332 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 332, kind }),
+                    };
+                    let d = Self::addr(332, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(334, 9)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(334, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(334))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(338, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 340;
+            }
+            340 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 340, kind }),
+                    };
+                    let d = Self::addr(340, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(342, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(342, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(342))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(346, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 348;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            349 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 349, kind }),
+                    };
+                    let d = Self::addr(349, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(351, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(351, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(351))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(355, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 357;
+            }
+            357 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 357, kind }),
+                    };
+                    let d = Self::addr(357, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(359, 9)?);
+                    let d = Self::addr(359, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(359))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(363, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 365;
+            }
+            365 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 365, kind }),
+                    };
+                    let d = Self::addr(365, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(367, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(367, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(367))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(371, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 373;
+            }
+            373 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 373, kind }),
+                    };
+                    let d = Self::addr(373, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(375, 9)?);
+                    let d = Self::addr(375, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(375))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(379, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 381;
+            }
+            381 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 381, kind }),
+                    };
+                    let d = Self::addr(381, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(383, 9)?);
+                    let d = Self::addr(383, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(383))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(387, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 389;
+            }
+            389 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 389, kind }),
+                    };
+                    let d = Self::addr(389, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(391, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(391, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(391))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(395, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 397;
+            }
+            397 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 397, kind }),
+                    };
+                    let d = Self::addr(397, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(399, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(399, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(399))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(403, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 405;
+            }
+            405 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 405, kind }),
+                    };
+                    let d = Self::addr(405, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(407, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(407, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(407))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(411, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 413;
+            }
+            413 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 413, kind }),
+                    };
+                    let d = Self::addr(413, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(415, 9)?);
+                    let d = Self::addr(415, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(415))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(419, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 421;
+            }
+            421 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 421, kind }),
+                    };
+                    let d = Self::addr(421, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(Self::addr(423, 9)?);
+                    let d = Self::addr(423, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(423))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(427, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 429;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            430 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 430, kind }),
+                    };
+                    let d = Self::addr(430, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(432, 9)?);
+                    let d = Self::addr(432, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(432))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(436, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 438;
+            }
+            438 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 438, kind }),
+                    };
+                    let d = Self::addr(438, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(440, 9)?);
+                    let d = Self::addr(440, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(440))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(444, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 446;
+            }
+            446 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 446, kind }),
+                    };
+                    let d = Self::addr(446, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(448, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(448, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(448))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(452, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 454;
+            }
+            454 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 454, kind }),
+                    };
+                    let d = Self::addr(454, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(456, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(456, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(456))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(460, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 462;
+            }
+            462 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 462, kind }),
+                    };
+                    let d = Self::addr(462, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(464, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(464, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(464))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(468, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 470;
+            }
+            470 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 470, kind }),
+                    };
+                    let d = Self::addr(470, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(472, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(472, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(472))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(476, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 478;
+            }
+            478 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 478, kind }),
+                    };
+                    let d = Self::addr(478, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(480, 9)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(480, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(480))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(484, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 486;
+            }
+            486 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 486, kind }),
+                    };
+                    let d = Self::addr(486, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(488, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(488, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(488))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(492, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 494;
+            }
+            494 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 494, kind }),
+                    };
+                    let d = Self::addr(494, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(496, 9)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(496, 9)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(496))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(500, 9)?);
+                    self.output.write(v);
+                }
+                self.pc = 502;
+            }
+            502 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 502, kind }),
+                    };
+                    let d = Self::addr(502, 9)?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 2;
+                    let b: i64 = self.load(Self::addr(504, 9)?);
+                    let d = Self::addr(504, 9)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(504))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = self.load(Self::addr(508, 9)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 510;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            _ => return self.interpret(),
+            }
+        }
+    }
+}
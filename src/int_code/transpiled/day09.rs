@@ -0,0 +1,2584 @@
+// Generated from a 973 cell Intcode program by crate::int_code::transpile.
+
+use crate::int_code::{
+    DataStream, ErrorKind, IntCodeComputer, IntCodeError, RunOutcome, MAX_ADDRESS,
+};
+
+const PROGRAM: [i64; 973] = [
+    1102, 34463338, 34463338, 63, 1007, 63, 34463338, 63, 1005, 63, 53, 1102,
+    1, 3, 1000, 109, 988, 209, 12, 9, 1000, 209, 6, 209,
+    3, 203, 0, 1008, 1000, 1, 63, 1005, 63, 65, 1008, 1000,
+    2, 63, 1005, 63, 904, 1008, 1000, 0, 63, 1005, 63, 58,
+    4, 25, 104, 0, 99, 4, 0, 104, 0, 99, 4, 17,
+    104, 0, 99, 0, 0, 1102, 1, 21, 1004, 1101, 28, 0,
+    1016, 1101, 0, 27, 1010, 1102, 36, 1, 1008, 1102, 33, 1,
+    1013, 1101, 0, 22, 1012, 1101, 0, 37, 1011, 1102, 34, 1,
+    1017, 1102, 466, 1, 1027, 1102, 1, 484, 1029, 1102, 1, 699,
+    1024, 1102, 1, 1, 1021, 1101, 0, 0, 1020, 1102, 1, 24,
+    1015, 1101, 0, 473, 1026, 1101, 653, 0, 1022, 1102, 26, 1,
+    1007, 1102, 25, 1, 1006, 1101, 0, 39, 1014, 1102, 646, 1,
+    1023, 1101, 690, 0, 1025, 1102, 1, 29, 1019, 1101, 32, 0,
+    1018, 1101, 30, 0, 1002, 1101, 0, 20, 1001, 1102, 1, 38,
+    1005, 1102, 1, 23, 1003, 1101, 0, 31, 1000, 1101, 35, 0,
+    1009, 1101, 0, 493, 1028, 109, 5, 1208, 0, 37, 63, 1005,
+    63, 201, 1001, 64, 1, 64, 1106, 0, 203, 4, 187, 1002,
+    64, 2, 64, 109, -4, 2107, 36, 8, 63, 1005, 63, 223,
+    1001, 64, 1, 64, 1105, 1, 225, 4, 209, 1002, 64, 2,
+    64, 109, 18, 21107, 40, 41, -9, 1005, 1010, 243, 4, 231,
+    1105, 1, 247, 1001, 64, 1, 64, 1002, 64, 2, 64, 109,
+    6, 21107, 41, 40, -9, 1005, 1016, 267, 1001, 64, 1, 64,
+    1106, 0, 269, 4, 253, 1002, 64, 2, 64, 109, -19, 21102,
+    42, 1, 5, 1008, 1011, 42, 63, 1005, 63, 291, 4, 275,
+    1105, 1, 295, 1001, 64, 1, 64, 1002, 64, 2, 64, 109,
+    15, 1205, 0, 309, 4, 301, 1105, 1, 313, 1001, 64, 1,
+    64, 1002, 64, 2, 64, 109, -27, 2101, 0, 9, 63, 1008,
+    63, 20, 63, 1005, 63, 333, 1106, 0, 339, 4, 319, 1001,
+    64, 1, 64, 1002, 64, 2, 64, 109, 19, 21102, 43, 1,
+    6, 1008, 1019, 45, 63, 1005, 63, 363, 1001, 64, 1, 64,
+    1105, 1, 365, 4, 345, 1002, 64, 2, 64, 109, 1, 21108,
+    44, 47, -3, 1005, 1011, 385, 1001, 64, 1, 64, 1106, 0,
+    387, 4, 371, 1002, 64, 2, 64, 109, -22, 1201, 9, 0,
+    63, 1008, 63, 21, 63, 1005, 63, 411, 1001, 64, 1, 64,
+    1106, 0, 413, 4, 393, 1002, 64, 2, 64, 109, 9, 1207,
+    0, 19, 63, 1005, 63, 433, 1001, 64, 1, 64, 1106, 0,
+    435, 4, 419, 1002, 64, 2, 64, 109, -9, 2107, 30, 8,
+    63, 1005, 63, 453, 4, 441, 1105, 1, 457, 1001, 64, 1,
+    64, 1002, 64, 2, 64, 109, 25, 2106, 0, 10, 1001, 64,
+    1, 64, 1106, 0, 475, 4, 463, 1002, 64, 2, 64, 109,
+    11, 2106, 0, 0, 4, 481, 1001, 64, 1, 64, 1105, 1,
+    493, 1002, 64, 2, 64, 109, -18, 2108, 21, -6, 63, 1005,
+    63, 511, 4, 499, 1106, 0, 515, 1001, 64, 1, 64, 1002,
+    64, 2, 64, 109, -12, 2108, 18, 6, 63, 1005, 63, 535,
+    1001, 64, 1, 64, 1106, 0, 537, 4, 521, 1002, 64, 2,
+    64, 109, 19, 21101, 45, 0, -7, 1008, 1010, 45, 63, 1005,
+    63, 563, 4, 543, 1001, 64, 1, 64, 1105, 1, 563, 1002,
+    64, 2, 64, 109, -10, 1207, -5, 31, 63, 1005, 63, 581,
+    4, 569, 1106, 0, 585, 1001, 64, 1, 64, 1002, 64, 2,
+    64, 109, -8, 2102, 1, 5, 63, 1008, 63, 21, 63, 1005,
+    63, 611, 4, 591, 1001, 64, 1, 64, 1105, 1, 611, 1002,
+    64, 2, 64, 109, 5, 1201, 0, 0, 63, 1008, 63, 21,
+    63, 1005, 63, 633, 4, 617, 1106, 0, 637, 1001, 64, 1,
+    64, 1002, 64, 2, 64, 109, 13, 2105, 1, 6, 1001, 64,
+    1, 64, 1106, 0, 655, 4, 643, 1002, 64, 2, 64, 109,
+    -7, 1202, -3, 1, 63, 1008, 63, 26, 63, 1005, 63, 681,
+    4, 661, 1001, 64, 1, 64, 1106, 0, 681, 1002, 64, 2,
+    64, 109, 12, 2105, 1, 2, 4, 687, 1001, 64, 1, 64,
+    1105, 1, 699, 1002, 64, 2, 64, 109, -28, 1208, 8, 30,
+    63, 1005, 63, 717, 4, 705, 1106, 0, 721, 1001, 64, 1,
+    64, 1002, 64, 2, 64, 109, 10, 1202, 1, 1, 63, 1008,
+    63, 40, 63, 1005, 63, 745, 1001, 64, 1, 64, 1105, 1,
+    747, 4, 727, 1002, 64, 2, 64, 109, 10, 21108, 46, 46,
+    -2, 1005, 1012, 765, 4, 753, 1105, 1, 769, 1001, 64, 1,
+    64, 1002, 64, 2, 64, 109, -2, 1205, 8, 781, 1106, 0,
+    787, 4, 775, 1001, 64, 1, 64, 1002, 64, 2, 64, 109,
+    -9, 2101, 0, 0, 63, 1008, 63, 23, 63, 1005, 63, 809,
+    4, 793, 1105, 1, 813, 1001, 64, 1, 64, 1002, 64, 2,
+    64, 109, 9, 1206, 8, 831, 4, 819, 1001, 64, 1, 64,
+    1106, 0, 831, 1002, 64, 2, 64, 109, -9, 2102, 1, -2,
+    63, 1008, 63, 22, 63, 1005, 63, 855, 1001, 64, 1, 64,
+    1106, 0, 857, 4, 837, 1002, 64, 2, 64, 109, 4, 21101,
+    47, 0, 10, 1008, 1017, 50, 63, 1005, 63, 877, 1105, 1,
+    883, 4, 863, 1001, 64, 1, 64, 1002, 64, 2, 64, 109,
+    18, 1206, -4, 895, 1105, 1, 901, 4, 889, 1001, 64, 1,
+    64, 4, 64, 99, 21101, 0, 27, 1, 21102, 915, 1, 0,
+    1106, 0, 922, 21201, 1, 56639, 1, 204, 1, 99, 109, 3,
+    1207, -2, 3, 63, 1005, 63, 964, 21201, -2, -1, 1, 21102,
+    1, 942, 0, 1106, 0, 922, 22102, 1, 1, -1, 21201, -2,
+    -3, 1, 21101, 0, 957, 0, 1106, 0, 922, 22201, 1, -1,
+    -2, 1106, 0, 968, 22102, 1, -2, -2, 109, -3, 2106, 0,
+    0,
+];
+
+/// Returns whether the cell holds a compiled instruction.
+fn is_code(addr: usize) -> bool {
+    matches!(addr, 0..=62 | 65..=972)
+}
+
+pub struct Machine {
+    pub input: DataStream,
+    pub output: DataStream,
+    memory: Vec<i64>,
+    pc: u64,
+    rb: i64,
+    interpreter: Option<IntCodeComputer>,
+    crashed: Option<IntCodeError>,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::with_memory(PROGRAM.to_vec())
+    }
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a machine for the program with some cells changed. If any of them hold compiled
+    /// instructions the whole run is interpreted.
+    pub fn with_memory(memory: Vec<i64>) -> Self {
+        let patched = (0..PROGRAM.len()).any(|a| is_code(a) && memory.get(a) != Some(&PROGRAM[a]));
+        let mut machine = Self {
+            input: DataStream::new(),
+            output: DataStream::new(),
+            memory,
+            pc: 0,
+            rb: 0,
+            interpreter: None,
+            crashed: None,
+        };
+        if patched {
+            machine.start_interpreter();
+        }
+        machine
+    }
+
+    /// Returns whether the machine has handed over to IntCodeComputer.
+    pub fn is_interpreted(&self) -> bool {
+        self.interpreter.is_some()
+    }
+
+    /// Returns the value at the address. Addresses past the end of memory read as 0.
+    pub fn read(&self, addr: u64) -> i64 {
+        match &self.interpreter {
+            Some(computer) => computer.memory().read(addr),
+            None => self.load(addr as usize),
+        }
+    }
+
+    /// Runs until the program halts or needs input, like IntCodeComputer::run_with_budget without
+    /// a budget. After an error every call returns the same error.
+    pub fn run(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if let Some(e) = self.crashed {
+            return Err(e);
+        }
+        let result = self.run_compiled();
+        if let Err(e) = result {
+            self.crashed = Some(e);
+        }
+        result
+    }
+
+    fn start_interpreter(&mut self) {
+        let memory = std::mem::take(&mut self.memory);
+        self.interpreter = Some(IntCodeComputer::from_state(memory, self.pc, self.rb));
+    }
+
+    fn interpret(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_none() {
+            self.start_interpreter();
+        }
+        let computer = self.interpreter.as_mut().unwrap();
+        std::mem::swap(&mut self.input, &mut computer.input);
+        let outcome = computer.run_with_budget(u64::MAX);
+        std::mem::swap(&mut self.input, &mut computer.input);
+        for v in computer.dump_output().read_all() {
+            self.output.write(v);
+        }
+        computer.clear_output();
+        outcome
+    }
+
+    fn overflow(ptr: u64) -> IntCodeError {
+        IntCodeError {
+            ptr,
+            kind: ErrorKind::Overflow,
+        }
+    }
+
+    #[inline]
+    fn addr(ptr: u64, v: i64) -> Result<usize, IntCodeError> {
+        let kind = if v < 0 {
+            ErrorKind::NegativeAddress(v)
+        } else if v > MAX_ADDRESS {
+            ErrorKind::AddressOutOfRange(v)
+        } else {
+            return Ok(v as usize);
+        };
+        Err(IntCodeError { ptr, kind })
+    }
+
+    #[inline]
+    fn rel(&self, ptr: u64, offset: i64) -> Result<usize, IntCodeError> {
+        let v = offset.checked_add(self.rb).ok_or(Self::overflow(ptr))?;
+        Self::addr(ptr, v)
+    }
+
+    #[inline]
+    fn load(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    fn store(&mut self, addr: usize, v: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+        self.memory[addr] = v;
+    }
+
+    // A program without backward jumps never goes round the loop.
+    #[allow(clippy::never_loop)]
+    fn run_compiled(&mut self) -> Result<RunOutcome, IntCodeError> {
+        if self.interpreter.is_some() {
+            return self.interpret();
+        }
+        loop {
+            match self.pc {
+            0 => {
+                {
+                    let a: i64 = 34463338;
+                    let b: i64 = 34463338;
+                    let d = Self::addr(0, 63)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(0))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(4, 63)?);
+                    let b: i64 = 34463338;
+                    let d = Self::addr(4, 63)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(8, 63)?);
+                    let t: i64 = 53;
+                    if c != 0 {
+                        self.pc = Self::addr(8, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 11;
+            }
+            11 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 3;
+                    let d = Self::addr(11, 1000)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(11))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 988;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(15))?;
+                }
+                {
+                    let v: i64 = self.load(self.rel(17, 12)?);
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(17))?;
+                }
+                {
+                    let v: i64 = self.load(Self::addr(19, 1000)?);
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(19))?;
+                }
+                {
+                    let v: i64 = self.load(self.rel(21, 6)?);
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(21))?;
+                }
+                {
+                    let v: i64 = self.load(self.rel(23, 3)?);
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(23))?;
+                }
+                self.pc = 25;
+            }
+            25 => {
+                {
+                    let v = match self.input.try_read() {
+                        Ok(Some(v)) => v,
+                        Ok(None) => return Ok(RunOutcome::WaitingForInput),
+                        Err(kind) => return Err(IntCodeError { ptr: 25, kind }),
+                    };
+                    let d = self.rel(25, 0)?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 27;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(27, 1000)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(27, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(31, 63)?);
+                    let t: i64 = 65;
+                    if c != 0 {
+                        self.pc = Self::addr(31, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 34;
+            }
+            34 => {
+                {
+                    let a: i64 = self.load(Self::addr(34, 1000)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(34, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(38, 63)?);
+                    let t: i64 = 904;
+                    if c != 0 {
+                        self.pc = Self::addr(38, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 41;
+            }
+            41 => {
+                {
+                    let a: i64 = self.load(Self::addr(41, 1000)?);
+                    let b: i64 = 0;
+                    let d = Self::addr(41, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(45, 63)?);
+                    let t: i64 = 58;
+                    if c != 0 {
+                        self.pc = Self::addr(45, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 48;
+            }
+            48 => {
+                {
+                    let v: i64 = self.load(Self::addr(48, 25)?);
+                    self.output.write(v);
+                }
+                {
+                    let v: i64 = 0;
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 52;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            53 => {
+                {
+                    let v: i64 = self.load(Self::addr(53, 0)?);
+                    self.output.write(v);
+                }
+                {
+                    let v: i64 = 0;
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 57;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            58 => {
+                {
+                    let v: i64 = self.load(Self::addr(58, 17)?);
+                    self.output.write(v);
+                }
+                {
+                    let v: i64 = 0;
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 62;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            65 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 21;
+                    let d = Self::addr(65, 1004)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(65))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 28;
+                    let b: i64 = 0;
+                    let d = Self::addr(69, 1016)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(69))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 27;
+                    let d = Self::addr(73, 1010)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(73))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 36;
+                    let b: i64 = 1;
+                    let d = Self::addr(77, 1008)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(77))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 33;
+                    let b: i64 = 1;
+                    let d = Self::addr(81, 1013)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(81))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 22;
+                    let d = Self::addr(85, 1012)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(85))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 37;
+                    let d = Self::addr(89, 1011)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(89))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 34;
+                    let b: i64 = 1;
+                    let d = Self::addr(93, 1017)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(93))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 466;
+                    let b: i64 = 1;
+                    let d = Self::addr(97, 1027)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(97))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 484;
+                    let d = Self::addr(101, 1029)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(101))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 699;
+                    let d = Self::addr(105, 1024)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(105))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 1;
+                    let d = Self::addr(109, 1021)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(109))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 0;
+                    let d = Self::addr(113, 1020)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(113))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 24;
+                    let d = Self::addr(117, 1015)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(117))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 473;
+                    let d = Self::addr(121, 1026)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(121))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 653;
+                    let b: i64 = 0;
+                    let d = Self::addr(125, 1022)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(125))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 26;
+                    let b: i64 = 1;
+                    let d = Self::addr(129, 1007)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(129))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 25;
+                    let b: i64 = 1;
+                    let d = Self::addr(133, 1006)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(133))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 39;
+                    let d = Self::addr(137, 1014)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(137))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 646;
+                    let b: i64 = 1;
+                    let d = Self::addr(141, 1023)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(141))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 690;
+                    let b: i64 = 0;
+                    let d = Self::addr(145, 1025)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(145))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 29;
+                    let d = Self::addr(149, 1019)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(149))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 32;
+                    let b: i64 = 0;
+                    let d = Self::addr(153, 1018)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(153))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 30;
+                    let b: i64 = 0;
+                    let d = Self::addr(157, 1002)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(157))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 20;
+                    let d = Self::addr(161, 1001)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(161))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 38;
+                    let d = Self::addr(165, 1005)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(165))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 23;
+                    let d = Self::addr(169, 1003)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(169))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 31;
+                    let d = Self::addr(173, 1000)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(173))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 35;
+                    let b: i64 = 0;
+                    let d = Self::addr(177, 1009)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(177))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 493;
+                    let d = Self::addr(181, 1028)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(181))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 5;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(185))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(187, 0)?);
+                    let b: i64 = 37;
+                    let d = Self::addr(187, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(191, 63)?);
+                    let t: i64 = 201;
+                    if c != 0 {
+                        self.pc = Self::addr(191, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 194;
+            }
+            194 => {
+                {
+                    let a: i64 = self.load(Self::addr(194, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(194, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(194))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 203;
+                    if c == 0 {
+                        self.pc = Self::addr(198, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 201;
+            }
+            201 => {
+                {
+                    let v: i64 = self.load(Self::addr(201, 187)?);
+                    self.output.write(v);
+                }
+                self.pc = 203;
+            }
+            203 => {
+                {
+                    let a: i64 = self.load(Self::addr(203, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(203, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(203))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -4;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(207))?;
+                }
+                {
+                    let a: i64 = 36;
+                    let b: i64 = self.load(self.rel(209, 8)?);
+                    let d = Self::addr(209, 63)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(213, 63)?);
+                    let t: i64 = 223;
+                    if c != 0 {
+                        self.pc = Self::addr(213, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 216;
+            }
+            216 => {
+                {
+                    let a: i64 = self.load(Self::addr(216, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(216, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(216))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 225;
+                    if c != 0 {
+                        self.pc = Self::addr(220, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 223;
+            }
+            223 => {
+                {
+                    let v: i64 = self.load(Self::addr(223, 209)?);
+                    self.output.write(v);
+                }
+                self.pc = 225;
+            }
+            225 => {
+                {
+                    let a: i64 = self.load(Self::addr(225, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(225, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(225))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 18;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(229))?;
+                }
+                {
+                    let a: i64 = 40;
+                    let b: i64 = 41;
+                    let d = self.rel(231, -9)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 235;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = self.load(Self::addr(235, 1010)?);
+                    let t: i64 = 243;
+                    if c != 0 {
+                        self.pc = Self::addr(235, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 238;
+            }
+            238 => {
+                {
+                    let v: i64 = self.load(Self::addr(238, 231)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 247;
+                    if c != 0 {
+                        self.pc = Self::addr(240, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 243;
+            }
+            243 => {
+                {
+                    let a: i64 = self.load(Self::addr(243, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(243, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(243))?;
+                    self.store(d, v);
+                }
+                self.pc = 247;
+            }
+            247 => {
+                {
+                    let a: i64 = self.load(Self::addr(247, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(247, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(247))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 6;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(251))?;
+                }
+                {
+                    let a: i64 = 41;
+                    let b: i64 = 40;
+                    let d = self.rel(253, -9)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 257;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = self.load(Self::addr(257, 1016)?);
+                    let t: i64 = 267;
+                    if c != 0 {
+                        self.pc = Self::addr(257, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 260;
+            }
+            260 => {
+                {
+                    let a: i64 = self.load(Self::addr(260, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(260, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(260))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 269;
+                    if c == 0 {
+                        self.pc = Self::addr(264, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 267;
+            }
+            267 => {
+                {
+                    let v: i64 = self.load(Self::addr(267, 253)?);
+                    self.output.write(v);
+                }
+                self.pc = 269;
+            }
+            269 => {
+                {
+                    let a: i64 = self.load(Self::addr(269, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(269, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(269))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -19;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(273))?;
+                }
+                {
+                    let a: i64 = 42;
+                    let b: i64 = 1;
+                    let d = self.rel(275, 5)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(275))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 279;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(279, 1011)?);
+                    let b: i64 = 42;
+                    let d = Self::addr(279, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(283, 63)?);
+                    let t: i64 = 291;
+                    if c != 0 {
+                        self.pc = Self::addr(283, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 286;
+            }
+            286 => {
+                {
+                    let v: i64 = self.load(Self::addr(286, 275)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 295;
+                    if c != 0 {
+                        self.pc = Self::addr(288, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 291;
+            }
+            291 => {
+                {
+                    let a: i64 = self.load(Self::addr(291, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(291, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(291))?;
+                    self.store(d, v);
+                }
+                self.pc = 295;
+            }
+            295 => {
+                {
+                    let a: i64 = self.load(Self::addr(295, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(295, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(295))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 15;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(299))?;
+                }
+                {
+                    let c: i64 = self.load(self.rel(301, 0)?);
+                    let t: i64 = 309;
+                    if c != 0 {
+                        self.pc = Self::addr(301, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 304;
+            }
+            304 => {
+                {
+                    let v: i64 = self.load(Self::addr(304, 301)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 313;
+                    if c != 0 {
+                        self.pc = Self::addr(306, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 309;
+            }
+            309 => {
+                {
+                    let a: i64 = self.load(Self::addr(309, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(309, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(309))?;
+                    self.store(d, v);
+                }
+                self.pc = 313;
+            }
+            313 => {
+                {
+                    let a: i64 = self.load(Self::addr(313, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(313, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(313))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -27;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(317))?;
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = self.load(self.rel(319, 9)?);
+                    let d = Self::addr(319, 63)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(319))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(323, 63)?);
+                    let b: i64 = 20;
+                    let d = Self::addr(323, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(327, 63)?);
+                    let t: i64 = 333;
+                    if c != 0 {
+                        self.pc = Self::addr(327, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 330;
+            }
+            330 => {
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 339;
+                    if c == 0 {
+                        self.pc = Self::addr(330, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 333;
+            }
+            333 => {
+                {
+                    let v: i64 = self.load(Self::addr(333, 319)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(335, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(335, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(335))?;
+                    self.store(d, v);
+                }
+                self.pc = 339;
+            }
+            339 => {
+                {
+                    let a: i64 = self.load(Self::addr(339, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(339, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(339))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 19;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(343))?;
+                }
+                {
+                    let a: i64 = 43;
+                    let b: i64 = 1;
+                    let d = self.rel(345, 6)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(345))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 349;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(349, 1019)?);
+                    let b: i64 = 45;
+                    let d = Self::addr(349, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(353, 63)?);
+                    let t: i64 = 363;
+                    if c != 0 {
+                        self.pc = Self::addr(353, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 356;
+            }
+            356 => {
+                {
+                    let a: i64 = self.load(Self::addr(356, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(356, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(356))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 365;
+                    if c != 0 {
+                        self.pc = Self::addr(360, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 363;
+            }
+            363 => {
+                {
+                    let v: i64 = self.load(Self::addr(363, 345)?);
+                    self.output.write(v);
+                }
+                self.pc = 365;
+            }
+            365 => {
+                {
+                    let a: i64 = self.load(Self::addr(365, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(365, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(365))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 1;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(369))?;
+                }
+                {
+                    let a: i64 = 44;
+                    let b: i64 = 47;
+                    let d = self.rel(371, -3)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 375;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = self.load(Self::addr(375, 1011)?);
+                    let t: i64 = 385;
+                    if c != 0 {
+                        self.pc = Self::addr(375, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 378;
+            }
+            378 => {
+                {
+                    let a: i64 = self.load(Self::addr(378, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(378, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(378))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 387;
+                    if c == 0 {
+                        self.pc = Self::addr(382, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 385;
+            }
+            385 => {
+                {
+                    let v: i64 = self.load(Self::addr(385, 371)?);
+                    self.output.write(v);
+                }
+                self.pc = 387;
+            }
+            387 => {
+                {
+                    let a: i64 = self.load(Self::addr(387, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(387, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(387))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -22;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(391))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(393, 9)?);
+                    let b: i64 = 0;
+                    let d = Self::addr(393, 63)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(393))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(397, 63)?);
+                    let b: i64 = 21;
+                    let d = Self::addr(397, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(401, 63)?);
+                    let t: i64 = 411;
+                    if c != 0 {
+                        self.pc = Self::addr(401, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 404;
+            }
+            404 => {
+                {
+                    let a: i64 = self.load(Self::addr(404, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(404, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(404))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 413;
+                    if c == 0 {
+                        self.pc = Self::addr(408, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 411;
+            }
+            411 => {
+                {
+                    let v: i64 = self.load(Self::addr(411, 393)?);
+                    self.output.write(v);
+                }
+                self.pc = 413;
+            }
+            413 => {
+                {
+                    let a: i64 = self.load(Self::addr(413, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(413, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(413))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 9;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(417))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(419, 0)?);
+                    let b: i64 = 19;
+                    let d = Self::addr(419, 63)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(423, 63)?);
+                    let t: i64 = 433;
+                    if c != 0 {
+                        self.pc = Self::addr(423, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 426;
+            }
+            426 => {
+                {
+                    let a: i64 = self.load(Self::addr(426, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(426, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(426))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 435;
+                    if c == 0 {
+                        self.pc = Self::addr(430, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 433;
+            }
+            433 => {
+                {
+                    let v: i64 = self.load(Self::addr(433, 419)?);
+                    self.output.write(v);
+                }
+                self.pc = 435;
+            }
+            435 => {
+                {
+                    let a: i64 = self.load(Self::addr(435, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(435, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(435))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -9;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(439))?;
+                }
+                {
+                    let a: i64 = 30;
+                    let b: i64 = self.load(self.rel(441, 8)?);
+                    let d = Self::addr(441, 63)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(445, 63)?);
+                    let t: i64 = 453;
+                    if c != 0 {
+                        self.pc = Self::addr(445, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 448;
+            }
+            448 => {
+                {
+                    let v: i64 = self.load(Self::addr(448, 441)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 457;
+                    if c != 0 {
+                        self.pc = Self::addr(450, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 453;
+            }
+            453 => {
+                {
+                    let a: i64 = self.load(Self::addr(453, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(453, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(453))?;
+                    self.store(d, v);
+                }
+                self.pc = 457;
+            }
+            457 => {
+                {
+                    let a: i64 = self.load(Self::addr(457, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(457, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(457))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 25;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(461))?;
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = self.load(self.rel(463, 10)?);
+                    if c == 0 {
+                        self.pc = Self::addr(463, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 466;
+            }
+            466 => {
+                {
+                    let a: i64 = self.load(Self::addr(466, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(466, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(466))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 475;
+                    if c == 0 {
+                        self.pc = Self::addr(470, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 473;
+            }
+            473 => {
+                {
+                    let v: i64 = self.load(Self::addr(473, 463)?);
+                    self.output.write(v);
+                }
+                self.pc = 475;
+            }
+            475 => {
+                {
+                    let a: i64 = self.load(Self::addr(475, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(475, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(475))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 11;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(479))?;
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = self.load(self.rel(481, 0)?);
+                    if c == 0 {
+                        self.pc = Self::addr(481, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 484;
+            }
+            484 => {
+                {
+                    let v: i64 = self.load(Self::addr(484, 481)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(486, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(486, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(486))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 493;
+                    if c != 0 {
+                        self.pc = Self::addr(490, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 493;
+            }
+            493 => {
+                {
+                    let a: i64 = self.load(Self::addr(493, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(493, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(493))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -18;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(497))?;
+                }
+                {
+                    let a: i64 = 21;
+                    let b: i64 = self.load(self.rel(499, -6)?);
+                    let d = Self::addr(499, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(503, 63)?);
+                    let t: i64 = 511;
+                    if c != 0 {
+                        self.pc = Self::addr(503, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 506;
+            }
+            506 => {
+                {
+                    let v: i64 = self.load(Self::addr(506, 499)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 515;
+                    if c == 0 {
+                        self.pc = Self::addr(508, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 511;
+            }
+            511 => {
+                {
+                    let a: i64 = self.load(Self::addr(511, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(511, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(511))?;
+                    self.store(d, v);
+                }
+                self.pc = 515;
+            }
+            515 => {
+                {
+                    let a: i64 = self.load(Self::addr(515, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(515, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(515))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -12;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(519))?;
+                }
+                {
+                    let a: i64 = 18;
+                    let b: i64 = self.load(self.rel(521, 6)?);
+                    let d = Self::addr(521, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(525, 63)?);
+                    let t: i64 = 535;
+                    if c != 0 {
+                        self.pc = Self::addr(525, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 528;
+            }
+            528 => {
+                {
+                    let a: i64 = self.load(Self::addr(528, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(528, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(528))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 537;
+                    if c == 0 {
+                        self.pc = Self::addr(532, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 535;
+            }
+            535 => {
+                {
+                    let v: i64 = self.load(Self::addr(535, 521)?);
+                    self.output.write(v);
+                }
+                self.pc = 537;
+            }
+            537 => {
+                {
+                    let a: i64 = self.load(Self::addr(537, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(537, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(537))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 19;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(541))?;
+                }
+                {
+                    let a: i64 = 45;
+                    let b: i64 = 0;
+                    let d = self.rel(543, -7)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(543))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 547;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(547, 1010)?);
+                    let b: i64 = 45;
+                    let d = Self::addr(547, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(551, 63)?);
+                    let t: i64 = 563;
+                    if c != 0 {
+                        self.pc = Self::addr(551, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 554;
+            }
+            554 => {
+                {
+                    let v: i64 = self.load(Self::addr(554, 543)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(556, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(556, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(556))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 563;
+                    if c != 0 {
+                        self.pc = Self::addr(560, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 563;
+            }
+            563 => {
+                {
+                    let a: i64 = self.load(Self::addr(563, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(563, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(563))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -10;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(567))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(569, -5)?);
+                    let b: i64 = 31;
+                    let d = Self::addr(569, 63)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(573, 63)?);
+                    let t: i64 = 581;
+                    if c != 0 {
+                        self.pc = Self::addr(573, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 576;
+            }
+            576 => {
+                {
+                    let v: i64 = self.load(Self::addr(576, 569)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 585;
+                    if c == 0 {
+                        self.pc = Self::addr(578, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 581;
+            }
+            581 => {
+                {
+                    let a: i64 = self.load(Self::addr(581, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(581, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(581))?;
+                    self.store(d, v);
+                }
+                self.pc = 585;
+            }
+            585 => {
+                {
+                    let a: i64 = self.load(Self::addr(585, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(585, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(585))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -8;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(589))?;
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(self.rel(591, 5)?);
+                    let d = Self::addr(591, 63)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(591))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(595, 63)?);
+                    let b: i64 = 21;
+                    let d = Self::addr(595, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(599, 63)?);
+                    let t: i64 = 611;
+                    if c != 0 {
+                        self.pc = Self::addr(599, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 602;
+            }
+            602 => {
+                {
+                    let v: i64 = self.load(Self::addr(602, 591)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(604, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(604, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(604))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 611;
+                    if c != 0 {
+                        self.pc = Self::addr(608, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 611;
+            }
+            611 => {
+                {
+                    let a: i64 = self.load(Self::addr(611, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(611, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(611))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 5;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(615))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(617, 0)?);
+                    let b: i64 = 0;
+                    let d = Self::addr(617, 63)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(617))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(621, 63)?);
+                    let b: i64 = 21;
+                    let d = Self::addr(621, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(625, 63)?);
+                    let t: i64 = 633;
+                    if c != 0 {
+                        self.pc = Self::addr(625, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 628;
+            }
+            628 => {
+                {
+                    let v: i64 = self.load(Self::addr(628, 617)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 637;
+                    if c == 0 {
+                        self.pc = Self::addr(630, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 633;
+            }
+            633 => {
+                {
+                    let a: i64 = self.load(Self::addr(633, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(633, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(633))?;
+                    self.store(d, v);
+                }
+                self.pc = 637;
+            }
+            637 => {
+                {
+                    let a: i64 = self.load(Self::addr(637, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(637, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(637))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 13;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(641))?;
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = self.load(self.rel(643, 6)?);
+                    if c != 0 {
+                        self.pc = Self::addr(643, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 646;
+            }
+            646 => {
+                {
+                    let a: i64 = self.load(Self::addr(646, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(646, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(646))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 655;
+                    if c == 0 {
+                        self.pc = Self::addr(650, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 653;
+            }
+            653 => {
+                {
+                    let v: i64 = self.load(Self::addr(653, 643)?);
+                    self.output.write(v);
+                }
+                self.pc = 655;
+            }
+            655 => {
+                {
+                    let a: i64 = self.load(Self::addr(655, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(655, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(655))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -7;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(659))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(661, -3)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(661, 63)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(661))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(665, 63)?);
+                    let b: i64 = 26;
+                    let d = Self::addr(665, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(669, 63)?);
+                    let t: i64 = 681;
+                    if c != 0 {
+                        self.pc = Self::addr(669, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 672;
+            }
+            672 => {
+                {
+                    let v: i64 = self.load(Self::addr(672, 661)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(674, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(674, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(674))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 681;
+                    if c == 0 {
+                        self.pc = Self::addr(678, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 681;
+            }
+            681 => {
+                {
+                    let a: i64 = self.load(Self::addr(681, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(681, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(681))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 12;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(685))?;
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = self.load(self.rel(687, 2)?);
+                    if c != 0 {
+                        self.pc = Self::addr(687, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 690;
+            }
+            690 => {
+                {
+                    let v: i64 = self.load(Self::addr(690, 687)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(692, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(692, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(692))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 699;
+                    if c != 0 {
+                        self.pc = Self::addr(696, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 699;
+            }
+            699 => {
+                {
+                    let a: i64 = self.load(Self::addr(699, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(699, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(699))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -28;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(703))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(705, 8)?);
+                    let b: i64 = 30;
+                    let d = Self::addr(705, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(709, 63)?);
+                    let t: i64 = 717;
+                    if c != 0 {
+                        self.pc = Self::addr(709, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 712;
+            }
+            712 => {
+                {
+                    let v: i64 = self.load(Self::addr(712, 705)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 721;
+                    if c == 0 {
+                        self.pc = Self::addr(714, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 717;
+            }
+            717 => {
+                {
+                    let a: i64 = self.load(Self::addr(717, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(717, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(717))?;
+                    self.store(d, v);
+                }
+                self.pc = 721;
+            }
+            721 => {
+                {
+                    let a: i64 = self.load(Self::addr(721, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(721, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(721))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 10;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(725))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(727, 1)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(727, 63)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(727))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(731, 63)?);
+                    let b: i64 = 40;
+                    let d = Self::addr(731, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(735, 63)?);
+                    let t: i64 = 745;
+                    if c != 0 {
+                        self.pc = Self::addr(735, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 738;
+            }
+            738 => {
+                {
+                    let a: i64 = self.load(Self::addr(738, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(738, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(738))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 747;
+                    if c != 0 {
+                        self.pc = Self::addr(742, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 745;
+            }
+            745 => {
+                {
+                    let v: i64 = self.load(Self::addr(745, 727)?);
+                    self.output.write(v);
+                }
+                self.pc = 747;
+            }
+            747 => {
+                {
+                    let a: i64 = self.load(Self::addr(747, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(747, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(747))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 10;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(751))?;
+                }
+                {
+                    let a: i64 = 46;
+                    let b: i64 = 46;
+                    let d = self.rel(753, -2)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 757;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = self.load(Self::addr(757, 1012)?);
+                    let t: i64 = 765;
+                    if c != 0 {
+                        self.pc = Self::addr(757, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 760;
+            }
+            760 => {
+                {
+                    let v: i64 = self.load(Self::addr(760, 753)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 769;
+                    if c != 0 {
+                        self.pc = Self::addr(762, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 765;
+            }
+            765 => {
+                {
+                    let a: i64 = self.load(Self::addr(765, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(765, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(765))?;
+                    self.store(d, v);
+                }
+                self.pc = 769;
+            }
+            769 => {
+                {
+                    let a: i64 = self.load(Self::addr(769, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(769, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(769))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -2;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(773))?;
+                }
+                {
+                    let c: i64 = self.load(self.rel(775, 8)?);
+                    let t: i64 = 781;
+                    if c != 0 {
+                        self.pc = Self::addr(775, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 778;
+            }
+            778 => {
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 787;
+                    if c == 0 {
+                        self.pc = Self::addr(778, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 781;
+            }
+            781 => {
+                {
+                    let v: i64 = self.load(Self::addr(781, 775)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(783, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(783, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(783))?;
+                    self.store(d, v);
+                }
+                self.pc = 787;
+            }
+            787 => {
+                {
+                    let a: i64 = self.load(Self::addr(787, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(787, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(787))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -9;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(791))?;
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = self.load(self.rel(793, 0)?);
+                    let d = Self::addr(793, 63)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(793))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(797, 63)?);
+                    let b: i64 = 23;
+                    let d = Self::addr(797, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(801, 63)?);
+                    let t: i64 = 809;
+                    if c != 0 {
+                        self.pc = Self::addr(801, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 804;
+            }
+            804 => {
+                {
+                    let v: i64 = self.load(Self::addr(804, 793)?);
+                    self.output.write(v);
+                }
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 813;
+                    if c != 0 {
+                        self.pc = Self::addr(806, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 809;
+            }
+            809 => {
+                {
+                    let a: i64 = self.load(Self::addr(809, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(809, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(809))?;
+                    self.store(d, v);
+                }
+                self.pc = 813;
+            }
+            813 => {
+                {
+                    let a: i64 = self.load(Self::addr(813, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(813, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(813))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 9;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(817))?;
+                }
+                {
+                    let c: i64 = self.load(self.rel(819, 8)?);
+                    let t: i64 = 831;
+                    if c == 0 {
+                        self.pc = Self::addr(819, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 822;
+            }
+            822 => {
+                {
+                    let v: i64 = self.load(Self::addr(822, 819)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(824, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(824, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(824))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 831;
+                    if c == 0 {
+                        self.pc = Self::addr(828, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 831;
+            }
+            831 => {
+                {
+                    let a: i64 = self.load(Self::addr(831, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(831, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(831))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = -9;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(835))?;
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(self.rel(837, -2)?);
+                    let d = Self::addr(837, 63)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(837))?;
+                    self.store(d, v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(841, 63)?);
+                    let b: i64 = 22;
+                    let d = Self::addr(841, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(845, 63)?);
+                    let t: i64 = 855;
+                    if c != 0 {
+                        self.pc = Self::addr(845, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 848;
+            }
+            848 => {
+                {
+                    let a: i64 = self.load(Self::addr(848, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(848, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(848))?;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 857;
+                    if c == 0 {
+                        self.pc = Self::addr(852, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 855;
+            }
+            855 => {
+                {
+                    let v: i64 = self.load(Self::addr(855, 837)?);
+                    self.output.write(v);
+                }
+                self.pc = 857;
+            }
+            857 => {
+                {
+                    let a: i64 = self.load(Self::addr(857, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(857, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(857))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 4;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(861))?;
+                }
+                {
+                    let a: i64 = 47;
+                    let b: i64 = 0;
+                    let d = self.rel(863, 10)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(863))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 867;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(Self::addr(867, 1017)?);
+                    let b: i64 = 50;
+                    let d = Self::addr(867, 63)?;
+                    let v = (a == b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(871, 63)?);
+                    let t: i64 = 877;
+                    if c != 0 {
+                        self.pc = Self::addr(871, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 874;
+            }
+            874 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 883;
+                    if c != 0 {
+                        self.pc = Self::addr(874, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 877;
+            }
+            877 => {
+                {
+                    let v: i64 = self.load(Self::addr(877, 863)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(879, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(879, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(879))?;
+                    self.store(d, v);
+                }
+                self.pc = 883;
+            }
+            883 => {
+                {
+                    let a: i64 = self.load(Self::addr(883, 64)?);
+                    let b: i64 = 2;
+                    let d = Self::addr(883, 64)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(883))?;
+                    self.store(d, v);
+                }
+                {
+                    let v: i64 = 18;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(887))?;
+                }
+                {
+                    let c: i64 = self.load(self.rel(889, -4)?);
+                    let t: i64 = 895;
+                    if c == 0 {
+                        self.pc = Self::addr(889, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 892;
+            }
+            892 => {
+                {
+                    let c: i64 = 1;
+                    let t: i64 = 901;
+                    if c != 0 {
+                        self.pc = Self::addr(892, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 895;
+            }
+            895 => {
+                {
+                    let v: i64 = self.load(Self::addr(895, 889)?);
+                    self.output.write(v);
+                }
+                {
+                    let a: i64 = self.load(Self::addr(897, 64)?);
+                    let b: i64 = 1;
+                    let d = Self::addr(897, 64)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(897))?;
+                    self.store(d, v);
+                }
+                self.pc = 901;
+            }
+            901 => {
+                {
+                    let v: i64 = self.load(Self::addr(901, 64)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 903;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            904 => {
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 27;
+                    let d = self.rel(904, 1)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(904))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 908;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = 915;
+                    let b: i64 = 1;
+                    let d = self.rel(908, 0)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(908))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 912;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 922;
+                    if c == 0 {
+                        self.pc = Self::addr(912, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 915;
+            }
+            915 => {
+                {
+                    let a: i64 = self.load(self.rel(915, 1)?);
+                    let b: i64 = 56639;
+                    let d = self.rel(915, 1)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(915))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 919;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let v: i64 = self.load(self.rel(919, 1)?);
+                    self.output.write(v);
+                }
+                {
+                    self.pc = 921;
+                    return Ok(RunOutcome::Halted);
+                }
+            }
+            922 => {
+                {
+                    let v: i64 = 3;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(922))?;
+                }
+                {
+                    let a: i64 = self.load(self.rel(924, -2)?);
+                    let b: i64 = 3;
+                    let d = Self::addr(924, 63)?;
+                    let v = (a < b) as i64;
+                    self.store(d, v);
+                }
+                {
+                    let c: i64 = self.load(Self::addr(928, 63)?);
+                    let t: i64 = 964;
+                    if c != 0 {
+                        self.pc = Self::addr(928, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 931;
+            }
+            931 => {
+                {
+                    let a: i64 = self.load(self.rel(931, -2)?);
+                    let b: i64 = -1;
+                    let d = self.rel(931, 1)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(931))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 935;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = 1;
+                    let b: i64 = 942;
+                    let d = self.rel(935, 0)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(935))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 939;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 922;
+                    if c == 0 {
+                        self.pc = Self::addr(939, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 942;
+            }
+            942 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(self.rel(942, 1)?);
+                    let d = self.rel(942, -1)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(942))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 946;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = self.load(self.rel(946, -2)?);
+                    let b: i64 = -3;
+                    let d = self.rel(946, 1)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(946))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 950;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let a: i64 = 0;
+                    let b: i64 = 957;
+                    let d = self.rel(950, 0)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(950))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 954;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 922;
+                    if c == 0 {
+                        self.pc = Self::addr(954, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 957;
+            }
+            957 => {
+                {
+                    let a: i64 = self.load(self.rel(957, 1)?);
+                    let b: i64 = self.load(self.rel(957, -1)?);
+                    let d = self.rel(957, -2)?;
+                    let v = a.checked_add(b).ok_or(Self::overflow(957))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 961;
+                        return self.interpret();
+                    }
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = 968;
+                    if c == 0 {
+                        self.pc = Self::addr(961, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 964;
+            }
+            964 => {
+                {
+                    let a: i64 = 1;
+                    let b: i64 = self.load(self.rel(964, -2)?);
+                    let d = self.rel(964, -2)?;
+                    let v = a.checked_mul(b).ok_or(Self::overflow(964))?;
+                    self.store(d, v);
+                    if is_code(d) {
+                        self.pc = 968;
+                        return self.interpret();
+                    }
+                }
+                self.pc = 968;
+            }
+            968 => {
+                {
+                    let v: i64 = -3;
+                    self.rb = self.rb.checked_add(v).ok_or(Self::overflow(968))?;
+                }
+                {
+                    let c: i64 = 0;
+                    let t: i64 = self.load(self.rel(970, 0)?);
+                    if c == 0 {
+                        self.pc = Self::addr(970, t)? as u64;
+                        continue;
+                    }
+                }
+                self.pc = 973;
+            }
+            _ => return self.interpret(),
+            }
+        }
+    }
+}
@@ -0,0 +1,459 @@
+//! A terminal view of an IntCodeComputer that steps through a program.
+//!
+//! A Player owns a computer and runs it one instruction at a time, either on request or at a
+//! chosen speed. Each frame shows the memory around the instruction pointer as a grid, with the
+//! current instruction, the cells its operands read and write, the cells the last instruction
+//! wrote and the relative base highlighted, followed by the instruction itself and the queued
+//! input and output. The computer reports what each step did to a Trace observer. Frames are
+//! plain text with ANSI escapes, so they work in any terminal; the `intcode_tui` binary draws
+//! them and handles the keyboard.
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::int_code::asm;
+use crate::int_code::transpile::{decode, Param};
+use crate::int_code::{ComputerState, IntCodeComputer, Observer, RunOutcome};
+
+/// The speeds, in instructions per second, that the player can run at.
+pub const SPEEDS: [u64; 9] = [1, 2, 5, 10, 20, 50, 100, 1000, 10_000];
+
+/// At most this many queued input and output values are shown.
+const QUEUE_LEN: usize = 16;
+
+const RESET: &str = "\x1b[0m";
+const OPCODE: &str = "\x1b[1;7m";
+const OPERAND: &str = "\x1b[7m";
+const READ: &str = "\x1b[32m";
+const WRITE: &str = "\x1b[33m";
+const WRITTEN: &str = "\x1b[43m";
+const RELATIVE_BASE: &str = "\x1b[4m";
+
+/// Key is something the user can ask the player to do.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Key {
+    PlayPause,
+    Step,
+    Faster,
+    Slower,
+    Restart,
+    Quit,
+}
+
+impl Key {
+    /// Returns the action bound to a byte read from the terminal.
+    pub fn parse(byte: u8) -> Option<Key> {
+        Some(match byte {
+            b' ' | b'p' => Key::PlayPause,
+            b's' | b'n' => Key::Step,
+            b'+' | b'=' | b'f' => Key::Faster,
+            b'-' | b'_' | b'd' => Key::Slower,
+            b'r' => Key::Restart,
+            // Ctrl-C is read as a byte too, since the terminal doesn't turn it into a signal.
+            b'q' | 3 => Key::Quit,
+            _ => return None,
+        })
+    }
+}
+
+/// View says how much memory a frame shows.
+#[derive(Copy, Clone, Debug)]
+pub struct View {
+    /// Cells per row of the grid.
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            columns: 8,
+            rows: 16,
+        }
+    }
+}
+
+fn format_queue(values: &[i64]) -> String {
+    let shown: Vec<String> = values[values.len().saturating_sub(QUEUE_LEN)..]
+        .iter()
+        .map(|v| v.to_string())
+        .collect();
+    let more = if values.len() > QUEUE_LEN { "... " } else { "" };
+    format!("{}{}", more, shown.join(", "))
+}
+
+/// Trace is the Observer that tells the player what the computer has done: how many
+/// instructions it has run and which cells the last one wrote.
+#[derive(Default)]
+pub struct Trace {
+    steps: u64,
+    written: Vec<u64>,
+}
+
+impl Observer for Trace {
+    fn instruction_decoded(&mut self, _ptr: u64, _op_code: i64) {
+        self.steps += 1;
+        self.written.clear();
+    }
+
+    fn memory_written(&mut self, addr: u64, _value: &i64) {
+        self.written.push(addr);
+    }
+}
+
+/// Renders the computer's memory, registers and streams.
+pub fn render(computer: &IntCodeComputer<i64, Trace>, view: &View) -> String {
+    let memory = computer.memory().slice(..);
+    let ptr = computer.ptr as usize;
+    let rb = computer.rel_pos;
+
+    // The cells the current instruction is made of and the ones it touches.
+    let op = decode(memory, ptr);
+    let len = op.as_ref().map_or(1, |op| op.len());
+    let mut reads = vec![];
+    let mut writes = vec![];
+    if let Some(op) = &op {
+        for (i, param) in op.params.iter().enumerate() {
+            let addr = match param {
+                Param::Position(addr) => *addr,
+                Param::Relative(offset) => rb + offset,
+                Param::Immediate(_) => continue,
+            };
+            let written = match op.op_code {
+                1 | 2 | 7 | 8 => i == 2,
+                3 => true,
+                _ => false,
+            };
+            if written {
+                writes.push(addr);
+            } else {
+                reads.push(addr);
+            }
+        }
+    }
+
+    // The window starts a few rows above the instruction, so what led up to it is visible too.
+    let columns = view.columns.max(1);
+    let total_rows = memory.len().div_ceil(columns);
+    let first_row = (ptr / columns)
+        .saturating_sub(view.rows / 4)
+        .min(total_rows.saturating_sub(view.rows));
+    let start = first_row * columns;
+    let end = memory.len().min(start + view.rows * columns);
+    let width = memory[start..end]
+        .iter()
+        .map(|v| v.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let addr_width = end.to_string().len().max(4);
+
+    let mut out = String::new();
+    write!(out, "{:>w$} |", "", w = addr_width).unwrap();
+    for column in 0..columns {
+        write!(out, " {:>w$}", format!("+{}", column), w = width).unwrap();
+    }
+    out.push('\n');
+    for row_start in (start..end).step_by(columns) {
+        write!(out, "{:>w$} |", row_start, w = addr_width).unwrap();
+        let row_end = end.min(row_start + columns);
+        for (addr, cell) in (row_start..row_end).zip(&memory[row_start..row_end]) {
+            let a = addr as i64;
+            let mut style = String::new();
+            if addr == ptr {
+                style += OPCODE;
+            } else if addr > ptr && addr < ptr + len {
+                style += OPERAND;
+            }
+            if computer.observer().written.contains(&(addr as u64)) {
+                style += WRITTEN;
+            }
+            if writes.contains(&a) {
+                style += WRITE;
+            } else if reads.contains(&a) {
+                style += READ;
+            }
+            if a == rb {
+                style += RELATIVE_BASE;
+            }
+            let value = format!("{:>w$}", cell, w = width);
+            if style.is_empty() {
+                write!(out, " {}", value).unwrap();
+            } else {
+                write!(out, " {}{}{}", style, value, RESET).unwrap();
+            }
+        }
+        out.push('\n');
+    }
+    out.push('\n');
+
+    let instruction = asm::disassemble(memory, ptr).map_or_else(
+        || format!("{} (not an instruction)", memory[ptr]),
+        |(text, _)| text,
+    );
+    let state = match computer.state {
+        ComputerState::Halted => "halted".to_string(),
+        ComputerState::ReadyForInstruction => "ready".to_string(),
+        ComputerState::WaitingForInput => "waiting for input".to_string(),
+        ComputerState::Crashed(e) => format!("crashed: {}", e),
+    };
+    writeln!(out, "ptr {}  rb {}  {}", ptr, rb, state).unwrap();
+    writeln!(out, "{:>6}: {}", ptr, instruction).unwrap();
+    writeln!(out, "input:  {}", format_queue(computer.input.unread())).unwrap();
+    writeln!(out, "output: {}", format_queue(computer.output.unread())).unwrap();
+    out
+}
+
+/// Player runs a program one instruction at a time for the terminal view.
+pub struct Player {
+    program: Vec<i64>,
+    input: Vec<i64>,
+    computer: IntCodeComputer<i64, Trace>,
+    playing: bool,
+    /// An index into SPEEDS.
+    speed: usize,
+    /// The part of an instruction that the time played so far has earned but not run.
+    owed: f64,
+    view: View,
+}
+
+impl Player {
+    /// Returns a paused player at the start of the program, with the input queued.
+    pub fn new(program: Vec<i64>, input: Vec<i64>, view: View) -> Self {
+        Self {
+            computer: Self::load(&program, &input),
+            program,
+            input,
+            playing: false,
+            speed: 3,
+            owed: 0.0,
+            view,
+        }
+    }
+
+    fn load(program: &[i64], input: &[i64]) -> IntCodeComputer<i64, Trace> {
+        let mut computer = IntCodeComputer::new(program.to_vec()).with_observer(Trace::default());
+        for v in input {
+            computer.input.write(*v);
+        }
+        computer
+    }
+
+    pub fn computer(&self) -> &IntCodeComputer<i64, Trace> {
+        &self.computer
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Returns the speed in instructions per second.
+    pub fn speed(&self) -> u64 {
+        SPEEDS[self.speed]
+    }
+
+    /// Sets the speed to the slowest one that is at least `speed` instructions per second.
+    pub fn set_speed(&mut self, speed: u64) {
+        self.speed = SPEEDS
+            .iter()
+            .position(|s| *s >= speed)
+            .unwrap_or(SPEEDS.len() - 1);
+    }
+
+    /// Returns the number of instructions run since the start.
+    pub fn steps(&self) -> u64 {
+        self.computer.observer().steps
+    }
+
+    /// Runs one instruction, and returns whether it ran. It pauses if the program can't go any
+    /// further: it has halted, crashed, or is waiting for input that there isn't.
+    pub fn step(&mut self) -> bool {
+        if self.computer.is_halted() {
+            self.playing = false;
+            return false;
+        }
+        let steps = self.steps();
+        if self.computer.run_with_budget(1) != Ok(RunOutcome::BudgetExhausted) {
+            self.playing = false;
+        }
+        self.steps() > steps
+    }
+
+    /// Handles a key, other than Quit, which is up to the caller.
+    pub fn press(&mut self, key: Key) {
+        match key {
+            Key::PlayPause => {
+                self.playing = !self.playing;
+                self.owed = 0.0;
+            }
+            Key::Step => {
+                self.playing = false;
+                self.step();
+            }
+            Key::Faster => self.speed = (self.speed + 1).min(SPEEDS.len() - 1),
+            Key::Slower => self.speed = self.speed.saturating_sub(1),
+            Key::Restart => {
+                self.computer = Self::load(&self.program, &self.input);
+                self.owed = 0.0;
+            }
+            Key::Quit => (),
+        }
+    }
+
+    /// Runs as many instructions as the speed allows in `elapsed`, if the player is playing.
+    pub fn tick(&mut self, elapsed: Duration) {
+        if !self.playing {
+            return;
+        }
+        self.owed += self.speed() as f64 * elapsed.as_secs_f64();
+        while self.owed >= 1.0 {
+            self.owed -= 1.0;
+            if !self.step() {
+                self.owed = 0.0;
+                return;
+            }
+        }
+    }
+
+    /// Renders the current state, with a status line and the keys.
+    pub fn frame(&self) -> String {
+        let status = if self.playing { "playing" } else { "paused" };
+        format!(
+            "{}  {}/s  {} steps\n\n{}\n{}instruction{} {}read{} {}write{} {}written{} {}rb{}   \
+             space play/pause  s step  +/- speed  r restart  q quit\n",
+            status,
+            self.speed(),
+            self.steps(),
+            render(&self.computer, &self.view),
+            OPCODE,
+            RESET,
+            READ,
+            RESET,
+            WRITE,
+            RESET,
+            WRITTEN,
+            RESET,
+            RELATIVE_BASE,
+            RESET,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::int_code::asm;
+    use crate::int_code::tui::{render, Key, Player, Trace, View, RESET, WRITE, WRITTEN};
+    use crate::int_code::IntCodeComputer;
+    use crate::util;
+
+    #[test]
+    fn test_render() {
+        let computer =
+            IntCodeComputer::new(vec![1002, 4, 3, 4, 33]).with_observer(Trace::default());
+        let frame = render(
+            &computer,
+            &View {
+                columns: 4,
+                rows: 2,
+            },
+        );
+        let lines: Vec<&str> = frame.lines().collect();
+        assert_eq!(lines[0], "     |    +0    +1    +2    +3");
+        // The opcode is at the relative base too, since that starts at 0.
+        assert_eq!(
+            lines[1],
+            "   0 | \x1b[1;7m\x1b[4m 1002\x1b[0m \x1b[7m    4\x1b[0m \x1b[7m    3\x1b[0m \x1b[7m    4\x1b[0m"
+        );
+        // [4] is read and written. Memory past the program reads as zeros.
+        assert_eq!(
+            lines[2],
+            format!("   4 | {}   33{}     0     0     0", WRITE, RESET)
+        );
+        assert_eq!(lines[4], "ptr 0  rb 0  ready");
+        assert_eq!(lines[5], "     0: mul [4], 3, [4]");
+        assert_eq!(lines[6], "input:  ");
+        assert_eq!(lines[7], "output: ");
+    }
+
+    #[test]
+    fn test_render_window_follows_ptr() {
+        let program = util::read_int_code_memory("./input/day09.txt");
+        let mut computer = IntCodeComputer::new(program).with_observer(Trace::default());
+        computer.input.write(1);
+        computer.run_with_budget(200).unwrap();
+        let frame = render(&computer, &View::default());
+        let ptr = computer.ptr as usize;
+        let first_row: usize = frame.lines().nth(1).unwrap()[..5].trim().parse().unwrap();
+        assert!(first_row <= ptr && ptr < first_row + 16 * 8);
+        assert_eq!(first_row % 8, 0);
+        assert!(frame.contains(&format!("ptr {}  rb {}", ptr, computer.rel_pos)));
+        let (instruction, _) = asm::disassemble(computer.memory().slice(..), ptr).unwrap();
+        assert!(frame.contains(&format!("{:>6}: {}\n", ptr, instruction)));
+    }
+
+    #[test]
+    fn test_player() {
+        // Reads two values and outputs their sum and product.
+        let program = vec![
+            3, 17, 3, 18, 1, 17, 18, 19, 4, 19, 2, 17, 18, 19, 4, 19, 99, 0, 0, 0,
+        ];
+        let mut player = Player::new(program, vec![6, 7], View::default());
+        assert!(player.frame().contains("input:  6, 7"));
+        assert!(!player.frame().contains(&format!("{} ", WRITTEN)));
+        player.press(Key::Step);
+        assert_eq!(player.steps(), 1);
+        assert!(player.frame().contains("input:  7"));
+        // The in wrote 6 to cell 17.
+        assert!(player
+            .frame()
+            .contains(&format!("{}    6{}", WRITTEN, RESET)));
+        assert!(player.frame().starts_with("paused  10/s  1 steps"));
+
+        player.press(Key::PlayPause);
+        player.tick(Duration::from_millis(250));
+        assert_eq!(player.steps(), 3);
+        player.press(Key::Faster);
+        assert_eq!(player.speed(), 20);
+        player.tick(Duration::from_secs(10));
+        assert!(player.computer().is_halted());
+        assert!(!player.is_playing());
+        assert!(player.frame().contains("output: 13, 42"));
+        assert!(!player.step());
+
+        player.press(Key::Restart);
+        assert_eq!(player.steps(), 0);
+        assert!(player.frame().contains("input:  6, 7"));
+    }
+
+    #[test]
+    fn test_player_pauses_for_input() {
+        let mut player = Player::new(vec![3, 0, 4, 0, 99], vec![], View::default());
+        player.set_speed(1000);
+        player.press(Key::PlayPause);
+        player.tick(Duration::from_secs(1));
+        assert!(!player.is_playing());
+        assert_eq!(player.steps(), 0);
+        assert!(player.frame().contains("waiting for input"));
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(Key::parse(b' '), Some(Key::PlayPause));
+        assert_eq!(Key::parse(b's'), Some(Key::Step));
+        assert_eq!(Key::parse(b'+'), Some(Key::Faster));
+        assert_eq!(Key::parse(b'-'), Some(Key::Slower));
+        assert_eq!(Key::parse(3), Some(Key::Quit));
+        assert_eq!(Key::parse(b'x'), None);
+
+        let mut player = Player::new(vec![99], vec![], View::default());
+        for _ in 0..20 {
+            player.press(Key::Slower);
+        }
+        assert_eq!(player.speed(), 1);
+        for _ in 0..20 {
+            player.press(Key::Faster);
+        }
+        assert_eq!(player.speed(), 10_000);
+    }
+}
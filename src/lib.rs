@@ -1,2 +1,120 @@
+use std::any::Any;
+use std::fmt;
+
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod input;
 pub mod int_code;
 pub mod util;
+
+pub use error::AocError;
+
+/// Answer is what a part of a puzzle comes to. It is nearly always a number, but it can be text,
+/// like the picture day 8 draws.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// Solution solves a day's puzzle. The input is parsed once, and both parts are solved from it.
+pub trait Solution {
+    /// The puzzle input once it has been parsed.
+    type Input;
+
+    /// Returns the day of December the puzzle is for.
+    fn day(&self) -> u32;
+
+    /// Returns the title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Parses the text of the puzzle input, returning an error if it isn't input for the puzzle.
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+
+    /// Solves part 1, returning an error if the input has no answer.
+    fn part1(&self, input: &Self::Input) -> Result<Answer, AocError>;
+
+    /// Solves part 2, returning an error if the input has no answer.
+    fn part2(&self, input: &Self::Input) -> Result<Answer, AocError>;
+}
+
+/// Puzzle is a Solution with the type of its input hidden, so that the solutions for every day
+/// can be kept in one list. Every Solution is a Puzzle.
+pub trait Puzzle {
+    /// Returns the day of December the puzzle is for.
+    fn day(&self) -> u32;
+
+    /// Returns the title of the puzzle.
+    fn title(&self) -> &'static str;
+
+    /// Parses the text of the puzzle input, for solve.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError>;
+
+    /// Solves part 1 or 2 of the puzzle from input that parse returned, returning an error if
+    /// the input has no answer. It panics if the input came from another puzzle or there is no
+    /// such part.
+    fn solve(&self, input: &dyn Any, part: u32) -> Result<Answer, AocError>;
+}
+
+impl<S: Solution> Puzzle for S
+where
+    S::Input: 'static,
+{
+    fn day(&self) -> u32 {
+        Solution::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Solution::title(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u32) -> Result<Answer, AocError> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by another puzzle");
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => panic!("day {} has no part {}", Solution::day(self), part),
+        }
+    }
+}
@@ -1,28 +1,81 @@
-use std::{
-    fs::File,
-    io::{prelude::*, BufReader},
-    path::Path,
-};
+use std::fs;
+use std::path::Path;
+
+use crate::int_code::loader;
+use crate::AocError;
+
+/// Reads the text of the file at `path`.
+pub fn read_to_string(path: impl AsRef<Path>) -> Result<String, AocError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|error| AocError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Reads the lines of the file at `path`. It is an error for there to be none.
+pub fn read_lines(path: impl AsRef<Path>) -> Result<Vec<String>, AocError> {
+    let path = path.as_ref();
+    lines(&read_to_string(path)?).map_err(|e| e.in_file(path))
+}
+
+/// Reads an Intcode program written as values, as puzzle inputs are, from the file at `path`.
+pub fn read_int_code(path: impl AsRef<Path>) -> Result<Vec<i64>, AocError> {
+    let path = path.as_ref();
+    parse_int_code(&read_to_string(path)?).map_err(|e| e.in_file(path))
+}
 
 /// Returns a vector of String. The idea is to use this format to consume lines
 /// from the files. We can also mock this out by passing Vec<String> to the solutions
-/// since they expect this format as well.
+/// since they expect this format as well. It panics if the file can't be read, or is empty.
 pub fn lines_from_file(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-    buf.lines()
-        .map(|l| l.expect("Could not parse line"))
-        .collect()
+    read_lines(filename).unwrap_or_else(|e| panic!("{}", e))
 }
 
+/// Reads an Intcode program written as values, like read_int_code, but panics if the file can't
+/// be read or has anything in it that isn't part of the program.
 pub fn read_int_code_memory(filename: impl AsRef<Path>) -> Vec<i64> {
-    lines_from_file(filename)
-        .first()
-        .unwrap()
-        .split(',')
-        .map(|x| x.parse::<i64>())
-        .filter_map(Result::ok)
-        .collect()
+    read_int_code(filename).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Splits puzzle input into lines. It is an error for there to be none.
+pub fn lines(text: &str) -> Result<Vec<String>, AocError> {
+    let lines: Vec<String> = text.lines().map(String::from).collect();
+    if lines.iter().all(|l| l.trim().is_empty()) {
+        return Err(AocError::parse(1, "", "the input is empty"));
+    }
+    Ok(lines)
+}
+
+/// Parses an Intcode program written as values, as puzzle inputs are. It is an error for there
+/// to be anything else in the text, or no program at all.
+pub fn parse_int_code(text: &str) -> Result<Vec<i64>, AocError> {
+    loader::parse_values(text).map_err(|e| {
+        let line = text.lines().nth(e.line.saturating_sub(1)).unwrap_or("");
+        let token = line
+            .chars()
+            .skip(e.column.saturating_sub(1))
+            .take_while(|c| !c.is_whitespace() && *c != ',')
+            .collect::<String>();
+        AocError::parse(e.line, &token, e.message)
+    })
+}
+
+/// Quotes a string for JSON.
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
 }
 
 pub struct Permutations {
@@ -57,8 +110,7 @@ impl Permutations {
 
     fn call_into_next_frame(&mut self) {
         self.stack_ptr += 1;
-        self.stack_frames[self.stack_ptr as usize] =
-            IterState::new((self.n - self.stack_ptr) as usize);
+        self.stack_frames[self.stack_ptr] = IterState::new(self.n - self.stack_ptr);
     }
 
     fn exit_current_frame(&mut self) {
@@ -107,11 +159,7 @@ impl Iterator for Permutations {
         // go through here we check the stack frame but also the instruction pointer within the frame
         // that is what instruction are we executing inside the function call.
         loop {
-            let mut current_frame = self
-                .stack_frames
-                .get(self.stack_ptr as usize)
-                .unwrap()
-                .copy();
+            let mut current_frame = self.stack_frames.get(self.stack_ptr).unwrap().copy();
 
             if current_frame.depth == 1 {
                 self.exit_current_frame();
@@ -121,7 +169,7 @@ impl Iterator for Permutations {
 
             if !current_frame.in_loop {
                 current_frame.in_loop = true;
-                self.stack_frames[self.stack_ptr as usize] = current_frame;
+                self.stack_frames[self.stack_ptr] = current_frame;
                 self.call_into_next_frame();
                 continue;
             }
@@ -137,14 +185,14 @@ impl Iterator for Permutations {
                 current_frame.i = 0;
             }
 
-            let swap_index = if current_frame.depth % 2 == 0 {
+            let swap_index = if current_frame.depth.is_multiple_of(2) {
                 current_frame.i as usize
             } else {
                 0
             };
             self.current.swap(swap_index, current_frame.depth - 1);
             current_frame.i += 1;
-            self.stack_frames[self.stack_ptr as usize] = current_frame;
+            self.stack_frames[self.stack_ptr] = current_frame;
             self.call_into_next_frame();
         }
     }
@@ -152,15 +200,51 @@ impl Iterator for Permutations {
 
 #[cfg(test)]
 mod tests {
-    use crate::util::Permutations;
+    use crate::util::{json_string, lines, parse_int_code, read_lines, Permutations};
+    use crate::AocError;
+
+    #[test]
+    fn test_errors() {
+        match read_lines("./input/day99.txt") {
+            Err(AocError::Io { path, error }) => {
+                assert_eq!(path.to_str(), Some("./input/day99.txt"));
+                assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(
+            lines("\n \n").unwrap_err().to_string(),
+            "line 1: the input is empty"
+        );
+        assert_eq!(
+            parse_int_code("").unwrap_err().to_string(),
+            "line 1: no values in program"
+        );
+        let error = parse_int_code("1,2\n3 x,4").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: invalid value 'x'");
+        match parse_int_code("1,x3,4,5").unwrap_err() {
+            AocError::Parse { line, token, .. } => assert_eq!((line, token.as_str()), (1, "x3")),
+            e => panic!("unexpected error {:?}", e),
+        }
+        assert_eq!(parse_int_code("1,2\n").unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("42"), "\"42\"");
+        assert_eq!(
+            json_string("# #\n\"a\\b\"\t"),
+            "\"# #\\n\\\"a\\\\b\\\"\\u0009\""
+        );
+    }
 
     #[test]
     fn test_perm_iterator() {
-        assert_eq!(Permutations::new(1).into_iter().count(), 1);
-        assert_eq!(Permutations::new(2).into_iter().count(), 2);
-        assert_eq!(Permutations::new(3).into_iter().count(), 6);
-        assert_eq!(Permutations::new(4).into_iter().count(), 24);
-        assert_eq!(Permutations::new(5).into_iter().count(), 120);
+        assert_eq!(Permutations::new(1).count(), 1);
+        assert_eq!(Permutations::new(2).count(), 2);
+        assert_eq!(Permutations::new(3).count(), 6);
+        assert_eq!(Permutations::new(4).count(), 24);
+        assert_eq!(Permutations::new(5).count(), 120);
     }
 
     #[test]
@@ -174,7 +258,7 @@ mod tests {
                 vec![1u64, 2u64, 0u64],
                 vec![2u64, 1u64, 0u64],
             ],
-            Permutations::new(3).into_iter().collect::<Vec<Vec<u64>>>()
+            Permutations::new(3).collect::<Vec<Vec<u64>>>()
         );
     }
 }